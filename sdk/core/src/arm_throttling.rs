@@ -0,0 +1,260 @@
+use crate::headers::{
+    RATELIMIT_REMAINING_SUBSCRIPTION_DELETES, RATELIMIT_REMAINING_SUBSCRIPTION_READS,
+    RATELIMIT_REMAINING_SUBSCRIPTION_WRITES, RATELIMIT_REMAINING_TENANT_READS,
+    RATELIMIT_REMAINING_TENANT_WRITES,
+};
+use crate::sleep::sleep;
+use crate::{HttpClient, HttpError};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::RETRY_AFTER;
+use http::{HeaderMap, Request, Response, StatusCode};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The calls Azure Resource Manager still accepts before throttling, from its
+/// `x-ms-ratelimit-remaining-*` headers.
+///
+/// A limit is `None` when the response did not carry its header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArmRateLimit {
+    pub subscription_reads: Option<u32>,
+    pub subscription_writes: Option<u32>,
+    pub subscription_deletes: Option<u32>,
+    pub tenant_reads: Option<u32>,
+    pub tenant_writes: Option<u32>,
+}
+
+impl ArmRateLimit {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let remaining = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+        };
+        Self {
+            subscription_reads: remaining(RATELIMIT_REMAINING_SUBSCRIPTION_READS),
+            subscription_writes: remaining(RATELIMIT_REMAINING_SUBSCRIPTION_WRITES),
+            subscription_deletes: remaining(RATELIMIT_REMAINING_SUBSCRIPTION_DELETES),
+            tenant_reads: remaining(RATELIMIT_REMAINING_TENANT_READS),
+            tenant_writes: remaining(RATELIMIT_REMAINING_TENANT_WRITES),
+        }
+    }
+}
+
+/// An [`HttpClient`] which retries the requests Azure Resource Manager throttles.
+///
+/// A `429 Too Many Requests` is retried after the seconds of its `Retry-After` header, or after
+/// `default_delay` without one, up to `max_retries` times. The last one, and one asking to wait
+/// longer than `max_delay`, fail with [`HttpError::Throttled`], which tells the remaining calls of
+/// the rate limit.
+///
+/// Only `execute_request`, the one of the management operations, retries: the requests of
+/// `execute_request2` go through a pipeline, with its own retry policy.
+#[derive(Debug, Clone)]
+pub struct ArmThrottlingHttpClient {
+    http_client: Arc<dyn HttpClient>,
+    max_retries: u32,
+    default_delay: Duration,
+    max_delay: Duration,
+}
+
+impl ArmThrottlingHttpClient {
+    /// Retries the throttled requests of `http_client` 3 times, 10 seconds apart by default,
+    /// waiting at most a minute for a `Retry-After`.
+    pub fn new(http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            http_client,
+            max_retries: 3,
+            default_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+
+    setters! {
+        max_retries: u32 => max_retries,
+        default_delay: Duration => default_delay,
+        max_delay: Duration => max_delay,
+    }
+}
+
+/// The delay the `Retry-After` header asks for, in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// A copy of `request`, to send it again.
+fn clone_request(request: &Request<Bytes>) -> Request<Bytes> {
+    let mut clone = Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl HttpClient for ArmThrottlingHttpClient {
+    async fn execute_request(&self, request: Request<Bytes>) -> Result<Response<Bytes>, HttpError> {
+        let mut retries = 0;
+        loop {
+            let response = self
+                .http_client
+                .execute_request(clone_request(&request))
+                .await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let requested_delay = retry_after(response.headers());
+            let delay = requested_delay.unwrap_or(self.default_delay);
+            if retries == self.max_retries || delay > self.max_delay {
+                return Err(HttpError::Throttled {
+                    retry_after: requested_delay,
+                    rate_limit: ArmRateLimit::from_headers(response.headers()),
+                });
+            }
+            retries += 1;
+            sleep(delay).await;
+        }
+    }
+
+    async fn execute_request2(
+        &self,
+        request: &crate::Request,
+    ) -> Result<crate::Response, HttpError> {
+        self.http_client.execute_request2(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    /// Answers the requests with `responses`, in order.
+    #[derive(Debug)]
+    struct MockHttpClient {
+        responses: Mutex<Vec<Response<Bytes>>>,
+    }
+
+    impl MockHttpClient {
+        fn new(responses: Vec<Response<Bytes>>) -> Arc<Self> {
+            Arc::new(Self {
+                responses: Mutex::new(responses),
+            })
+        }
+
+        fn remaining(&self) -> usize {
+            self.responses.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for MockHttpClient {
+        async fn execute_request(
+            &self,
+            _request: Request<Bytes>,
+        ) -> Result<Response<Bytes>, HttpError> {
+            Ok(self.responses.lock().unwrap().remove(0))
+        }
+
+        async fn execute_request2(
+            &self,
+            _request: &crate::Request,
+        ) -> Result<crate::Response, HttpError> {
+            Err(HttpError::new_unexpected_status_code(
+                StatusCode::OK,
+                StatusCode::NOT_IMPLEMENTED,
+                "the management operations do not use the pipeline",
+            ))
+        }
+    }
+
+    fn throttled(retry_after: &str) -> Response<Bytes> {
+        Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(RETRY_AFTER, retry_after)
+            .header(RATELIMIT_REMAINING_SUBSCRIPTION_READS, "0")
+            .header(RATELIMIT_REMAINING_TENANT_READS, "11998")
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    fn request() -> Request<Bytes> {
+        Request::get("https://management.azure.com/subscriptions?api-version=2020-01-01")
+            .body(Bytes::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn throttled_requests_are_retried_after_their_retry_after() {
+        let ok = Response::builder()
+            .status(StatusCode::OK)
+            .body(Bytes::new())
+            .unwrap();
+        let mock = MockHttpClient::new(vec![throttled("1"), ok]);
+        let client =
+            ArmThrottlingHttpClient::new(mock.clone()).default_delay(Duration::from_secs(30));
+
+        let start = Instant::now();
+        let response = futures::executor::block_on(client.execute_request(request())).unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(mock.remaining(), 0);
+        // the one second of `Retry-After`, not the default 30
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_secs(1));
+        assert!(elapsed < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn the_last_throttled_response_tells_the_rate_limit() {
+        let mock = MockHttpClient::new(vec![throttled("0"), throttled("0")]);
+        let client = ArmThrottlingHttpClient::new(mock.clone()).max_retries(1u32);
+
+        match futures::executor::block_on(client.execute_request(request())) {
+            Err(HttpError::Throttled {
+                retry_after,
+                rate_limit,
+            }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(0)));
+                assert_eq!(
+                    rate_limit,
+                    ArmRateLimit {
+                        subscription_reads: Some(0),
+                        tenant_reads: Some(11998),
+                        ..ArmRateLimit::default()
+                    }
+                );
+            }
+            other => panic!("expected the request to be throttled, got {:?}", other),
+        }
+        assert_eq!(mock.remaining(), 0);
+    }
+
+    #[test]
+    fn a_retry_after_longer_than_the_max_delay_is_not_waited_for() {
+        let mock = MockHttpClient::new(vec![throttled("3600")]);
+        let client = ArmThrottlingHttpClient::new(mock.clone()).max_delay(Duration::from_secs(5));
+
+        let start = Instant::now();
+        match futures::executor::block_on(client.execute_request(request())) {
+            Err(HttpError::Throttled { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(3600)));
+            }
+            other => panic!("expected the request to be throttled, got {:?}", other),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(mock.remaining(), 0);
+    }
+}
@@ -0,0 +1,97 @@
+use futures::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A token used to cooperatively cancel an operation.
+///
+/// Place a clone of the token in the [`Context`](crate::Context) passed to the pipeline with
+/// [`Context::with_cancellation_token`](crate::Context::with_cancellation_token) and call
+/// [`cancel`](Self::cancel) from anywhere (for example another task) to stop the operation.
+/// The pipeline checks the token before dispatching each attempt and while waiting between
+/// retries, returning [`Error::Cancelled`](crate::Error::Cancelled).
+///
+/// Cancellation is cooperative: an attempt already handed to the transport is allowed to
+/// complete. Drop the future returned by the pipeline to abort it instead.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations using this token (or any of its clones).
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for waker in self.inner.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// A future completing once the token has been cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+        }
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`].
+#[derive(Debug)]
+pub struct Cancelled {
+    token: CancellationToken,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = self.token.inner.wakers.lock().unwrap();
+        // check again now that `cancel` cannot drain the wakers under our feet
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[test]
+    fn cancelling_a_clone_completes_the_future() {
+        let token = CancellationToken::new();
+        let mut cancelled = token.cancelled();
+        assert!(!token.is_cancelled());
+        assert!((&mut cancelled).now_or_never().is_none());
+
+        let clone = token.clone();
+        std::thread::spawn(move || clone.cancel()).join().unwrap();
+
+        assert!(token.is_cancelled());
+        assert!(cancelled.now_or_never().is_some());
+    }
+}
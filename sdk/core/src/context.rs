@@ -1,3 +1,9 @@
+use crate::CancellationToken;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
 /// Pipeline execution context.
 ///
 /// During a pipeline execution, context will be passed from the function starting the
@@ -6,15 +12,104 @@
 /// pipeline execution history between policies.
 /// For example, it could be used to signal that an execution failed because a CosmosDB endpoint is
 /// down and the appropriate policy should try the next one).
-#[derive(Clone)]
+///
+/// Values are stored by type: there can be at most one value of any given type in a context.
+#[derive(Clone, Debug, Default)]
 pub struct Context {
-    // Temporary hack to make sure that Context is not initializeable
-    // Soon Context will have proper data fields
-    _priv: (),
+    type_map: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
 }
 
+/// The point in time by which the whole operation, retries included, must be complete.
+#[derive(Debug, Clone, Copy)]
+struct Deadline(Instant);
+
 impl Context {
     pub fn new() -> Self {
-        Self { _priv: () }
+        Self::default()
+    }
+
+    /// Inserts a value, replacing (and returning) the previous value of the same type.
+    pub fn insert<E>(&mut self, entity: E) -> Option<Arc<dyn Any + Send + Sync>>
+    where
+        E: Send + Sync + 'static,
+    {
+        self.type_map.insert(TypeId::of::<E>(), Arc::new(entity))
+    }
+
+    /// Removes the value of the given type, if any.
+    pub fn remove<E>(&mut self) -> Option<Arc<dyn Any + Send + Sync>>
+    where
+        E: Send + Sync + 'static,
+    {
+        self.type_map.remove(&TypeId::of::<E>())
+    }
+
+    /// Gets a reference to the value of the given type, if any.
+    pub fn get<E>(&self) -> Option<&E>
+    where
+        E: Send + Sync + 'static,
+    {
+        self.type_map
+            .get(&TypeId::of::<E>())
+            .and_then(|item| item.downcast_ref())
+    }
+
+    /// Sets an overall deadline for the operation.
+    ///
+    /// Unlike a per-attempt timeout, the deadline covers every attempt made by the retry
+    /// policy: no retry is started (and no back-off is waited) past it, and the pipeline
+    /// returns [`Error::DeadlineExceeded`](crate::Error::DeadlineExceeded) instead.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.insert(Deadline(deadline));
+        self
+    }
+
+    /// The deadline set with [`with_deadline`](Self::with_deadline), if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.get::<Deadline>().map(|deadline| deadline.0)
+    }
+
+    /// Makes the operation cancellable through `token`.
+    ///
+    /// See [`CancellationToken`] for when the token is checked.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.insert(token);
+        self
+    }
+
+    /// The cancellation token set with
+    /// [`with_cancellation_token`](Self::with_cancellation_token), if any.
+    pub fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn values_are_stored_by_type() {
+        let mut ctx = Context::new();
+        assert!(ctx.get::<String>().is_none());
+
+        ctx.insert("first".to_owned());
+        ctx.insert(42u32);
+        assert!(ctx.insert("second".to_owned()).is_some());
+
+        assert_eq!(ctx.get::<String>().map(String::as_str), Some("second"));
+        assert_eq!(ctx.get::<u32>(), Some(&42));
+
+        ctx.remove::<String>();
+        assert!(ctx.get::<String>().is_none());
+    }
+
+    #[test]
+    fn deadline_survives_clone() {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let ctx = Context::new().with_deadline(deadline);
+        assert_eq!(ctx.clone().deadline(), Some(deadline));
+        assert_eq!(Context::new().deadline(), None);
     }
 }
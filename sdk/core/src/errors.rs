@@ -35,6 +35,10 @@ pub enum Error {
     StreamError(#[from] StreamError),
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
+    #[error("the operation deadline has passed")]
+    DeadlineExceeded,
+    #[error("the operation was cancelled")]
+    Cancelled,
 }
 
 #[cfg(feature = "enable_hyper")]
@@ -127,6 +131,10 @@ pub enum HttpError {
     BuildRequestError(http::Error),
     #[error("Failed to build request: {0}")]
     BuildClientRequestError(HttpClientError),
+    #[error("Failed to build the HTTP client: {0}")]
+    BuildClientError(HttpClientError),
+    #[error("TLS options not supported by the HTTP client: {0}")]
+    UnsupportedTlsOptions(String),
     #[error("Failed to execute request: {0}")]
     ExecuteRequestError(HttpClientError),
     #[error("Failed to read response as bytes: {0}")]
@@ -139,6 +147,11 @@ pub enum HttpError {
     ToStrError(#[from] http::header::ToStrError),
     #[error("Failed to reset stream: {0}")]
     StreamResetError(StreamError),
+    #[error("Throttled by Azure Resource Manager, retry after {retry_after:?}, {rate_limit:?}")]
+    Throttled {
+        retry_after: Option<std::time::Duration>,
+        rate_limit: crate::ArmRateLimit,
+    },
 }
 
 impl HttpError {
@@ -174,6 +187,14 @@ pub enum Not512ByteAlignedError {
     EndRange(u64),
 }
 
+/// A request body the service would reject, caught before it is sent.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    #[error("the required field {0} is empty")]
+    EmptyRequiredField(&'static str),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum PermissionError {
     #[error("Permission token not supported in this service ({}). Received token {}, supported tokens {:?}",
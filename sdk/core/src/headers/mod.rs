@@ -36,7 +36,7 @@ impl TryFrom<&HeaderMap> for CommonStorageResponseHeaders {
 
 pub trait AddAsHeader {
     fn add_as_header(&self, builder: Builder) -> Builder;
-    fn add_as_header2(&self, _request: &mut crate::Request) {
+    fn add_as_header2(&self, _request: &mut crate::Request) -> Result<(), http::Error> {
         unimplemented!()
     }
 }
@@ -57,10 +57,21 @@ pub fn add_optional_header<T: AddAsHeader>(item: &Option<T>, mut builder: Builde
     builder
 }
 
-pub fn add_optional_header2<T: AddAsHeader>(item: &Option<T>, request: &mut crate::Request) {
+pub fn add_optional_header2<T: AddAsHeader>(
+    item: &Option<T>,
+    request: &mut crate::Request,
+) -> Result<(), http::Error> {
     if let Some(item) = item {
-        item.add_as_header2(request);
+        item.add_as_header2(request)?;
     }
+    Ok(())
+}
+
+pub fn add_mandatory_header2<T: AddAsHeader>(
+    item: &T,
+    request: &mut crate::Request,
+) -> Result<(), http::Error> {
+    item.add_as_header2(request)
 }
 
 #[must_use]
@@ -112,6 +123,14 @@ pub const ACCOUNT_KIND: &str = "x-ms-account-kind";
 pub const APPEND_POSITION: &str = "x-ms-blob-condition-appendpos";
 pub const CACHE_CONTROL: &str = "x-ms-blob-cache-control";
 pub const CONTENT_DISPOSITION: &str = "x-ms-blob-content-disposition";
+pub const RATELIMIT_REMAINING_SUBSCRIPTION_READS: &str =
+    "x-ms-ratelimit-remaining-subscription-reads";
+pub const RATELIMIT_REMAINING_SUBSCRIPTION_WRITES: &str =
+    "x-ms-ratelimit-remaining-subscription-writes";
+pub const RATELIMIT_REMAINING_SUBSCRIPTION_DELETES: &str =
+    "x-ms-ratelimit-remaining-subscription-deletes";
+pub const RATELIMIT_REMAINING_TENANT_READS: &str = "x-ms-ratelimit-remaining-tenant-reads";
+pub const RATELIMIT_REMAINING_TENANT_WRITES: &str = "x-ms-ratelimit-remaining-tenant-writes";
 pub const ACTIVITY_ID: &str = "x-ms-activity-id";
 pub const CONTINUATION: &str = "x-ms-continuation";
 pub const SESSION_TOKEN: &str = "x-ms-session-token";
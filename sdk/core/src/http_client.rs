@@ -1,6 +1,8 @@
 #[allow(unused_imports)]
 use crate::Body;
 use crate::HttpError;
+#[allow(unused_imports)]
+use crate::{Identity, TlsOptions, TlsVersion};
 use async_trait::async_trait;
 use bytes::Bytes;
 #[allow(unused_imports)]
@@ -22,6 +24,49 @@ pub fn new_http_client() -> Arc<dyn HttpClient> {
     Arc::new(hyper::Client::builder().build(hyper_rustls::HttpsConnector::with_native_roots()))
 }
 
+/// Creates an `HttpClient` with the TLS settings of `tls`.
+///
+/// The settings the backend cannot honour are reported as errors rather than ignored.
+#[cfg(feature = "enable_reqwest")]
+pub fn new_http_client_with_tls(tls: &TlsOptions) -> Result<Arc<dyn HttpClient>, HttpError> {
+    let min_tls_version = match tls.min_tls_version {
+        TlsVersion::Tls1_2 => reqwest::tls::Version::TLS_1_2,
+        TlsVersion::Tls1_3 => reqwest::tls::Version::TLS_1_3,
+    };
+    let mut builder = reqwest::Client::builder().min_tls_version(min_tls_version);
+    if let Some(identity) = &tls.client_identity {
+        let identity = match identity {
+            Identity::Pkcs12 { der, password } => reqwest::Identity::from_pkcs12_der(der, password),
+            Identity::Pem {
+                certificate,
+                private_key,
+            } => reqwest::Identity::from_pkcs8_pem(certificate, private_key),
+        };
+        builder = builder.identity(identity.map_err(HttpError::BuildClientError)?);
+    }
+    let client = builder.build().map_err(HttpError::BuildClientError)?;
+    Ok(Arc::new(client))
+}
+
+/// Creates an `HttpClient` with the TLS settings of `tls`.
+///
+/// The settings the backend cannot honour are reported as errors rather than ignored.
+#[cfg(feature = "enable_hyper")]
+pub fn new_http_client_with_tls(tls: &TlsOptions) -> Result<Arc<dyn HttpClient>, HttpError> {
+    if tls.client_identity.is_some() {
+        return Err(HttpError::UnsupportedTlsOptions(
+            "the hyper client cannot present a client certificate".to_owned(),
+        ));
+    }
+    match tls.min_tls_version {
+        // rustls never negotiates a version older than TLS 1.2
+        TlsVersion::Tls1_2 => Ok(new_http_client()),
+        TlsVersion::Tls1_3 => Err(HttpError::UnsupportedTlsOptions(
+            "the hyper client cannot require TLS 1.3".to_owned(),
+        )),
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait HttpClient: Send + Sync + std::fmt::Debug {
@@ -0,0 +1,375 @@
+use crate::{Error, StreamError};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use serde::de::{DeserializeOwned, Error as _};
+
+/// Incrementally deserializes the elements of a JSON array found in a response body.
+///
+/// `field` is the name of a property of the top-level JSON object holding the array (for
+/// example `value` or, for Cosmos DB, `Documents`). Each element is deserialized and yielded
+/// as soon as its last byte has been received, so only the element currently being parsed is
+/// kept in memory instead of the whole body. Properties following the array are never read.
+pub fn json_array_stream<T, S>(
+    stream: S,
+    field: &'static str,
+) -> impl Stream<Item = Result<T, Error>>
+where
+    T: DeserializeOwned,
+    S: Stream<Item = Result<Bytes, StreamError>> + Unpin,
+{
+    futures::stream::unfold(
+        Some((stream, ArrayScanner::new(field))),
+        move |state| async move {
+            let (mut stream, mut scanner) = state?;
+            loop {
+                match scanner.next_element() {
+                    Ok(Some(element)) => {
+                        let item = serde_json::from_slice(&element).map_err(Error::from);
+                        return Some((item, Some((stream, scanner))));
+                    }
+                    Ok(None) if scanner.is_done() => return None,
+                    Ok(None) => {}
+                    Err(error) => return Some((Err(error), None)),
+                }
+
+                match stream.next().await {
+                    Some(Ok(chunk)) => scanner.feed(chunk),
+                    Some(Err(error)) => return Some((Err(error.into()), None)),
+                    None => {
+                        let error = serde_json::Error::custom(format!(
+                            "unexpected end of body while reading the `{}` array",
+                            field
+                        ));
+                        return Some((Err(error.into()), None));
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Looking for `field` in the top-level object.
+    Seek,
+    /// Inside the array, between or within elements.
+    InArray,
+    /// The closing bracket of the array has been consumed.
+    Done,
+}
+
+/// Splits the bytes of a JSON array into its elements without parsing them.
+#[derive(Debug)]
+struct ArrayScanner {
+    field: &'static str,
+    phase: Phase,
+    chunk: Bytes,
+    position: usize,
+    in_string: bool,
+    escaped: bool,
+    // Seek state
+    depth: usize,
+    expect_key: bool,
+    capturing_key: bool,
+    key: Vec<u8>,
+    field_matched: bool,
+    // InArray state
+    nesting: usize,
+    element: Vec<u8>,
+    after_comma: bool,
+}
+
+impl ArrayScanner {
+    fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            phase: Phase::Seek,
+            chunk: Bytes::new(),
+            position: 0,
+            in_string: false,
+            escaped: false,
+            depth: 0,
+            expect_key: false,
+            capturing_key: false,
+            key: Vec::new(),
+            field_matched: false,
+            nesting: 0,
+            element: Vec::new(),
+            after_comma: false,
+        }
+    }
+
+    fn feed(&mut self, chunk: Bytes) {
+        self.chunk = chunk;
+        self.position = 0;
+    }
+
+    fn is_done(&self) -> bool {
+        self.phase == Phase::Done
+    }
+
+    /// Consumes the current chunk until an element is complete. Returns `None` when more
+    /// bytes are needed or the array is over.
+    fn next_element(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        while self.position < self.chunk.len() && self.phase != Phase::Done {
+            let byte = self.chunk[self.position];
+            self.position += 1;
+
+            let element = match self.phase {
+                Phase::Seek => {
+                    self.seek(byte)?;
+                    None
+                }
+                Phase::InArray => self.scan(byte)?,
+                Phase::Done => None,
+            };
+            if element.is_some() {
+                return Ok(element);
+            }
+        }
+        Ok(None)
+    }
+
+    fn seek(&mut self, byte: u8) -> Result<(), Error> {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+                if self.capturing_key {
+                    self.capturing_key = false;
+                    self.field_matched = self.key == self.field.as_bytes();
+                }
+                return Ok(());
+            }
+            if self.capturing_key {
+                self.key.push(byte);
+            }
+            return Ok(());
+        }
+
+        if self.field_matched && !byte.is_ascii_whitespace() {
+            return match byte {
+                b':' => {
+                    self.expect_key = false;
+                    Ok(())
+                }
+                b'[' => {
+                    self.phase = Phase::InArray;
+                    Ok(())
+                }
+                _ => Err(self.error("is not an array")),
+            };
+        }
+
+        match byte {
+            b'{' | b'[' => {
+                self.depth += 1;
+                if self.depth == 1 {
+                    if byte == b'[' {
+                        return Err(self.error("cannot be found in a top-level array"));
+                    }
+                    self.expect_key = true;
+                }
+            }
+            b'}' | b']' => {
+                self.depth = self.depth.saturating_sub(1);
+                if self.depth == 0 {
+                    return Err(self.error("is missing"));
+                }
+            }
+            b':' if self.depth == 1 => self.expect_key = false,
+            b',' if self.depth == 1 => self.expect_key = true,
+            b'"' => {
+                self.in_string = true;
+                self.capturing_key = self.depth == 1 && self.expect_key;
+                self.key.clear();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn scan(&mut self, byte: u8) -> Result<Option<Vec<u8>>, Error> {
+        if self.in_string {
+            self.element.push(byte);
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return Ok(None);
+        }
+
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.element.push(byte);
+            }
+            b'{' | b'[' => {
+                self.nesting += 1;
+                self.element.push(byte);
+            }
+            b'}' | b']' if self.nesting > 0 => {
+                self.nesting -= 1;
+                self.element.push(byte);
+            }
+            b']' => {
+                self.phase = Phase::Done;
+                if !self.element.is_empty() {
+                    return Ok(Some(std::mem::take(&mut self.element)));
+                }
+                // a trailing comma, as in `[1,]`
+                if self.after_comma {
+                    return Err(self.error("contains an empty element"));
+                }
+            }
+            b',' if self.nesting == 0 => {
+                if self.element.is_empty() {
+                    return Err(self.error("contains an empty element"));
+                }
+                self.after_comma = true;
+                return Ok(Some(std::mem::take(&mut self.element)));
+            }
+            byte if byte.is_ascii_whitespace() && self.element.is_empty() => {}
+            byte => self.element.push(byte),
+        }
+        Ok(None)
+    }
+
+    fn error(&self, reason: &str) -> Error {
+        serde_json::Error::custom(format!("the `{}` array {}", self.field, reason)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use serde::Deserialize;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Item {
+        id: u64,
+        name: String,
+    }
+
+    fn chunked(
+        body: &'static str,
+        chunk_len: usize,
+    ) -> impl Stream<Item = Result<Bytes, StreamError>> + Unpin {
+        let chunks: Vec<_> = body
+            .as_bytes()
+            .chunks(chunk_len)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+        futures::stream::iter(chunks)
+    }
+
+    #[test]
+    fn yields_the_same_items_as_the_buffered_path() {
+        futures::executor::block_on(async {
+            const BODY: &str = r#"{"_rid":"x]y","odata":{"value":[1]},"value" : [
+                {"id":1,"name":"a \"quoted\" ] name"},
+                {"id":2,"name":"nested { [ braces"},
+                {"id":3,"name":"\\"}
+            ],"count":3}"#;
+
+            #[derive(Deserialize)]
+            struct Buffered {
+                value: Vec<Item>,
+            }
+            let expected = serde_json::from_str::<Buffered>(BODY).unwrap().value;
+
+            // one byte at a time exercises every possible chunk boundary
+            for chunk_len in &[1, 7, BODY.len()] {
+                let items: Vec<Item> = json_array_stream(chunked(BODY, *chunk_len), "value")
+                    .try_collect()
+                    .await
+                    .unwrap();
+                assert_eq!(items, expected);
+            }
+        });
+    }
+
+    #[test]
+    fn yields_scalars_and_empty_arrays() {
+        futures::executor::block_on(async {
+            let numbers: Vec<u64> =
+                json_array_stream::<u64, _>(chunked(r#"{"value":[1, 22 ,333]}"#, 2), "value")
+                    .try_collect()
+                    .await
+                    .unwrap();
+            assert_eq!(numbers, vec![1, 22, 333]);
+
+            let empty: Vec<u64> =
+                json_array_stream::<u64, _>(chunked(r#"{"value":[ ]}"#, 2), "value")
+                    .try_collect()
+                    .await
+                    .unwrap();
+            assert!(empty.is_empty());
+        });
+    }
+
+    #[test]
+    fn malformed_bodies_are_errors() {
+        futures::executor::block_on(async {
+            for body in &[
+                r#"{"other":[1]}"#,
+                r#"{"value":1}"#,
+                r#"{"value":[1,,2]}"#,
+                r#"{"value":[1,]}"#,
+                r#"{"value":[1, ]}"#,
+                r#"{"value":[1,2"#,
+            ] {
+                let result: Result<Vec<u64>, _> =
+                    json_array_stream::<u64, _>(chunked(body, 3), "value")
+                        .try_collect()
+                        .await;
+                assert!(result.is_err(), "{} should not parse", body);
+            }
+        });
+    }
+
+    #[test]
+    fn items_are_yielded_before_the_body_is_read() {
+        futures::executor::block_on(async {
+            const ITEMS: usize = 100_000;
+            let chunks_read = Arc::new(AtomicUsize::new(0));
+
+            // A body that is only generated as it is read, one element per chunk.
+            let counter = chunks_read.clone();
+            let body = futures::stream::unfold(0, move |i| {
+                let counter = counter.clone();
+                async move {
+                    let chunk = match i {
+                        0 => "{\"value\":[".to_owned(),
+                        i if i <= ITEMS => format!(
+                            "{}{{\"id\":{},\"name\":\"item {}\"}}",
+                            if i == 1 { "" } else { "," },
+                            i,
+                            i
+                        ),
+                        i if i == ITEMS + 1 => "]}".to_owned(),
+                        _ => return None,
+                    };
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Some((Ok(Bytes::from(chunk)), i + 1))
+                }
+            });
+
+            let mut items = Box::pin(json_array_stream::<Item, _>(Box::pin(body), "value"));
+            for id in 1..=3 {
+                assert_eq!(items.next().await.unwrap().unwrap().id, id);
+            }
+            // An element is only complete once the following comma has been read.
+            assert!(chunks_read.load(Ordering::SeqCst) <= 5);
+        });
+    }
+}
@@ -0,0 +1,71 @@
+use serde_json::Value;
+
+/// Typed access to the fields of free-form JSON, such as the `info` of an `ErrorAdditionalInfo`.
+///
+/// Fields are addressed with a dotted path: each segment is either an object key or, for arrays,
+/// an index (`"details.0.code"`). An empty path is the value itself.
+pub trait JsonValueExt {
+    /// The value at `path`, if there is one.
+    fn get_path(&self, path: &str) -> Option<&Value>;
+
+    /// The string at `path`, if there is one and it is a string.
+    fn get_str(&self, path: &str) -> Option<&str> {
+        self.get_path(path).and_then(Value::as_str)
+    }
+
+    /// The integer at `path`, if there is one and it fits in an `i64`.
+    fn get_i64(&self, path: &str) -> Option<i64> {
+        self.get_path(path).and_then(Value::as_i64)
+    }
+}
+
+impl JsonValueExt for Value {
+    fn get_path(&self, path: &str) -> Option<&Value> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        path.split('.')
+            .try_fold(self, |value, segment| match value {
+                Value::Object(map) => map.get(segment),
+                Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn navigates_nested_objects_and_arrays() {
+        let info = json!({
+            "policy": {
+                "name": "allowed-locations",
+                "version": 3,
+                "locations": ["westeurope", "northeurope"],
+            },
+            "retryable": false,
+        });
+
+        assert_eq!(info.get_str("policy.name"), Some("allowed-locations"));
+        assert_eq!(info.get_i64("policy.version"), Some(3));
+        assert_eq!(info.get_str("policy.locations.1"), Some("northeurope"));
+        assert_eq!(info.get_path("retryable"), Some(&json!(false)));
+        assert_eq!(info.get_path(""), Some(&info));
+    }
+
+    #[test]
+    fn missing_or_mistyped_fields_are_none() {
+        let info =
+            json!({ "policy": { "name": "allowed-locations", "locations": ["westeurope"] } });
+
+        assert_eq!(info.get_str("policy.missing"), None);
+        assert_eq!(info.get_str("policy.name.inner"), None);
+        assert_eq!(info.get_str("policy.locations.1"), None);
+        assert_eq!(info.get_str("policy.locations.first"), None);
+        assert_eq!(info.get_i64("policy.name"), None);
+        assert_eq!(info.get_str("policy"), None);
+    }
+}
@@ -7,18 +7,23 @@ extern crate serde_derive;
 #[macro_use]
 mod macros;
 
+mod arm_throttling;
 mod bytes_stream;
+mod cancellation_token;
 mod constants;
 mod context;
 mod errors;
 pub mod headers;
 mod http_client;
 pub mod incompletevector;
+mod json_stream;
+mod json_value_ext;
 mod models;
 mod options;
 pub mod parsing;
 pub mod pipeline;
 mod policies;
+mod poll_result;
 pub mod prelude;
 mod request;
 mod request_options;
@@ -33,18 +38,24 @@ use oauth2::AccessToken;
 use std::fmt::Debug;
 use uuid::Uuid;
 
+pub use arm_throttling::{ArmRateLimit, ArmThrottlingHttpClient};
 pub use bytes_stream::*;
+pub use cancellation_token::{CancellationToken, Cancelled};
 pub use constants::*;
 pub use context::Context;
 pub use errors::*;
 pub use headers::AddAsHeader;
-pub use http_client::{new_http_client, to_json, HttpClient};
+pub use http_client::{new_http_client, new_http_client_with_tls, to_json, HttpClient};
+pub use json_stream::json_array_stream;
+pub use json_value_ext::JsonValueExt;
 pub use models::*;
 pub use options::*;
-pub use policies::{Policy, PolicyResult};
+pub use policies::{Policy, PolicyResult, RetryBudget};
+pub use poll_result::{IntoPollResult, PollResult};
 pub use request::*;
 pub use response::*;
 pub use seekable_stream::*;
+pub use sleep::{sleep, Sleep};
 
 pub type RequestId = Uuid;
 pub type SessionToken = String;
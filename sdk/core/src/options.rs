@@ -1,4 +1,6 @@
-use crate::policies::{ExponentialRetryPolicy, FixedRetryPolicy, NoRetryPolicy, Policy};
+use crate::policies::{
+    ExponentialRetryPolicy, FixedRetryPolicy, NoRetryPolicy, Policy, RetryBudget,
+};
 use crate::{new_http_client, HttpClient};
 use std::sync::Arc;
 use std::time::Duration;
@@ -45,6 +47,11 @@ impl ClientOptions {
         &mut self.per_retry_policies
     }
 
+    /// A mutable reference to retry options.
+    pub fn retry_mut(&mut self) -> &mut RetryOptions {
+        &mut self.retry
+    }
+
     setters! {
         per_call_policies: Vec<Arc<dyn Policy>> => per_call_policies,
         per_retry_policies: Vec<Arc<dyn Policy>> => per_retry_policies,
@@ -98,6 +105,12 @@ pub struct RetryOptions {
     ///
     /// The default is 1 minute.
     max_delay: Duration,
+
+    /// The budget shared with the other operations of the client, capping the retries of all of
+    /// them together.
+    ///
+    /// There is no budget by default.
+    budget: Option<RetryBudget>,
 }
 
 impl RetryOptions {
@@ -106,6 +119,7 @@ impl RetryOptions {
         delay: Duration => delay,
         max_retries: u32 => max_retries,
         max_delay: Duration => max_delay,
+        budget: RetryBudget => Some(budget),
     }
 }
 
@@ -116,6 +130,7 @@ impl Default for RetryOptions {
             delay: Duration::from_millis(800),
             max_retries: 3,
             max_delay: Duration::from_secs(60),
+            budget: None,
         }
     }
 }
@@ -123,16 +138,14 @@ impl Default for RetryOptions {
 impl RetryOptions {
     pub(crate) fn to_policy(&self) -> Arc<dyn Policy> {
         match self.mode {
-            RetryMode::Exponential => Arc::new(ExponentialRetryPolicy::new(
-                self.delay,
-                self.max_retries,
-                self.max_delay,
-            )),
-            RetryMode::Fixed => Arc::new(FixedRetryPolicy::new(
-                self.delay,
-                self.max_retries,
-                self.max_delay,
-            )),
+            RetryMode::Exponential => Arc::new(
+                ExponentialRetryPolicy::new(self.delay, self.max_retries, self.max_delay)
+                    .with_budget(self.budget.clone()),
+            ),
+            RetryMode::Fixed => Arc::new(
+                FixedRetryPolicy::new(self.delay, self.max_retries, self.max_delay)
+                    .with_budget(self.budget.clone()),
+            ),
             RetryMode::None => Arc::new(NoRetryPolicy::default()),
         }
     }
@@ -173,3 +186,84 @@ impl Default for TransportOptions {
         }
     }
 }
+
+/// A version of the TLS protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// TLS 1.2
+    Tls1_2,
+    /// TLS 1.3
+    Tls1_3,
+}
+
+/// A client certificate and its private key, presented to the server during the TLS handshake.
+///
+/// The `Debug` output leaves the key material and the password out.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Identity {
+    /// A DER-encoded PKCS#12 archive and the password protecting it.
+    Pkcs12 { der: Vec<u8>, password: String },
+    /// A PEM-encoded certificate chain and its PEM-encoded PKCS#8 private key.
+    Pem {
+        certificate: Vec<u8>,
+        private_key: Vec<u8>,
+    },
+}
+
+impl Identity {
+    /// An identity read from a DER-encoded PKCS#12 archive.
+    pub fn from_pkcs12_der(der: impl Into<Vec<u8>>, password: impl Into<String>) -> Self {
+        Self::Pkcs12 {
+            der: der.into(),
+            password: password.into(),
+        }
+    }
+
+    /// An identity read from a PEM-encoded certificate chain and PKCS#8 private key.
+    pub fn from_pem(certificate: impl Into<Vec<u8>>, private_key: impl Into<Vec<u8>>) -> Self {
+        Self::Pem {
+            certificate: certificate.into(),
+            private_key: private_key.into(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Identity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pkcs12 { .. } => f.write_str("Identity::Pkcs12"),
+            Self::Pem { .. } => f.write_str("Identity::Pem"),
+        }
+    }
+}
+
+/// The TLS settings of an `HttpClient` created by [`new_http_client_with_tls`](crate::new_http_client_with_tls).
+///
+/// The cipher suites cannot be restricted: neither the `reqwest` nor the `hyper` backend exposes
+/// them. Both only offer suites of TLS 1.2 and later that are considered secure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// The oldest TLS version the client negotiates.
+    ///
+    /// The default is TLS 1.2.
+    pub(crate) min_tls_version: TlsVersion,
+
+    /// The certificate the client authenticates with, if the server asks for one.
+    pub(crate) client_identity: Option<Identity>,
+}
+
+impl TlsOptions {
+    setters! {
+        min_tls_version: TlsVersion => min_tls_version,
+        client_identity: Identity => Some(client_identity),
+    }
+}
+
+impl Default for TlsOptions {
+    fn default() -> Self {
+        Self {
+            min_tls_version: TlsVersion::Tls1_2,
+            client_identity: None,
+        }
+    }
+}
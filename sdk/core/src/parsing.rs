@@ -40,6 +40,12 @@ pub fn from_azure_time(s: &str) -> Result<chrono::DateTime<chrono::Utc>, chrono:
     Ok(dt_utc)
 }
 
+/// Formats `date` as the RFC 1123 HTTP-date of the `If-Modified-Since` family of headers, such as
+/// `Sun, 27 Sep 2009 17:26:40 GMT`.
+pub fn to_azure_time(date: &chrono::DateTime<chrono::Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
 pub mod rfc2822_time_format {
     use super::from_azure_time;
     use chrono::{DateTime, Utc};
@@ -87,6 +93,76 @@ pub mod rfc2822_time_format_optional {
     }
 }
 
+/// An integer the service sends either as a JSON number or as a numeric string, such as `123` or
+/// `"123"`. It is always serialized as a number.
+pub mod number_or_string {
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub(super) enum NumberOrString<T> {
+        Number(T),
+        String(String),
+    }
+
+    impl<T> NumberOrString<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        pub(super) fn into_number<E: serde::de::Error>(self) -> Result<T, E> {
+            match self {
+                NumberOrString::Number(number) => Ok(number),
+                NumberOrString::String(s) => s.trim().parse().map_err(E::custom),
+            }
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + FromStr,
+        T::Err: Display,
+    {
+        NumberOrString::deserialize(deserializer)?.into_number()
+    }
+
+    pub fn serialize<S, T>(number: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        number.serialize(serializer)
+    }
+}
+
+pub mod number_or_string_optional {
+    use super::number_or_string::NumberOrString;
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + FromStr,
+        T::Err: Display,
+    {
+        let number: Option<NumberOrString<T>> = Option::deserialize(deserializer)?;
+        number.map(NumberOrString::into_number).transpose()
+    }
+
+    pub fn serialize<S, T>(number: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        number.serialize(serializer)
+    }
+}
+
 #[inline]
 #[cfg(feature = "azurite_workaround")]
 pub fn from_azure_time(s: &str) -> Result<chrono::DateTime<chrono::Utc>, chrono::ParseError> {
@@ -112,4 +188,52 @@ mod test {
         assert_eq!(t.hour(), 17);
         assert_eq!(t.second(), 40);
     }
+
+    #[test]
+    fn test_to_azure_time() {
+        let t = super::from_azure_time("Mon, 01 Jan 1900 08:05:03 GMT").unwrap();
+
+        assert_eq!(super::to_azure_time(&t), "Mon, 01 Jan 1900 08:05:03 GMT");
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Order {
+        #[serde(with = "super::number_or_string")]
+        quantity: u32,
+        #[serde(default, with = "super::number_or_string_optional")]
+        etag: Option<i64>,
+    }
+
+    #[test]
+    fn test_number_or_string() {
+        let order: Order = serde_json::from_str(r#"{ "quantity": 3, "etag": 123 }"#).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                quantity: 3,
+                etag: Some(123)
+            }
+        );
+
+        let order: Order = serde_json::from_str(r#"{ "quantity": "3", "etag": "123" }"#).unwrap();
+        assert_eq!(
+            order,
+            Order {
+                quantity: 3,
+                etag: Some(123)
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&order).unwrap(),
+            r#"{"quantity":3,"etag":123}"#
+        );
+
+        let order: Order = serde_json::from_str(r#"{ "quantity": 3, "etag": null }"#).unwrap();
+        assert_eq!(order.etag, None);
+        let order: Order = serde_json::from_str(r#"{ "quantity": 3 }"#).unwrap();
+        assert_eq!(order.etag, None);
+
+        assert!(serde_json::from_str::<Order>(r#"{ "quantity": "three" }"#).is_err());
+        assert!(serde_json::from_str::<Order>(r#"{ "quantity": true }"#).is_err());
+    }
 }
@@ -81,10 +81,33 @@ impl Pipeline {
         self.http_client.as_ref()
     }
 
+    /// Gets a handle on the `HttpClient` used by the pipeline.
+    ///
+    /// Pipelines built with this handle as their transport share its connection pool.
+    pub fn shared_http_client(&self) -> Arc<dyn HttpClient> {
+        self.http_client.clone()
+    }
+
+    /// Sends the request through the pipeline policies.
+    ///
+    /// # Cancellation
+    ///
+    /// The returned future is cancellation safe: it does not spawn detached tasks and owns
+    /// everything needed to complete the request. Dropping it (for example because it lost a
+    /// `select!` race or an upstream timeout fired) aborts the request at whatever point it has
+    /// reached. An in-flight transport call is dropped along with its connection, and a pending
+    /// retry back-off is stopped. Neither the `Context` nor the `Request` is left half-modified
+    /// in a way that prevents reuse, but the request may or may not have reached the service.
+    ///
+    /// Errors raised by policies are returned as [`Error::PolicyError`], unless they already are
+    /// an [`Error`] (for example [`Error::DeadlineExceeded`]), in which case they are returned as is.
     pub async fn send(&self, ctx: &mut Context, request: &mut Request) -> Result<Response, Error> {
         self.pipeline[0]
             .send(ctx, request, &self.pipeline[1..])
             .await
-            .map_err(Error::PolicyError)
+            .map_err(|error| match error.downcast::<Error>() {
+                Ok(error) => *error,
+                Err(error) => Error::PolicyError(error),
+            })
     }
 }
@@ -1,5 +1,5 @@
+use super::{check_context, is_transient, retry_after, wait_before_retry, RetryBudget};
 use crate::policies::{Context, Policy, PolicyResult, Request, Response};
-use crate::sleep::sleep;
 use chrono::{DateTime, Local};
 use std::sync::Arc;
 use std::time::Duration;
@@ -11,11 +11,15 @@ use std::time::Duration;
 /// second will be x*2, the third x*4 and so on. The policy will retry until the maximum number of
 /// retries have been reached or the maximum allowed delay has passed (whichever comes first). The
 /// wait time is not precise.
+///
+/// Both failed attempts and `503 Service Unavailable` responses are retried, the latter after
+/// the seconds of their `Retry-After` header when they have one.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExponentialRetryPolicy {
     delay: Duration,
     max_retries: u32,
     max_delay: Duration,
+    budget: Option<RetryBudget>,
 }
 
 impl ExponentialRetryPolicy {
@@ -24,9 +28,15 @@ impl ExponentialRetryPolicy {
             delay,
             max_retries,
             max_delay,
+            budget: None,
         }
     }
 
+    /// Also stops retrying when `budget`, shared with other policies, is exhausted.
+    pub(crate) fn with_budget(self, budget: Option<RetryBudget>) -> Self {
+        Self { budget, ..self }
+    }
+
     fn is_expired(
         &self,
         first_retry_time: &mut Option<DateTime<Local>>,
@@ -55,23 +65,35 @@ impl Policy for ExponentialRetryPolicy {
     ) -> PolicyResult<Response> {
         let mut first_retry_time = None;
         let mut current_retries = 0;
+        if let Some(budget) = &self.budget {
+            budget.deposit();
+        }
 
         loop {
-            match next[0].send(ctx, request, &next[1..]).await {
-                Ok(response) => return Ok(response),
-                Err(error) => {
-                    if self.is_expired(&mut first_retry_time, &mut current_retries) {
-                        return Err(error);
-                    } else {
-                        current_retries += 1;
-
-                        let sleep_ms = self.delay.as_millis() as u64
-                            * u64::pow(2u64, current_retries - 1)
-                            + rand::random::<u8>() as u64;
-                        sleep(Duration::from_millis(sleep_ms)).await;
-                    }
+            check_context(ctx)?;
+            // a transient response is returned as is once the retries are exhausted
+            let (result, requested_delay) = match next[0].send(ctx, request, &next[1..]).await {
+                Ok(response) if is_transient(&response) => {
+                    let requested_delay = retry_after(&response);
+                    (Ok(response), requested_delay)
                 }
+                Ok(response) => return Ok(response),
+                Err(error) => (Err(error), None),
+            };
+            if self.is_expired(&mut first_retry_time, &mut current_retries)
+                || matches!(&self.budget, Some(budget) if !budget.withdraw())
+            {
+                return result;
             }
+            current_retries += 1;
+
+            let sleep_ms = self.delay.as_millis() as u64 * u64::pow(2u64, current_retries - 1)
+                + rand::random::<u8>() as u64;
+            wait_before_retry(
+                ctx,
+                requested_delay.unwrap_or(Duration::from_millis(sleep_ms)),
+            )
+            .await?;
         }
     }
 }
@@ -1,5 +1,5 @@
+use super::{check_context, is_transient, retry_after, wait_before_retry, RetryBudget};
 use crate::policies::{Context, Policy, PolicyResult, Request, Response};
-use crate::sleep::sleep;
 use chrono::{DateTime, Local};
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,11 +10,15 @@ use std::time::Duration;
 /// happen at least after the same, configured sleep time. The policy will retry until the maximum number of
 /// retries have been reached or the maximum allowed delay has passed (whichever comes first). The
 /// wait time is not precise.
+///
+/// Both failed attempts and `503 Service Unavailable` responses are retried, the latter after
+/// the seconds of their `Retry-After` header when they have one.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FixedRetryPolicy {
     delay: Duration,
     max_retries: u32,
     max_delay: Duration,
+    budget: Option<RetryBudget>,
 }
 
 impl FixedRetryPolicy {
@@ -23,9 +27,15 @@ impl FixedRetryPolicy {
             delay,
             max_retries,
             max_delay,
+            budget: None,
         }
     }
 
+    /// Also stops retrying when `budget`, shared with other policies, is exhausted.
+    pub(crate) fn with_budget(self, budget: Option<RetryBudget>) -> Self {
+        Self { budget, ..self }
+    }
+
     fn is_expired(
         &self,
         first_retry_time: &mut Option<DateTime<Local>>,
@@ -54,21 +64,232 @@ impl Policy for FixedRetryPolicy {
     ) -> PolicyResult<Response> {
         let mut first_retry_time = None;
         let mut current_retries = 0;
+        if let Some(budget) = &self.budget {
+            budget.deposit();
+        }
 
         loop {
-            match next[0].send(ctx, request, &next[1..]).await {
+            check_context(ctx)?;
+            // a transient response is returned as is once the retries are exhausted
+            let (result, requested_delay) = match next[0].send(ctx, request, &next[1..]).await {
+                Ok(response) if is_transient(&response) => {
+                    let requested_delay = retry_after(&response);
+                    (Ok(response), requested_delay)
+                }
                 Ok(response) => return Ok(response),
-                Err(error) => {
-                    if self.is_expired(&mut first_retry_time, &mut current_retries) {
-                        return Err(error);
-                    } else {
-                        current_retries += 1;
-
-                        let sleep_ms = self.delay.as_millis() as u64 + rand::random::<u8>() as u64;
-                        sleep(Duration::from_millis(sleep_ms)).await;
-                    }
+                Err(error) => (Err(error), None),
+            };
+            if self.is_expired(&mut first_retry_time, &mut current_retries)
+                || matches!(&self.budget, Some(budget) if !budget.withdraw())
+            {
+                return result;
+            }
+            current_retries += 1;
+
+            let sleep_ms = self.delay.as_millis() as u64 + rand::random::<u8>() as u64;
+            wait_before_retry(
+                ctx,
+                requested_delay.unwrap_or(Duration::from_millis(sleep_ms)),
+            )
+            .await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CancellationToken, Error, ResponseBuilder};
+    use http::header::RETRY_AFTER;
+    use http::{HeaderValue, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    #[derive(Debug, Default)]
+    struct FailingPolicy {
+        attempts: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for FailingPolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err("transient failure".into())
+        }
+    }
+
+    /// Answers `503 Service Unavailable` with `retry_after`, then `200 OK`.
+    #[derive(Debug)]
+    struct UnavailablePolicy {
+        attempts: AtomicUsize,
+        retry_after: Option<&'static str>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for UnavailablePolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            let response = if self.attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                let mut response = ResponseBuilder::new(StatusCode::SERVICE_UNAVAILABLE);
+                if let Some(retry_after) = self.retry_after {
+                    response.with_header(&RETRY_AFTER, HeaderValue::from_static(retry_after));
                 }
+                response
+            } else {
+                ResponseBuilder::new(StatusCode::OK)
+            };
+            Ok(response.with_pinned_stream(Box::pin(futures::stream::empty())))
+        }
+    }
+
+    #[test]
+    fn service_unavailable_is_retried_after_its_retry_after() {
+        let policy = FixedRetryPolicy::new(Duration::from_secs(30), 5, Duration::from_secs(60));
+        let unavailable = Arc::new(UnavailablePolicy {
+            attempts: AtomicUsize::new(0),
+            retry_after: Some("1"),
+        });
+        let next: Vec<Arc<dyn Policy>> = vec![unavailable.clone()];
+
+        let mut request: Request = http::Request::new(bytes::Bytes::new()).into();
+        let start = Instant::now();
+        let response =
+            futures::executor::block_on(policy.send(&mut Context::new(), &mut request, &next))
+                .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(unavailable.attempts.load(Ordering::SeqCst), 2);
+        // the one second of `Retry-After`, not the 30 of the back-off
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_secs(1));
+        assert!(elapsed < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn service_unavailable_without_retry_after_is_retried_after_the_back_off() {
+        let policy = FixedRetryPolicy::new(Duration::from_millis(0), 5, Duration::from_secs(60));
+        let unavailable = Arc::new(UnavailablePolicy {
+            attempts: AtomicUsize::new(0),
+            retry_after: None,
+        });
+        let next: Vec<Arc<dyn Policy>> = vec![unavailable.clone()];
+
+        let mut request: Request = http::Request::new(bytes::Bytes::new()).into();
+        let start = Instant::now();
+        let response =
+            futures::executor::block_on(policy.send(&mut Context::new(), &mut request, &next))
+                .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(unavailable.attempts.load(Ordering::SeqCst), 2);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    /// Answers `503 Service Unavailable` with `Retry-After: 0`, then fails.
+    #[derive(Debug, Default)]
+    struct UnavailableThenFailingPolicy {
+        attempts: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for UnavailableThenFailingPolicy {
+        async fn send(
+            &self,
+            _ctx: &mut Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult<Response> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) > 0 {
+                return Err("transient failure".into());
             }
+            let mut response = ResponseBuilder::new(StatusCode::SERVICE_UNAVAILABLE);
+            response.with_header(&RETRY_AFTER, HeaderValue::from_static("0"));
+            Ok(response.with_pinned_stream(Box::pin(futures::stream::empty())))
         }
     }
+
+    #[test]
+    fn deadline_prevents_further_retries() {
+        // The immediate retry asked by `Retry-After: 0` starts before the deadline, but the
+        // minute of back-off after the second attempt would overrun it.
+        let policy = FixedRetryPolicy::new(Duration::from_secs(60), 5, Duration::from_secs(600));
+        let failing = Arc::new(UnavailableThenFailingPolicy::default());
+        let next: Vec<Arc<dyn Policy>> = vec![failing.clone()];
+
+        let mut ctx = Context::new().with_deadline(Instant::now() + Duration::from_secs(30));
+        let mut request: Request = http::Request::new(bytes::Bytes::new()).into();
+        let start = Instant::now();
+        let error = match futures::executor::block_on(policy.send(&mut ctx, &mut request, &next)) {
+            Ok(_) => panic!("the failing policy never succeeds"),
+            Err(error) => error,
+        };
+
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::DeadlineExceeded)
+        ));
+        assert_eq!(failing.attempts.load(Ordering::SeqCst), 2);
+        // giving up does not wait for the back-off that would overrun the deadline
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn cancelling_stops_further_retries() {
+        let policy = FixedRetryPolicy::new(Duration::from_secs(30), 5, Duration::from_secs(60));
+        let failing = Arc::new(FailingPolicy::default());
+        let next: Vec<Arc<dyn Policy>> = vec![failing.clone()];
+
+        let token = CancellationToken::new();
+        let mut ctx = Context::new().with_cancellation_token(token.clone());
+        let mut request: Request = http::Request::new(bytes::Bytes::new()).into();
+
+        // cancel while the policy waits before the first retry
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        });
+        let start = Instant::now();
+        let error = match futures::executor::block_on(policy.send(&mut ctx, &mut request, &next)) {
+            Ok(_) => panic!("the failing policy never succeeds"),
+            Err(error) => error,
+        };
+        canceller.join().unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::Cancelled)
+        ));
+        assert_eq!(failing.attempts.load(Ordering::SeqCst), 1);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn exhausted_budget_suppresses_retries() {
+        // The first operation spends the two retries of the full budget, then every other one
+        // earns a retry: ten failing operations are retried 6 times in total instead of 5 times
+        // each.
+        let budget = RetryBudget::new(0.5, 2);
+        let policy = FixedRetryPolicy::new(Duration::from_millis(0), 5, Duration::from_secs(60))
+            .with_budget(Some(budget));
+        let failing = Arc::new(FailingPolicy::default());
+        let next: Vec<Arc<dyn Policy>> = vec![failing.clone()];
+
+        for _ in 0..10 {
+            let mut request: Request = http::Request::new(bytes::Bytes::new()).into();
+            let result =
+                futures::executor::block_on(policy.send(&mut Context::new(), &mut request, &next));
+            assert!(result.is_err());
+        }
+
+        assert_eq!(failing.attempts.load(Ordering::SeqCst), 10 + 6);
+    }
 }
@@ -1,7 +1,118 @@
 mod exponential_retry;
 mod fixed_retry;
 mod no_retry;
+mod retry_budget;
 
 pub use exponential_retry::*;
 pub use fixed_retry::*;
 pub use no_retry::*;
+pub use retry_budget::*;
+
+use crate::policies::{Context, PolicyResult, Response};
+use crate::sleep::sleep;
+use crate::Error;
+use futures::future::select;
+use http::{header::RETRY_AFTER, StatusCode};
+use std::time::{Duration, Instant};
+
+/// Fails if the operation has been cancelled or its deadline has passed.
+fn check_context(ctx: &Context) -> PolicyResult<()> {
+    if matches!(ctx.cancellation_token(), Some(token) if token.is_cancelled()) {
+        return Err(Box::new(Error::Cancelled));
+    }
+    match ctx.deadline() {
+        Some(deadline) if Instant::now() >= deadline => Err(Box::new(Error::DeadlineExceeded)),
+        _ => Ok(()),
+    }
+}
+
+/// Whether `response` is a transient failure, retried like a failed attempt.
+///
+/// The service answers `503 Service Unavailable` during failovers and maintenance.
+fn is_transient(response: &Response) -> bool {
+    response.status() == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// The delay the `Retry-After` header of `response` asks for, in seconds.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Waits `delay` before the next attempt.
+///
+/// If the next attempt would start after the context deadline this returns
+/// `DeadlineExceeded` right away instead of waiting for an attempt that will never be made.
+/// Cancelling the context token interrupts the wait with `Cancelled`.
+async fn wait_before_retry(ctx: &Context, delay: Duration) -> PolicyResult<()> {
+    if let Some(deadline) = ctx.deadline() {
+        if Instant::now() + delay >= deadline {
+            return Err(Box::new(Error::DeadlineExceeded));
+        }
+    }
+    match ctx.cancellation_token() {
+        Some(token) => {
+            if !token.is_cancelled() {
+                select(sleep(delay), token.cancelled()).await;
+            }
+            // the sleep may have been polled first as the token was cancelled
+            if token.is_cancelled() {
+                Err(Box::new(Error::Cancelled))
+            } else {
+                Ok(())
+            }
+        }
+        None => {
+            sleep(delay).await;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CancellationToken;
+
+    #[test]
+    fn cancelling_interrupts_the_wait_before_a_retry() {
+        let token = CancellationToken::new();
+        let ctx = Context::new().with_cancellation_token(token.clone());
+
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        });
+        let start = Instant::now();
+        let error = futures::executor::block_on(wait_before_retry(&ctx, Duration::from_secs(60)))
+            .unwrap_err();
+        canceller.join().unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::Cancelled)
+        ));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn a_cancelled_token_does_not_wait() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let ctx = Context::new().with_cancellation_token(token);
+
+        let error = futures::executor::block_on(wait_before_retry(&ctx, Duration::from_millis(0)))
+            .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::Cancelled)
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use super::check_context;
 use crate::policies::{Context, Policy, PolicyResult, Request, Response};
 use std::sync::Arc;
 
@@ -17,6 +18,7 @@ impl Policy for NoRetryPolicy {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult<Response> {
+        check_context(ctx)?;
         // just call the following policies and bubble up the error
         next[0].send(ctx, request, &next[1..]).await
     }
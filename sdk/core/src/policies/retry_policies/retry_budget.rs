@@ -0,0 +1,61 @@
+use std::sync::{Arc, Mutex};
+
+/// A budget of retries shared by all the operations of a client.
+///
+/// The budget is a token bucket: every operation adds `ratio` tokens, up to `max_retries`, and
+/// every retry takes one. Once the bucket is empty, failures are returned without being retried,
+/// so during an outage the retries stay around `ratio` of the operations instead of multiplying
+/// the load on the service. The per-operation limits of the retry policy still apply.
+///
+/// Cloning a budget shares its tokens.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    tokens: Arc<Mutex<f64>>,
+    ratio: f64,
+    max_retries: f64,
+}
+
+impl RetryBudget {
+    /// A budget allowing a retry for `ratio` of the operations (`0.1` is 10%) and bursts of up to
+    /// `max_retries` retries. The budget starts full.
+    pub fn new(ratio: f64, max_retries: u32) -> Self {
+        Self {
+            tokens: Arc::new(Mutex::new(max_retries as f64)),
+            ratio,
+            max_retries: max_retries as f64,
+        }
+    }
+
+    /// Credits the budget for a new operation.
+    pub(crate) fn deposit(&self) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = (*tokens + self.ratio).min(self.max_retries);
+    }
+
+    /// Takes the token of a retry, returning `false` if the budget is exhausted.
+    pub(crate) fn withdraw(&self) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for RetryBudget {
+    /// Retries for 10% of the operations, in bursts of up to 10.
+    fn default() -> Self {
+        Self::new(0.1, 10)
+    }
+}
+
+impl PartialEq for RetryBudget {
+    /// Budgets are equal when they share their tokens.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.tokens, &other.tokens)
+    }
+}
+
+impl Eq for RetryBudget {}
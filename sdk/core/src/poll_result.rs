@@ -0,0 +1,18 @@
+/// Where a long-running operation stands, as told by the state of the resource it works on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollResult {
+    /// The operation completed.
+    Done,
+    /// The operation reached a state it will not complete from, named by the service.
+    Failed(String),
+    /// The operation is still running: poll again.
+    InProgress,
+}
+
+/// A resource state that tells whether the operation that produced it is over, such as the
+/// `ProvisioningState` enums of the management crates.
+pub trait IntoPollResult {
+    /// Where the operation stands now that the resource is in this state.
+    #[allow(clippy::wrong_self_convention)]
+    fn into_poll_result(&self) -> PollResult;
+}
@@ -1,6 +1,6 @@
 pub use crate::etag::Etag;
 pub use crate::request_options::*;
 pub use crate::{
-    new_http_client, AddAsHeader, AppendToUrlQuery, Context, HttpClient, RequestId, SessionToken,
-    EMPTY_BODY,
+    new_http_client, AddAsHeader, AppendToUrlQuery, Context, HttpClient, JsonValueExt, RequestId,
+    SessionToken, EMPTY_BODY,
 };
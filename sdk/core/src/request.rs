@@ -1,6 +1,7 @@
 use crate::SeekableStream;
+use http::header::{HeaderName, AUTHORIZATION};
 use http::{HeaderMap, Method, Uri};
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 #[derive(Debug, Clone)]
 pub enum Body {
@@ -61,6 +62,89 @@ impl Request {
     pub fn clone_body(&self) -> Body {
         self.body.clone()
     }
+
+    /// The headers sorted by name, for comparing requests with recordings and snapshots.
+    ///
+    /// The iteration order of a `HeaderMap` depends on how it was filled, this one does not. The
+    /// names are lowercase and the values of a name repeated several times keep their order.
+    /// Values that are not valid UTF-8 are converted lossily.
+    pub fn canonical_headers(&self) -> Vec<(String, String)> {
+        let mut headers: Vec<(String, String)> = self
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_owned(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        headers.sort_by(|(a, _), (b, _)| a.cmp(b));
+        headers
+    }
+
+    /// A view of the request safe to log.
+    ///
+    /// Its `Debug` implementation prints `<redacted>` instead of the value of the
+    /// `Authorization` header and of the `x-ms-*-key` headers. Other headers can be masked with
+    /// [`SanitizedRequest::redact`].
+    pub fn sanitized(&self) -> SanitizedRequest<'_> {
+        SanitizedRequest {
+            request: self,
+            secret_headers: Vec::new(),
+        }
+    }
+}
+
+/// A view of a [`Request`] whose `Debug` implementation masks secrets.
+///
+/// See [`Request::sanitized`].
+#[derive(Clone)]
+pub struct SanitizedRequest<'a> {
+    request: &'a Request,
+    secret_headers: Vec<HeaderName>,
+}
+
+impl<'a> SanitizedRequest<'a> {
+    /// Masks the value of an additional header.
+    pub fn redact(mut self, header_name: HeaderName) -> Self {
+        self.secret_headers.push(header_name);
+        self
+    }
+
+    fn is_secret(&self, header_name: &HeaderName) -> bool {
+        let name = header_name.as_str();
+        header_name == AUTHORIZATION
+            || (name.starts_with("x-ms-") && name.ends_with("-key"))
+            || self.secret_headers.contains(header_name)
+    }
+}
+
+impl<'a> Debug for SanitizedRequest<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Headers<'b>(&'b SanitizedRequest<'b>);
+
+        impl<'b> Debug for Headers<'b> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut map = f.debug_map();
+                for (name, value) in self.0.request.headers.iter() {
+                    if self.0.is_secret(name) {
+                        map.entry(name, &format_args!("<redacted>"));
+                    } else {
+                        map.entry(name, value);
+                    }
+                }
+                map.finish()
+            }
+        }
+
+        f.debug_struct("Request")
+            .field("uri", &self.request.uri)
+            .field("method", &self.request.method)
+            .field("headers", &Headers(self))
+            .field("body", &self.request.body)
+            .finish()
+    }
 }
 
 /// Temporary hack to convert preexisting requests into the new format. It
@@ -83,3 +167,98 @@ pub(crate) struct Parts {
     pub method: Method,
     pub headers: HeaderMap,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitized_requests_mask_secrets() {
+        let request: Request = http::Request::builder()
+            .uri("https://myaccount.documents.azure.com/dbs")
+            .header(
+                AUTHORIZATION,
+                "type%3Dmaster%26ver%3D1.0%26sig%3DdGhlIHNpZ25hdHVyZQ%3D%3D",
+            )
+            .header("x-ms-encryption-key", "a-customer-key")
+            .header("x-ms-session-token", "0:-1#12")
+            .header("x-ms-version", "2018-12-31")
+            .body(bytes::Bytes::new())
+            .unwrap()
+            .into();
+
+        let sanitized = format!(
+            "{:?}",
+            request
+                .sanitized()
+                .redact(HeaderName::from_static("x-ms-session-token"))
+        );
+        assert!(sanitized.contains(r#""authorization": <redacted>"#));
+        assert!(!sanitized.contains("dGhlIHNpZ25hdHVyZQ"));
+        assert!(!sanitized.contains("a-customer-key"));
+        assert!(!sanitized.contains("0:-1#12"));
+        assert!(sanitized.contains("2018-12-31"));
+
+        assert!(format!("{:?}", request).contains("dGhlIHNpZ25hdHVyZQ"));
+    }
+
+    #[test]
+    fn canonical_headers_do_not_depend_on_the_insertion_order() {
+        let request = |headers: &[(&str, &str)]| -> Request {
+            let mut builder =
+                http::Request::builder().uri("https://myaccount.documents.azure.com/dbs");
+            for (name, value) in headers {
+                builder = builder.header(*name, *value);
+            }
+            builder.body(bytes::Bytes::new()).unwrap().into()
+        };
+
+        let headers = [
+            ("x-ms-version", "2018-12-31"),
+            ("Accept", "application/json"),
+            ("x-ms-date", "mon, 01 jan 1900 01:00:00 gmt"),
+            ("A-IM", "Incremental feed"),
+        ];
+        let mut reversed = headers;
+        reversed.reverse();
+
+        let expected = vec![
+            ("a-im".to_owned(), "Incremental feed".to_owned()),
+            ("accept".to_owned(), "application/json".to_owned()),
+            (
+                "x-ms-date".to_owned(),
+                "mon, 01 jan 1900 01:00:00 gmt".to_owned(),
+            ),
+            ("x-ms-version".to_owned(), "2018-12-31".to_owned()),
+        ];
+        assert_eq!(request(&headers).canonical_headers(), expected);
+        assert_eq!(request(&reversed).canonical_headers(), expected);
+    }
+
+    #[test]
+    fn canonical_headers_keep_the_order_of_repeated_values() {
+        let request: Request = http::Request::builder()
+            .uri("https://myaccount.documents.azure.com/dbs")
+            .header("x-ms-documentdb-partitionkey", "[\"b\"]")
+            .header("accept", "application/json")
+            .header("x-ms-documentdb-partitionkey", "[\"a\"]")
+            .body(bytes::Bytes::new())
+            .unwrap()
+            .into();
+
+        assert_eq!(
+            request.canonical_headers(),
+            vec![
+                ("accept".to_owned(), "application/json".to_owned()),
+                (
+                    "x-ms-documentdb-partitionkey".to_owned(),
+                    "[\"b\"]".to_owned()
+                ),
+                (
+                    "x-ms-documentdb-partitionkey".to_owned(),
+                    "[\"a\"]".to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::AddAsHeader;
-use http::header::{IF_MATCH, IF_NONE_MATCH};
+use http::header::{HeaderValue, IF_MATCH, IF_NONE_MATCH};
 use http::request::Builder;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -15,4 +15,15 @@ impl<'a> AddAsHeader for IfMatchCondition<'a> {
             IfMatchCondition::NotMatch(etag) => builder.header(IF_NONE_MATCH, *etag),
         }
     }
+
+    fn add_as_header2(&self, request: &mut crate::Request) -> Result<(), http::Error> {
+        let (header_name, etag) = match self {
+            IfMatchCondition::Match(etag) => (IF_MATCH, etag),
+            IfMatchCondition::NotMatch(etag) => (IF_NONE_MATCH, etag),
+        };
+        request
+            .headers_mut()
+            .insert(header_name, HeaderValue::from_str(etag)?);
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
+use crate::parsing::to_azure_time;
 use crate::{headers, AddAsHeader};
 use chrono::{DateTime, Utc};
+use http::header::HeaderValue;
 use http::request::Builder;
 
 #[derive(Debug, Clone, Copy)]
@@ -13,6 +15,34 @@ impl<'a> IfModifiedSince<'a> {
 
 impl AddAsHeader for IfModifiedSince<'_> {
     fn add_as_header(&self, builder: Builder) -> Builder {
-        builder.header(headers::IF_MODIFIED_SINCE, self.0.to_rfc2822())
+        builder.header(headers::IF_MODIFIED_SINCE, to_azure_time(self.0))
+    }
+
+    fn add_as_header2(&self, request: &mut crate::Request) -> Result<(), http::Error> {
+        request.headers_mut().insert(
+            headers::IF_MODIFIED_SINCE,
+            HeaderValue::from_str(&to_azure_time(self.0))?,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::from_azure_time;
+
+    #[test]
+    fn test_rfc1123_header() {
+        let time = from_azure_time("Mon, 01 Jan 1900 00:00:00 +0000").unwrap();
+        let request = IfModifiedSince::new(&time)
+            .add_as_header(http::Request::builder())
+            .body(())
+            .unwrap();
+
+        assert_eq!(
+            request.headers()[headers::IF_MODIFIED_SINCE],
+            "Mon, 01 Jan 1900 00:00:00 GMT"
+        );
     }
 }
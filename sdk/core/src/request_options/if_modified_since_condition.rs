@@ -1,6 +1,7 @@
+use crate::parsing::to_azure_time;
 use crate::AddAsHeader;
 use chrono::{DateTime, Utc};
-use http::header::{IF_MODIFIED_SINCE, IF_UNMODIFIED_SINCE};
+use http::header::{HeaderValue, IF_MODIFIED_SINCE, IF_UNMODIFIED_SINCE};
 use http::request::Builder;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -13,11 +14,22 @@ impl AddAsHeader for IfModifiedSinceCondition {
     fn add_as_header(&self, builder: Builder) -> Builder {
         match self {
             IfModifiedSinceCondition::Modified(date) => {
-                builder.header(IF_MODIFIED_SINCE, &date.to_rfc2822() as &str)
+                builder.header(IF_MODIFIED_SINCE, to_azure_time(date))
             }
             IfModifiedSinceCondition::Unmodified(date) => {
-                builder.header(IF_UNMODIFIED_SINCE, &date.to_rfc2822() as &str)
+                builder.header(IF_UNMODIFIED_SINCE, to_azure_time(date))
             }
         }
     }
+
+    fn add_as_header2(&self, request: &mut crate::Request) -> Result<(), http::Error> {
+        let (header_name, date) = match self {
+            IfModifiedSinceCondition::Modified(date) => (IF_MODIFIED_SINCE, date),
+            IfModifiedSinceCondition::Unmodified(date) => (IF_UNMODIFIED_SINCE, date),
+        };
+        request
+            .headers_mut()
+            .insert(header_name, HeaderValue::from_str(&to_azure_time(date))?);
+        Ok(())
+    }
 }
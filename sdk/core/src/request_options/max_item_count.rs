@@ -1,6 +1,7 @@
 use crate::headers;
 use crate::AddAsHeader;
 use http::request::Builder;
+use http::HeaderValue;
 
 /// The max number of items in the collection
 #[derive(Debug, Clone, Copy)]
@@ -21,4 +22,12 @@ impl AddAsHeader for MaxItemCount {
             builder.header(headers::MAX_ITEM_COUNT, self.0)
         }
     }
+
+    fn add_as_header2(&self, request: &mut crate::Request) -> Result<(), http::Error> {
+        let count = if self.0 <= 0 { -1 } else { self.0 };
+        request
+            .headers_mut()
+            .insert(headers::MAX_ITEM_COUNT, HeaderValue::from(count));
+        Ok(())
+    }
 }
@@ -7,14 +7,16 @@ use std::pin::Pin;
 
 type PinnedStream = Pin<Box<dyn Stream<Item = Result<Bytes, StreamError>> + Send + Sync>>;
 
-#[allow(dead_code)]
-pub(crate) struct ResponseBuilder {
+/// Builds a [`Response`].
+///
+/// Transports use this to hand the raw HTTP response over to the pipeline. It is also handy
+/// for test transports that need to return canned responses.
+pub struct ResponseBuilder {
     status: StatusCode,
     headers: HeaderMap,
 }
 
 impl ResponseBuilder {
-    #[allow(dead_code)]
     pub fn new(status: StatusCode) -> Self {
         Self {
             status,
@@ -22,13 +24,11 @@ impl ResponseBuilder {
         }
     }
 
-    #[allow(dead_code)]
     pub fn with_header(&mut self, key: &HeaderName, value: HeaderValue) -> &mut Self {
         self.headers.append(key, value);
         self
     }
 
-    #[allow(dead_code)]
     pub fn with_pinned_stream(self, response: PinnedStream) -> Response {
         Response::new(self.status, self.headers, response)
     }
@@ -57,10 +57,40 @@ impl Response {
         &self.headers
     }
 
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
     pub fn deconstruct(self) -> (StatusCode, HeaderMap, PinnedStream) {
         (self.status, self.headers, self.body)
     }
 
+    /// Collects the body into an `http` response, for the tooling of the `http` crate.
+    ///
+    /// The status and headers are already `http` types: [`status`](Self::status) and
+    /// [`headers`](Self::headers) borrow them without consuming the body.
+    pub async fn into_http_response(self) -> Result<http::Response<Bytes>, StreamError> {
+        let body = collect_pinned_stream(self.body).await?;
+        let mut response = http::Response::new(body);
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        Ok(response)
+    }
+
+    /// Deserializes the elements of the JSON array held by `field` as they are received.
+    ///
+    /// Unlike collecting the body and parsing it in one go, this never buffers more than one
+    /// element. See [`json_array_stream`](crate::json_array_stream).
+    pub fn into_json_array_stream<T>(
+        self,
+        field: &'static str,
+    ) -> impl Stream<Item = Result<T, crate::Error>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        crate::json_array_stream(self.body, field)
+    }
+
     pub async fn validate(self, expected_status: StatusCode) -> Result<Self, crate::HttpError> {
         let status = self.status();
         if expected_status != status {
@@ -78,6 +108,15 @@ impl Response {
     }
 }
 
+/// A response whose body is already received, such as a recorded one, streamed in one chunk.
+impl From<http::Response<Bytes>> for Response {
+    fn from(response: http::Response<Bytes>) -> Self {
+        let (parts, body) = response.into_parts();
+        let body: PinnedStream = Box::pin(futures::stream::once(futures::future::ready(Ok(body))));
+        Self::new(parts.status, parts.headers, body)
+    }
+}
+
 /// Convenience function that transforms a `PinnedStream` in a `bytes::Bytes` struct by collecting all the chunks. It consumes the response stream.
 pub async fn collect_pinned_stream(mut pinned_stream: PinnedStream) -> Result<Bytes, StreamError> {
     let mut final_result = Vec::new();
@@ -89,3 +128,42 @@ pub async fn collect_pinned_stream(mut pinned_stream: PinnedStream) -> Result<By
 
     Ok(final_result.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http_response() -> http::Response<Bytes> {
+        http::Response::builder()
+            .status(StatusCode::CREATED)
+            .header("etag", "\"0000d986-0000-0000-0000-5e1f9d200000\"")
+            .header("x-ms-request-charge", "1")
+            .header("x-ms-request-charge", "2")
+            .body(Bytes::from_static(br#"{"id":"recorded"}"#))
+            .unwrap()
+    }
+
+    #[test]
+    fn http_responses_round_trip() {
+        let response = Response::from(http_response());
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers(), http_response().headers());
+
+        let round_tripped = futures::executor::block_on(response.into_http_response()).unwrap();
+        assert_eq!(round_tripped.status(), StatusCode::CREATED);
+        assert_eq!(round_tripped.headers(), http_response().headers());
+        assert_eq!(round_tripped.body(), http_response().body());
+    }
+
+    #[test]
+    fn recorded_responses_are_validated() {
+        let response = futures::executor::block_on(
+            Response::from(http_response()).validate(StatusCode::CREATED),
+        );
+        assert!(response.is_ok());
+
+        let response =
+            futures::executor::block_on(Response::from(http_response()).validate(StatusCode::OK));
+        assert!(response.is_err());
+    }
+}
@@ -1,35 +1,133 @@
 use futures::Future;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-pub(crate) fn sleep(duration: Duration) -> Sleep {
+/// Waits for `duration` without depending on an async runtime.
+pub fn sleep(duration: Duration) -> Sleep {
     Sleep {
         thread: None,
+        shared: Arc::new(Shared::default()),
         duration,
     }
 }
 
-pub(crate) struct Sleep {
+/// A runtime-agnostic sleep future.
+///
+/// The timer runs on a helper thread. Dropping the future before it completes (for example
+/// because the caller gave up on the whole operation) signals the helper thread, which then
+/// exits immediately instead of lingering until the full duration has elapsed. The future is
+/// only ready once the timer has elapsed, however often it is polled before.
+pub struct Sleep {
     thread: Option<thread::JoinHandle<()>>,
+    shared: Arc<Shared>,
     duration: Duration,
 }
 
+/// The state shared by a [`Sleep`] and its timer thread.
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    cancelled: bool,
+    elapsed: bool,
+    /// The waker of the latest poll, woken when the timer elapses
+    waker: Option<Waker>,
+}
+
 impl Future for Sleep {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if self.thread.is_none() {
-            let waker = cx.waker().clone();
-            let duration = self.duration.clone();
-            self.get_mut().thread = Some(thread::spawn(move || {
-                thread::sleep(duration);
-                waker.wake();
+        let this = self.get_mut();
+        {
+            let mut state = this.shared.state.lock().unwrap();
+            if state.elapsed {
+                return Poll::Ready(());
+            }
+            match &state.waker {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                _ => state.waker = Some(cx.waker().clone()),
+            }
+        }
+
+        if this.thread.is_none() {
+            let duration = this.duration;
+            let shared = this.shared.clone();
+            this.thread = Some(thread::spawn(move || {
+                let deadline = Instant::now() + duration;
+                let mut state = shared.state.lock().unwrap();
+                while !state.cancelled {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    state = shared
+                        .condvar
+                        .wait_timeout(state, deadline - now)
+                        .unwrap()
+                        .0;
+                }
+                if !state.cancelled {
+                    state.elapsed = true;
+                    let waker = state.waker.take();
+                    drop(state);
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
             }));
-            Poll::Pending
-        } else {
-            Poll::Ready(())
         }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.cancelled = true;
+            self.shared.condvar.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[test]
+    fn dropping_sleep_stops_the_timer_thread() {
+        let mut sleep = sleep(Duration::from_secs(60));
+        // first poll starts the timer thread
+        assert!((&mut sleep).now_or_never().is_none());
+        let thread = sleep.thread.take().unwrap();
+
+        let start = Instant::now();
+        drop(sleep);
+        thread.join().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn polling_again_before_the_duration_is_still_pending() {
+        let mut sleep = sleep(Duration::from_secs(60));
+        assert!((&mut sleep).now_or_never().is_none());
+        // a spurious wake, as another branch of a `select!` waking the task, polls again
+        assert!((&mut sleep).now_or_never().is_none());
+        assert!((&mut sleep).now_or_never().is_none());
+    }
+
+    #[test]
+    fn sleep_is_ready_once_the_duration_has_passed() {
+        let start = Instant::now();
+        futures::executor::block_on(sleep(Duration::from_millis(50)));
+        assert!(start.elapsed() >= Duration::from_millis(50));
     }
 }
@@ -0,0 +1,78 @@
+//! Parses a large database listing.
+//!
+//! Compare the JSON backends by running the benchmark with and without the `simd-json` feature:
+//!
+//! ```bash
+//! cargo bench --bench json
+//! cargo bench --bench json --features simd-json
+//! ```
+
+use azure_cosmos::responses::ListDatabasesResponse;
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use std::convert::TryFrom;
+
+const DATABASES: usize = 10_000;
+
+fn list_databases_response(body: Bytes) -> http::Response<Bytes> {
+    http::Response::builder()
+        .status(http::StatusCode::OK)
+        .header("x-ms-session-token", "0:-1#1")
+        .header("x-ms-request-charge", "1")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+        .header(
+            "x-ms-last-state-change-utc",
+            "Wed, 15 Jan 2020 23:39:44.369 GMT",
+        )
+        .header("x-ms-resource-quota", "databases=100000;")
+        .header("x-ms-resource-usage", "databases=10000;")
+        .header("x-ms-schemaversion", "1.9")
+        .header("x-ms-serviceversion", "version=2.9.0.0")
+        .header("x-ms-gatewayversion", "version=2.9.0")
+        .body(body)
+        .unwrap()
+}
+
+fn list_databases_body() -> Bytes {
+    let databases: Vec<_> = (0..DATABASES)
+        .map(|i| {
+            serde_json::json!({
+                "id": format!("database-{}", i),
+                "_rid": "JHwXAA==",
+                "_self": "dbs/JHwXAA==/",
+                "_etag": "\"00000d01-0000-0d00-0000-5e1f9d200000\"",
+                "_colls": "colls/",
+                "_users": "users/",
+                "_ts": 1579130144 + i,
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "_rid": "",
+        "Databases": databases,
+        "_count": DATABASES,
+    });
+    serde_json::to_vec(&body).unwrap().into()
+}
+
+fn parse_list_databases(c: &mut Criterion) {
+    let body = list_databases_body();
+    let backend = if cfg!(feature = "simd-json") {
+        "simd-json"
+    } else {
+        "serde_json"
+    };
+
+    let mut group = c.benchmark_group("list_databases");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function(backend, |b| {
+        b.iter(|| {
+            let response = list_databases_response(body.clone());
+            black_box(ListDatabasesResponse::try_from(response).unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse_list_databases);
+criterion_main!(benches);
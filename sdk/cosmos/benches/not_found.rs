@@ -0,0 +1,43 @@
+//! Turns the body of a missing document into an error, with and without parsing it.
+//!
+//! The `NotFound` error keeps the body as it was received: existence checks only need the
+//! variant, and `Error::envelope` parses the code and message on demand.
+//!
+//! ```bash
+//! cargo bench --bench not_found
+//! ```
+
+use azure_cosmos::Error;
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+fn not_found_body() -> Bytes {
+    let body = serde_json::json!({
+        "code": "NotFound",
+        "message": "Message: {\"Errors\":[\"Resource Not Found. Learn more: https://aka.ms/cosmosdb-tsg-not-found\"]}\r\nActivityId: 0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44, Request URI: /apps/1b5c5d44-7d8a-4b5a-a0b5-5d0a7b1a2e3f/services/6e0c4c5f-0d1b-4c0e-9e9c-0b1b7c1c5a9d/partitions/2d4d5a3b-6a5c-4f7e-8b9a-1c2d3e4f5a6b/replicas/132512345678901234p/, RequestStats: \r\nRequestStartTime: 2021-01-15T23:39:44.3690000Z, RequestEndTime: 2021-01-15T23:39:44.3700000Z,  Number of regions attempted:1\r\n, SDK: Microsoft.Azure.Documents.Common/2.11.0",
+    });
+    serde_json::to_vec(&body).unwrap().into()
+}
+
+/// The error of a missing document, as read from the response by the operations
+fn not_found(body: &Bytes) -> Error {
+    let body = std::str::from_utf8(body).unwrap_or("<NON-UTF8 BODY>");
+    Error::NotFound {
+        body: body.to_owned(),
+    }
+}
+
+fn not_found_error(c: &mut Criterion) {
+    let body = not_found_body();
+
+    let mut group = c.benchmark_group("not_found");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("unparsed", |b| b.iter(|| black_box(not_found(&body))));
+    group.bench_function("envelope", |b| {
+        b.iter(|| black_box(not_found(&body).envelope().unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, not_found_error);
+criterion_main!(benches);
@@ -1,3 +1,4 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -56,13 +57,17 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     };
 
     // let's add an entity.
-    match client.create_document().execute(&doc).await {
+    match client
+        .create_document(Context::new(), &doc, CreateDocumentOptions::new())
+        .await
+    {
         Ok(_) => {
             println!("document created");
         }
-        Err(err) => {
-            println!("already exists? ==> {:?}", err);
+        Err(azure_cosmos::Error::Conflict { .. }) => {
+            println!("document already exists");
         }
+        Err(err) => return Err(err.into()),
     };
 
     let document_client = client.into_document_client(doc.id.clone(), &doc.id)?;
@@ -56,7 +56,8 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
             .create_collection(
                 Context::new(),
                 "panzadoro",
-                CreateCollectionOptions::new("/id"),
+                "/id",
+                CreateCollectionOptions::new(),
             )
             .await?;
 
@@ -1,7 +1,25 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::error::Error;
 
+#[derive(Serialize, Deserialize, Debug)]
+struct MySampleStruct {
+    id: String,
+    name: String,
+    age: u32,
+    phones: Vec<String>,
+}
+
+impl<'a> azure_cosmos::CosmosEntity<'a> for MySampleStruct {
+    type Entity = u32;
+
+    fn partition_key(&'a self) -> Self::Entity {
+        self.age
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // First we retrieve the account name and master key from environment variables.
@@ -38,12 +56,10 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
                         "+44 2345678"
                     ]
                 }"#;
-                let document: Value = serde_json::from_str(data)?;
+                let document: MySampleStruct = serde_json::from_str(data)?;
 
                 let resp = collection_client
-                    .create_document()
-                    .is_upsert(true)
-                    .execute_with_partition_key(&document, &43u32)
+                    .upsert_document(Context::new(), &document, CreateDocumentOptions::new())
                     .await?;
 
                 println!("resp == {:?}", resp);
@@ -68,12 +68,11 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // If the requested database is not found we create it.
     let database = match db {
         Some(db) => db,
-        None => {
-            client
-                .create_database(Context::new(), DATABASE, CreateDatabaseOptions::new())
-                .await?
-                .database
-        }
+        None => client
+            .create_database(Context::new(), DATABASE, CreateDatabaseOptions::new())
+            .await?
+            .database
+            .expect("the service returns the created database"),
     };
     println!("database == {:?}", database);
 
@@ -101,7 +100,8 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
                 .create_collection(
                     Context::new(),
                     COLLECTION,
-                    CreateCollectionOptions::new("/id"),
+                    "/id",
+                    CreateCollectionOptions::new(),
                 )
                 .await?
                 .collection
@@ -128,9 +128,11 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let collection_client = database_client.into_collection_client(collection.id);
 
     // The method create_document will return, upon success,
-    // the document attributes.
+    // the stored document with its attributes.
 
-    let create_document_response = collection_client.create_document().execute(&doc).await?;
+    let create_document_response = collection_client
+        .create_document(Context::new(), &doc, CreateDocumentOptions::new())
+        .await?;
     println!(
         "create_document_response == {:#?}",
         create_document_response
@@ -169,11 +171,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         // changes every time the document is updated. If the passed etag is different in
         // CosmosDB it means something else updated the document before us!
         let replace_document_response = collection_client
-            .clone()
-            .into_document_client(doc.id.clone(), &doc.id)?
-            .replace_document()
-            .if_match_condition(IfMatchCondition::Match(&document.etag))
-            .execute(&doc)
+            .replace_document(
+                Context::new(),
+                &doc.id,
+                &doc,
+                ReplaceDocumentOptions::new()
+                    .if_match_condition(IfMatchCondition::Match(&document.etag)),
+            )
             .await?;
         println!(
             "replace_document_response == {:#?}",
@@ -59,7 +59,12 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         };
 
         // let's add an entity.
-        response = Some(client.create_document().execute(&doc).await?);
+        // we keep the session token only: the response borrows the document.
+        response = Some(ConsistencyLevel::from(
+            client
+                .create_document(Context::new(), &doc, CreateDocumentOptions::new())
+                .await?,
+        ));
     }
 
     println!("Created 5 documents.");
@@ -138,18 +143,20 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     let mut doc = match response {
         GetDocumentResponse::Found(ref resp) => resp.clone(),
-        GetDocumentResponse::NotFound(_) => panic!(),
+        _ => panic!(),
     };
     doc.document.document.a_string = "Something else here".into();
 
     println!("\n\nReplacing document");
     let replace_document_response = client
-        .clone()
-        .into_document_client(id.clone(), &id)?
-        .replace_document()
-        .consistency_level(ConsistencyLevel::from(&response))
-        .if_match_condition(IfMatchCondition::Match(&doc.etag)) // use optimistic concurrency check
-        .execute(&doc.document)
+        .replace_document(
+            Context::new(),
+            &id,
+            &doc.document.document,
+            ReplaceDocumentOptions::new()
+                .consistency_level(ConsistencyLevel::from(&response))
+                .if_match_condition(IfMatchCondition::Match(&doc.etag)), // use optimistic concurrency check
+        )
         .await?;
 
     println!(
@@ -179,11 +186,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     for i in 0u64..5 {
         let id = format!("unique_id{}", i);
         client
-            .clone()
-            .into_document_client(id.clone(), &id)?
-            .delete_document()
-            .consistency_level(&response)
-            .execute()
+            .delete_document(Context::new(), &id, &id, None)
             .await?;
     }
     println!("Cleaned up");
@@ -1,4 +1,6 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
+use futures::stream::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::error::Error;
@@ -49,9 +51,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     // let's add an entity.
     let create_document_response = client
-        .create_document()
-        .is_upsert(true)
-        .execute(&doc)
+        .upsert_document(Context::new(), &doc, CreateDocumentOptions::new())
         .await?;
 
     println!(
@@ -89,11 +89,14 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .await?;
     println!("list_documents_response == {:#?}", list_documents_response);
 
-    let query_documents_response = client
-        .query_documents()
-        .consistency_level(&list_documents_response)
-        .query_cross_partition(true)
-        .execute::<serde_json::Value, _>("SELECT * FROM c WHERE c.a_number = 600")
+    let query_documents_response: Vec<serde_json::Value> = client
+        .query_documents(
+            Context::new(),
+            "SELECT * FROM c WHERE c.a_number = 600",
+            vec![],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect()
         .await?;
     println!(
         "query_documents_response == {:#?}",
@@ -103,10 +106,12 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     doc.a_number = 43;
 
     let replace_document_response = client
-        .into_document_client(doc.id.clone(), &doc.id)?
-        .replace_document()
-        .consistency_level(&query_documents_response)
-        .execute(&doc)
+        .replace_document(
+            Context::new(),
+            &doc.id,
+            &doc,
+            ReplaceDocumentOptions::new().consistency_level(&list_documents_response),
+        )
         .await?;
     println!(
         "replace_document_response == {:#?}",
@@ -54,7 +54,9 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         get_collection2_response
     );
 
-    let create_user_response = user_client.create_user().execute().await?;
+    let create_user_response = user_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await?;
     println!("create_user_response == {:#?}", create_user_response);
 
     // create the first permission!
@@ -138,11 +140,12 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     );
 
     let delete_user_response = user_client
-        .delete_user()
-        .consistency_level(ConsistencyLevel::Session(
-            delete_permission_response.session_token,
-        ))
-        .execute()
+        .delete_user(
+            Context::new(),
+            DeleteUserOptions::new().consistency_level(ConsistencyLevel::Session(
+                delete_permission_response.session_token,
+            )),
+        )
         .await?;
     println!("delete_user_response == {:#?}", delete_user_response);
 
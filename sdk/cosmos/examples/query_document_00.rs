@@ -1,5 +1,6 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
-use azure_cosmos::responses::QueryDocumentsResponse;
+use futures::stream::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
@@ -46,22 +47,15 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let client = client.into_database_client(database_name);
     let client = client.into_collection_client(collection_name);
 
-    let query_obj = Query::new(&query);
-
-    let respo: QueryDocumentsResponse<serde_json::Value> = client
-        .query_documents()
-        .query_cross_partition(true)
-        .max_item_count(3i32)
-        .execute(&query_obj)
+    let respo: Vec<serde_json::Value> = client
+        .query_documents(Context::new(), &query, vec![], QueryDocumentsOptions::new())
+        .try_collect()
         .await?;
     println!("as json == {:?}", respo);
 
-    let respo: QueryDocumentsResponse<MySecondSampleStructOwned> = client
-        .query_documents()
-        .query_cross_partition(true)
-        .parallelize_cross_partition_query(true)
-        .max_item_count(2)
-        .execute(&query_obj)
+    let respo: Vec<MySecondSampleStructOwned> = client
+        .query_documents(Context::new(), &query, vec![], QueryDocumentsOptions::new())
+        .try_collect()
         .await?;
     println!("as items == {:?}", respo);
 
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 // Using the prelude module of the Cosmos crate makes easier to use the Rust Azure SDK for Cosmos.
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
-use futures::stream::StreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
 use std::borrow::Cow;
 use std::error::Error;
 
@@ -79,9 +80,11 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         // insert it and store the returned session token for later use!
         session_token = Some(
             collection_client
-                .create_document()
-                .is_upsert(true) // this option will overwrite a preexisting document (if any)
-                .execute(&document_to_insert)
+                .upsert_document(
+                    Context::new(),
+                    &document_to_insert,
+                    CreateDocumentOptions::new(),
+                ) // an upsert will overwrite a preexisting document (if any)
                 .await?
                 .session_token, // get only the session token, if everything else was ok!
         );
@@ -113,44 +116,37 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     // TASK 3
     println!("\nQuerying documents");
-    let query_documents_response = collection_client
-        .query_documents()
-        .query_cross_partition(true) // this will perform a cross partition query! notice how simple it is!
-        .consistency_level(session_token)
-        .execute::<MySampleStruct, _>("SELECT * FROM A WHERE A.a_number < 600") // there are other ways to construct a query, this is the simplest.
-        .await?
-        .into_documents() // queries can return Documents or Raw json (ie without etag, _rid, etc...). Since our query return docs we convert with this function.
-        .unwrap(); // we know in advance that the conversion to Document will not fail since we SELECT'ed * FROM table
-
-    println!(
-        "Received {} documents!",
-        query_documents_response.results.len()
-    );
-
-    query_documents_response
-        .results
-        .iter()
-        .for_each(|document| {
-            println!("number ==> {}", document.result.a_number);
-        });
+    let query_documents_response: Vec<Document<MySampleStruct>> = collection_client
+        .query_documents(
+            Context::new(),
+            "SELECT * FROM A WHERE A.a_number < @max", // this will perform a cross partition query! notice how simple it is!
+            vec![("@max".to_owned(), 600.into())],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect() // the stream follows the continuation tokens for us.
+        .await?; // we SELECT'ed * FROM table so we can ask for whole Documents (with etag, _rid, etc...).
+
+    println!("Received {} documents!", query_documents_response.len());
+
+    query_documents_response.iter().for_each(|document| {
+        println!("number ==> {}", document.document.a_number);
+    });
 
     // TASK 4
-    let session_token = ConsistencyLevel::Session(query_documents_response.session_token);
-    for ref document in query_documents_response.results {
-        // From our query above we are sure to receive a Document.
+    for ref document in query_documents_response {
         println!(
             "deleting id == {}, a_number == {}.",
-            document.result.id, document.result.a_number
+            document.document.id, document.document.a_number
         );
 
         // to spice the delete a little we use optimistic concurreny
         collection_client
-            .clone()
-            .into_document_client(document.result.id.clone(), &document.result.a_number)?
-            .delete_document()
-            .consistency_level(session_token.clone())
-            .if_match_condition(&document.document_attributes)
-            .execute()
+            .delete_document(
+                Context::new(),
+                &document.document.id,
+                &document.document.a_number,
+                Some(document.document_attributes.etag().into()),
+            )
             .await?;
     }
 
@@ -5,7 +5,9 @@
 ///     var response = context.getResponse();
 ///     response.setBody("Hello, " + personToGreet);
 /// }
+use azure_core::Context;
 use azure_cosmos::prelude::*;
+use futures::StreamExt;
 use std::error::Error;
 
 #[tokio::main]
@@ -46,16 +48,22 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .clone()
         .into_stored_procedure_client(stored_procedure_name);
 
-    let list_stored_procedures_response =
-        collection_client.list_stored_procedures().execute().await?;
+    let list_stored_procedures_response = collection_client
+        .list_stored_procedures(Context::new(), ListStoredProceduresOptions::new())
+        .next()
+        .await
+        .unwrap()?;
     println!(
         "list_stored_procedures_response == {:#?}",
         list_stored_procedures_response
     );
 
     let create_stored_procedure_response = stored_procedure_client
-        .create_stored_procedure()
-        .execute(function_body)
+        .create_stored_procedure(
+            Context::new(),
+            function_body,
+            CreateStoredProcedureOptions::new(),
+        )
         .await?;
     println!(
         "create_stored_procedure_response == {:#?}",
@@ -78,8 +86,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     );
 
     let delete_stored_procedure_response = stored_procedure_client
-        .delete_stored_procedure()
-        .execute()
+        .delete_stored_procedure(Context::new(), DeleteStoredProcedureOptions::new())
         .await?;
     println!(
         "delete_stored_procedure_response == {:#?}",
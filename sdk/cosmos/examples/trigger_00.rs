@@ -1,3 +1,4 @@
+use azure_core::Context;
 use azure_cosmos::prelude::*;
 use azure_cosmos::resources::trigger::{TriggerOperation, TriggerType};
 use futures::stream::StreamExt;
@@ -62,8 +63,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let trigger_client = collection_client.clone().into_trigger_client(trigger_name);
 
     let ret = trigger_client
-        .create_trigger()
-        .execute("something", TriggerType::Post, TriggerOperation::All)
+        .create_trigger(
+            Context::new(),
+            TriggerType::Post,
+            TriggerOperation::All,
+            "something",
+            CreateTriggerOptions::new(),
+        )
         .await?;
     println!("Creeate response object:\n{:#?}", ret);
 
@@ -76,24 +82,26 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     let mut last_session_token: Option<ConsistencyLevel> = None;
 
-    let stream = collection_client
-        .list_triggers()
-        .max_item_count(3)
-        .consistency_level(&ret);
-    let mut stream = Box::pin(stream.stream());
+    let mut stream = collection_client.list_triggers(
+        Context::new(),
+        ListTriggersOptions::new()
+            .max_item_count(3)
+            .consistency_level(&ret),
+    );
     while let Some(ret) = stream.next().await {
         let ret = ret.unwrap();
         println!(
             "List loop received {} items. Object:\n{:#?}",
-            ret.item_count, ret
+            ret.count, ret
         );
         last_session_token = Some(ConsistencyLevel::Session(ret.session_token));
     }
 
     let ret = trigger_client
-        .delete_trigger()
-        .consistency_level(last_session_token.unwrap())
-        .execute()
+        .delete_trigger(
+            Context::new(),
+            DeleteTriggerOptions::new().consistency_level(last_session_token.unwrap()),
+        )
         .await?;
     println!("Delete response object:\n{:#?}", ret);
 
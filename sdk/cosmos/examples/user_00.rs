@@ -1,4 +1,6 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
+use futures::StreamExt;
 use std::error::Error;
 
 #[tokio::main]
@@ -27,10 +29,16 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let database_client = client.into_database_client(database_name);
     let user_client = database_client.clone().into_user_client(user_name.clone());
 
-    let create_user_response = user_client.create_user().execute().await?;
+    let create_user_response = user_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await?;
     println!("create_user_response == {:#?}", create_user_response);
 
-    let list_users_response = database_client.list_users().execute().await?;
+    let list_users_response = database_client
+        .list_users(Context::new(), ListUsersOptions::new())
+        .next()
+        .await
+        .unwrap()?;
     println!("list_users_response == {:#?}", list_users_response);
 
     let get_user_response = user_client.get_user().execute().await?;
@@ -43,7 +51,9 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     let user_client = database_client.into_user_client(new_user);
 
-    let delete_user_response = user_client.delete_user().execute().await?;
+    let delete_user_response = user_client
+        .delete_user(Context::new(), DeleteUserOptions::new())
+        .await?;
     println!("delete_user_response == {:#?}", delete_user_response);
 
     Ok(())
@@ -1,5 +1,6 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
-use futures::stream::StreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
 use std::error::Error;
 
 const FN_BODY: &str = r#"
@@ -42,21 +43,25 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .into_user_defined_function_client("test15");
 
     let ret = user_defined_function_client
-        .create_user_defined_function()
-        .execute("body")
+        .create_user_defined_function(
+            Context::new(),
+            "body",
+            CreateUserDefinedFunctionOptions::new(),
+        )
         .await?;
     println!("Creeate response object:\n{:#?}", ret);
 
-    let stream = collection_client
-        .list_user_defined_functions()
-        .max_item_count(3)
-        .consistency_level(&ret);
-    let mut stream = Box::pin(stream.stream());
+    let mut stream = collection_client.list_user_defined_functions(
+        Context::new(),
+        ListUserDefinedFunctionsOptions::new()
+            .max_item_count(3)
+            .consistency_level(&ret),
+    );
     while let Some(ret) = stream.next().await {
         let ret = ret.unwrap();
         println!(
             "List loop received {} items. Object:\n{:#?}",
-            ret.item_count, ret
+            ret.count, ret
         );
     }
 
@@ -67,17 +72,19 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         .await?;
     println!("Replace response object:\n{:#?}", ret);
 
-    let ret = collection_client
-        .query_documents()
-        .consistency_level(&ret)
-        .max_item_count(2i32)
-        .execute::<serde_json::Value, _>("SELECT udf.test15(100)")
-        .await?
-        .into_raw();
-    println!("Query response object:\n{:#?}", ret);
+    let results: Vec<serde_json::Value> = collection_client
+        .query_documents(
+            Context::new(),
+            "SELECT udf.test15(100)",
+            vec![],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect()
+        .await?;
+    println!("Query results:\n{:#?}", results);
 
     // this code extracts the first object
-    let fn_return = &ret.results[0].as_object().unwrap();
+    let fn_return = &results[0].as_object().unwrap();
     println!("fn_return == {:?}", fn_return);
     // and from the first object get the first value as f64
     let value = fn_return
@@ -91,9 +98,10 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     println!("value == {:?}", value);
 
     let ret = user_defined_function_client
-        .delete_user_defined_function()
-        .consistency_level(&ret)
-        .execute()
+        .delete_user_defined_function(
+            Context::new(),
+            DeleteUserDefinedFunctionOptions::new().consistency_level(&ret),
+        )
         .await?;
 
     println!("Delete response object:\n{:#?}", ret);
@@ -1,6 +1,23 @@
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 
+#[derive(Serialize, Deserialize, Debug)]
+struct MySampleStruct {
+    id: String,
+    age: u32,
+    phones: Vec<String>,
+}
+
+impl<'a> azure_cosmos::CosmosEntity<'a> for MySampleStruct {
+    type Entity = &'a str;
+
+    fn partition_key(&'a self) -> Self::Entity {
+        &self.id
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // First we retrieve the account name and master key from environment variables.
@@ -36,7 +53,9 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let get_collection_response = collection_client.get_collection().execute().await?;
     println!("get_collection_response == {:#?}", get_collection_response);
 
-    let create_user_response = user_client.create_user().execute().await?;
+    let create_user_response = user_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await?;
     println!("create_user_response == {:#?}", create_user_response);
 
     // test list documents
@@ -106,15 +125,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
                 "+39 2345678"
             ]
         }"#;
-    let document = serde_json::from_str::<serde_json::Value>(data)?;
+    let document = serde_json::from_str::<MySampleStruct>(data)?;
 
     match client
         .clone()
         .into_database_client(database_name.clone())
         .into_collection_client(collection_name.clone())
-        .create_document()
-        .is_upsert(true)
-        .execute_with_partition_key(&document, &"Gianluigi Bombatomica")
+        .upsert_document(Context::new(), &document, CreateDocumentOptions::new())
         .await
     {
         Ok(_) => panic!("this should not happen!"),
@@ -151,9 +168,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let create_document_response = client
         .into_database_client(database_name)
         .into_collection_client(collection_name)
-        .create_document()
-        .is_upsert(true)
-        .execute_with_partition_key(&document, &"Gianluigi Bombatomica")
+        .upsert_document(Context::new(), &document, CreateDocumentOptions::new())
         .await?;
     println!(
         "create_document_response == {:#?}",
@@ -161,7 +176,9 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     );
 
     println!("Cleaning up user.");
-    let delete_user_response = user_client.delete_user().execute().await?;
+    let delete_user_response = user_client
+        .delete_user(Context::new(), DeleteUserOptions::new())
+        .await?;
     println!("delete_user_response == {:#?}", delete_user_response);
 
     Ok(())
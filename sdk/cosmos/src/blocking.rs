@@ -0,0 +1,162 @@
+//! A synchronous Cosmos DB client, for consumers that do not run an async runtime.
+//!
+//! Enable the `blocking` feature to use it:
+//!
+//! ```no_run
+//! use azure_cosmos::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let authorization_token = AuthorizationToken::primary_from_base64("bWFzdGVyIGtleQ==")?;
+//! let client = CosmosClient::new("account".to_owned(), authorization_token, CosmosOptions::default());
+//! let client = azure_cosmos::blocking::CosmosClient::new(client)?;
+//!
+//! client.create_database("database", CreateDatabaseOptions::new())?;
+//! for database in client.list_databases()? {
+//!     println!("{}", database.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::operations::{CreateDatabaseOptions, CreateDatabaseResponse};
+use crate::resources::Database;
+use azure_core::Context;
+use futures::stream::TryStreamExt;
+use std::future::Future;
+use tokio::runtime::{Builder, Handle, Runtime};
+
+/// A [`CosmosClient`](crate::clients::CosmosClient) whose methods block until the response arrives.
+///
+/// Each call drives the async client on a current-thread runtime owned by this client. Calling it
+/// from a thread that is already running an async runtime returns
+/// [`Error::BlockingInAsyncContext`](crate::Error::BlockingInAsyncContext): use the async client
+/// there instead.
+#[derive(Debug)]
+pub struct CosmosClient {
+    client: crate::clients::CosmosClient,
+    runtime: Option<Runtime>,
+}
+
+impl CosmosClient {
+    /// Wrap an async client, starting the runtime its calls will be driven on
+    pub fn new(client: crate::clients::CosmosClient) -> Result<Self, crate::Error> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(crate::Error::BlockingRuntime)?;
+        Ok(Self {
+            client,
+            runtime: Some(runtime),
+        })
+    }
+
+    /// The async client the calls are forwarded to
+    pub fn async_client(&self) -> &crate::clients::CosmosClient {
+        &self.client
+    }
+
+    /// Create a database
+    pub fn create_database<S: AsRef<str>>(
+        &self,
+        database_name: S,
+        options: CreateDatabaseOptions,
+    ) -> Result<CreateDatabaseResponse, crate::Error> {
+        self.block_on(
+            self.client
+                .create_database(Context::new(), database_name, options),
+        )?
+    }
+
+    /// List all databases, following the continuation tokens until the last page
+    pub fn list_databases(&self) -> Result<Vec<Database>, crate::Error> {
+        let list_databases = self.client.list_databases();
+        self.block_on(
+            list_databases
+                .stream()
+                .map_ok(|page| page.databases)
+                .try_concat(),
+        )?
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> Result<F::Output, crate::Error> {
+        // tokio panics when a runtime is blocked on from within another one
+        if Handle::try_current().is_ok() {
+            return Err(crate::Error::BlockingInAsyncContext);
+        }
+        Ok(self
+            .runtime
+            .as_ref()
+            .expect("the runtime is only taken on drop")
+            .block_on(future))
+    }
+}
+
+impl Drop for CosmosClient {
+    fn drop(&mut self) {
+        // dropping a runtime from within an async context panics as well
+        if let Some(runtime) = self.runtime.take() {
+            if Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::CosmosOptions;
+    use crate::mock_transport::{MockResponse, MockTransport};
+    use std::sync::Arc;
+
+    fn client(transport: Arc<MockTransport>) -> CosmosClient {
+        CosmosClient::new(crate::clients::CosmosClient::new_emulator(
+            "localhost",
+            CosmosOptions::with_client(transport),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn create_database_blocks_until_the_database_is_created() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::database(
+            http::StatusCode::CREATED,
+            "blocking",
+        )]));
+        let client = client(transport.clone());
+
+        let response = client
+            .create_database("blocking", CreateDatabaseOptions::new())
+            .unwrap();
+
+        assert_eq!(response.database.unwrap().id, "blocking");
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri().path(), "/dbs");
+    }
+
+    #[test]
+    fn create_database_surfaces_the_errors_of_the_async_client() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::CONFLICT,
+            "{}",
+        )]));
+
+        assert!(client(transport)
+            .create_database("blocking", CreateDatabaseOptions::new())
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn calls_from_an_async_context_are_refused() {
+        let transport = Arc::new(MockTransport::new(vec![]));
+        let client = client(transport.clone());
+
+        assert!(matches!(
+            client.create_database("blocking", CreateDatabaseOptions::new()),
+            Err(crate::Error::BlockingInAsyncContext)
+        ));
+        assert!(transport.requests().is_empty());
+    }
+}
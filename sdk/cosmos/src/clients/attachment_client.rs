@@ -87,9 +87,8 @@ impl AttachmentClient {
     pub(crate) fn prepare_request(&self, method: http::Method) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/docs/{}/attachments",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/docs/{}/attachments",
+                self.collection_client().path(),
                 self.document_client().document_name(),
             ),
             method,
@@ -103,9 +102,8 @@ impl AttachmentClient {
     ) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/docs/{}/attachments/{}",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/docs/{}/attachments/{}",
+                self.collection_client().path(),
                 self.document_client().document_name(),
                 self.attachment_name()
             ),
@@ -1,9 +1,20 @@
 use super::{DatabaseClient, UserDefinedFunctionClient};
 use crate::clients::*;
+use crate::operation_name::name_operation;
+use crate::operations::*;
 use crate::requests;
+use crate::resources::collection::PartitionKeyRange;
+use crate::resources::document::{IsUpsert, Param, Query};
+use crate::resources::trigger::{TriggerOperation, TriggerType};
 use crate::resources::ResourceType;
-use crate::ReadonlyString;
-use azure_core::HttpClient;
+use crate::total_charge::PageQueryMetrics;
+use crate::{ChargedStream, CosmosEntity, ReadonlyString, TotalCharge};
+use azure_core::pipeline::Pipeline;
+use azure_core::prelude::Etag;
+use azure_core::{Context, HttpClient, Request as HttpRequest};
+use futures::future::Either;
+use futures::stream::{self, try_unfold, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 /// A client for Cosmos collection resources.
@@ -11,6 +22,8 @@ use serde::Serialize;
 pub struct CollectionClient {
     database_client: DatabaseClient,
     collection_name: ReadonlyString,
+    path: String,
+    partitioned: bool,
 }
 
 impl CollectionClient {
@@ -18,9 +31,17 @@ impl CollectionClient {
         database_client: DatabaseClient,
         collection_name: S,
     ) -> Self {
+        let collection_name = collection_name.into();
+        let path = format!(
+            "dbs/{}/colls/{}",
+            database_client.database_name(),
+            collection_name
+        );
         Self {
             database_client,
-            collection_name: collection_name.into(),
+            collection_name,
+            path,
+            partitioned: true,
         }
     }
 
@@ -39,6 +60,40 @@ impl CollectionClient {
         &self.collection_name
     }
 
+    /// Mark the collection as a legacy non-partitioned one
+    ///
+    /// Such collections reject the partition key header, so the operations of this client and of
+    /// the document clients created from it stop sending it: the partition keys they are given
+    /// are not sent.
+    pub fn non_partitioned(self) -> Self {
+        Self {
+            partitioned: false,
+            ..self
+        }
+    }
+
+    /// Whether the operations send the partition key header, see
+    /// [`non_partitioned`](Self::non_partitioned)
+    pub fn is_partitioned(&self) -> bool {
+        self.partitioned
+    }
+
+    /// Removes the partition key header set by the decoration of a request to a non-partitioned
+    /// collection
+    pub(crate) fn omit_partition_key_if_non_partitioned(&self, request: &mut HttpRequest) {
+        if !self.partitioned {
+            request
+                .headers_mut()
+                .remove(crate::headers::HEADER_DOCUMENTDB_PARTITIONKEY);
+        }
+    }
+
+    /// The `dbs/{db}/colls/{coll}` path of the collection, which the paths of its documents and
+    /// other sub-resources start with
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+
     /// Get a collection
     pub fn get_collection(&self) -> requests::GetCollectionBuilder<'_> {
         requests::GetCollectionBuilder::new(self)
@@ -60,28 +115,522 @@ impl CollectionClient {
     }
 
     /// create a document in a collection
-    pub fn create_document(&self) -> requests::CreateDocumentBuilder<'_, '_> {
-        requests::CreateDocumentBuilder::new(self)
+    ///
+    /// Fails with [`Error::Conflict`](crate::Error::Conflict) if a document with the same id
+    /// already exists.
+    pub async fn create_document<'a, T>(
+        &self,
+        mut ctx: Context,
+        document: &'a T,
+        options: CreateDocumentOptions,
+    ) -> Result<CreateDocumentResponse<T>, crate::Error>
+    where
+        T: Serialize + DeserializeOwned + CosmosEntity<'a>,
+    {
+        name_operation(&mut ctx, "create_document");
+        self.write_document(
+            ctx,
+            document,
+            options,
+            IsUpsert::No,
+            &[http::StatusCode::CREATED],
+        )
+        .await
+    }
+
+    /// create a document in a collection, or replace the document with the same id
+    pub async fn upsert_document<'a, T>(
+        &self,
+        mut ctx: Context,
+        document: &'a T,
+        options: CreateDocumentOptions,
+    ) -> Result<CreateDocumentResponse<T>, crate::Error>
+    where
+        T: Serialize + DeserializeOwned + CosmosEntity<'a>,
+    {
+        name_operation(&mut ctx, "upsert_document");
+        self.write_document(
+            ctx,
+            document,
+            options,
+            IsUpsert::Yes,
+            &[http::StatusCode::CREATED, http::StatusCode::OK],
+        )
+        .await
+    }
+
+    /// replace the document with the given id in a collection
+    pub async fn replace_document<'a, T, S>(
+        &self,
+        mut ctx: Context,
+        document_id: S,
+        document: &'a T,
+        options: ReplaceDocumentOptions<'_>,
+    ) -> Result<ReplaceDocumentResponse<T>, crate::Error>
+    where
+        T: Serialize + DeserializeOwned + CosmosEntity<'a>,
+        S: AsRef<str>,
+    {
+        name_operation(&mut ctx, "replace_document");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/docs/{}", self.path(), document_id.as_ref()),
+            http::Method::PUT,
+            ResourceType::Documents,
+        );
+        options.decorate_request(&mut request, document)?;
+        self.omit_partition_key_if_non_partitioned(&mut request);
+        let response = self.pipeline().send(&mut ctx, &mut request).await?;
+        let response = validate_document_write(response, &[http::StatusCode::OK]).await?;
+
+        ReplaceDocumentResponse::try_from(response).await
+    }
+
+    /// delete the document with the given id and partition key from a collection
+    ///
+    /// With `if_match`, the document is only deleted if it still has that etag, failing with
+    /// [`Error::PreconditionFailed`](crate::Error::PreconditionFailed) otherwise. A missing
+    /// document fails with [`Error::NotFound`](crate::Error::NotFound).
+    pub async fn delete_document<S, PK>(
+        &self,
+        mut ctx: Context,
+        document_id: S,
+        partition_key: &PK,
+        if_match: Option<Etag>,
+    ) -> Result<DeleteDocumentResponse, crate::Error>
+    where
+        S: AsRef<str>,
+        PK: Serialize,
+    {
+        name_operation(&mut ctx, "delete_document");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/docs/{}", self.path(), document_id.as_ref()),
+            http::Method::DELETE,
+            ResourceType::Documents,
+        );
+        decorate_delete_document_request(&mut request, partition_key, if_match.as_ref())?;
+        self.omit_partition_key_if_non_partitioned(&mut request);
+        let response = self.pipeline().send(&mut ctx, &mut request).await?;
+        let response = validate_document_write(response, &[http::StatusCode::NO_CONTENT]).await?;
+
+        DeleteDocumentResponse::try_from(response).await
+    }
+
+    /// execute the operations of `batch` on the documents of its partition, atomically
+    ///
+    /// A failed operation does not fail the call: none of the operations is applied and the
+    /// response tells which one failed, see [`TransactionalBatchResponse`].
+    pub async fn execute_transactional_batch(
+        &self,
+        mut ctx: Context,
+        batch: &TransactionalBatch,
+    ) -> Result<TransactionalBatchResponse, crate::Error> {
+        name_operation(&mut ctx, "execute_transactional_batch");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/docs", self.path()),
+            http::Method::POST,
+            ResourceType::Documents,
+        );
+        batch.decorate_request(&mut request)?;
+        let response = self.pipeline().send(&mut ctx, &mut request).await?;
+        let response = validate_document_write(
+            response,
+            &[http::StatusCode::OK, http::StatusCode::MULTI_STATUS],
+        )
+        .await?;
+
+        TransactionalBatchResponse::try_from(response).await
     }
 
     /// query documents in a collection
-    pub fn query_documents(&self) -> requests::QueryDocumentsBuilder<'_, '_> {
-        requests::QueryDocumentsBuilder::new(self)
+    ///
+    /// The query is sent with its `@`-prefixed parameters and may span partitions. The pages of
+    /// results are requested one after the other, following the continuation token, and the
+    /// stream yields their documents in order. It ends after the first error. Its
+    /// [`total_charge`](ChargedStream::total_charge) sums the request charges of the pages.
+    ///
+    /// With [`partition_key_range_id`](QueryDocumentsOptions::partition_key_range_id), only
+    /// the documents of that partition key range are queried. With
+    /// [`stream_documents`](QueryDocumentsOptions::stream_documents), the documents of a page
+    /// are yielded as its body is received.
+    pub fn query_documents<T: DeserializeOwned>(
+        &self,
+        mut ctx: Context,
+        query: &str,
+        params: Vec<(String, serde_json::Value)>,
+        options: QueryDocumentsOptions,
+    ) -> ChargedStream<impl Stream<Item = Result<T, crate::Error>> + Unpin + '_> {
+        enum States {
+            Init(Context, Result<bytes::Bytes, crate::Error>),
+            Continuation(Context, bytes::Bytes, String),
+            Done,
+        }
+
+        name_operation(&mut ctx, "query_documents");
+        let params = params
+            .iter()
+            .map(|(name, value)| Param::new(name, value.clone()))
+            .collect::<Vec<_>>();
+        let body = serde_json::to_vec(&Query::with_params(query, params))
+            .map(bytes::Bytes::from)
+            .map_err(crate::Error::from);
+
+        let total_charge = TotalCharge::default();
+        let page_charges = total_charge.clone();
+        let query_metrics = PageQueryMetrics::default();
+        let page_query_metrics = query_metrics.clone();
+        let stream = try_unfold(States::Init(ctx, body), move |state| {
+            let page_charges = page_charges.clone();
+            let page_query_metrics = page_query_metrics.clone();
+            let options = options.clone();
+            async move {
+                let (mut ctx, body, continuation) = match state {
+                    States::Init(ctx, body) => (ctx, body?, None),
+                    States::Continuation(ctx, body, continuation) => {
+                        (ctx, body, Some(continuation))
+                    }
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("{}/docs", self.path()),
+                    http::Method::POST,
+                    ResourceType::Documents,
+                );
+                options.decorate_request(&mut request, body.clone(), continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                page_charges.add_page(response.headers())?;
+                let page = if options.streams_documents() {
+                    let page = stream_query_documents_page::<T>(response)?;
+                    let documents = Either::Right(page.documents);
+                    (documents, page.continuation_token, page.query_metrics)
+                } else {
+                    let page = QueryDocumentsPage::<T>::try_from(response).await?;
+                    let documents = Either::Left(stream::iter(page.documents.into_iter().map(Ok)));
+                    (documents, page.continuation_token, page.query_metrics)
+                };
+                let (documents, continuation_token, query_metrics) = page;
+                if let Some(query_metrics) = query_metrics {
+                    page_query_metrics.lock().unwrap().push(query_metrics);
+                }
+
+                let next = match continuation_token {
+                    Some(continuation) => States::Continuation(ctx, body, continuation),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((documents, next)))
+            }
+        })
+        .try_flatten();
+        ChargedStream::new(Box::pin(stream), total_charge).with_query_metrics(query_metrics)
+    }
+
+    /// read the changes of the partition key range `partition_key_range_id` of a collection
+    ///
+    /// Without a `continuation`, the changes are read from the creation of the collection. Pass
+    /// the `continuation` of the returned page to the next call to only get the documents changed
+    /// since: when there are none, the page is empty and its `continuation` is unchanged. The ids
+    /// of the ranges come from [`list_partition_key_ranges`](Self::list_partition_key_ranges).
+    pub async fn read_change_feed<T: DeserializeOwned>(
+        &self,
+        mut ctx: Context,
+        partition_key_range_id: &str,
+        continuation: Option<String>,
+    ) -> Result<ChangeFeedPage<T>, crate::Error> {
+        name_operation(&mut ctx, "read_change_feed");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/docs", self.path()),
+            http::Method::GET,
+            ResourceType::Documents,
+        );
+        decorate_read_change_feed_request(
+            &mut request,
+            partition_key_range_id,
+            continuation.as_deref(),
+        )?;
+        let response = self.pipeline().send(&mut ctx, &mut request).await?;
+        let response = validate_document_write(
+            response,
+            &[http::StatusCode::OK, http::StatusCode::NOT_MODIFIED],
+        )
+        .await?;
+
+        ChangeFeedPage::try_from(response, continuation).await
+    }
+
+    /// execute the stored procedure `name` of a collection and return its result
+    ///
+    /// `params` are the arguments of the stored procedure's function, in order.
+    pub async fn execute_stored_procedure<T, S, PK>(
+        &self,
+        mut ctx: Context,
+        name: S,
+        params: Vec<serde_json::Value>,
+        partition_key: &PK,
+    ) -> Result<T, crate::Error>
+    where
+        T: DeserializeOwned,
+        S: AsRef<str>,
+        PK: Serialize,
+    {
+        name_operation(&mut ctx, "execute_stored_procedure");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/sprocs/{}", self.path(), name.as_ref()),
+            http::Method::POST,
+            ResourceType::StoredProcedures,
+        );
+        decorate_execute_stored_procedure_request(&mut request, &params, partition_key)?;
+        self.omit_partition_key_if_non_partitioned(&mut request);
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::OK)
+            .await?;
+
+        stored_procedure_result(response).await
+    }
+
+    /// create the stored procedure `stored_procedure_name` with the JavaScript `body`
+    pub async fn create_stored_procedure<S: Into<ReadonlyString>, B: AsRef<str>>(
+        &self,
+        ctx: Context,
+        stored_procedure_name: S,
+        body: B,
+        options: CreateStoredProcedureOptions,
+    ) -> Result<CreateStoredProcedureResponse, crate::Error> {
+        self.stored_procedure_client(stored_procedure_name)
+            .create_stored_procedure(ctx, body, options)
+            .await
+    }
+
+    /// delete the stored procedure `stored_procedure_name`
+    pub async fn delete_stored_procedure<S: Into<ReadonlyString>>(
+        &self,
+        ctx: Context,
+        stored_procedure_name: S,
+        options: DeleteStoredProcedureOptions,
+    ) -> Result<DeleteStoredProcedureResponse, crate::Error> {
+        self.stored_procedure_client(stored_procedure_name)
+            .delete_stored_procedure(ctx, options)
+            .await
+    }
+
+    /// list the stored procedures of a collection, one page after the other, following the continuation
+    /// tokens
+    ///
+    /// The stream ends after the first error, or after the `max_pages` of the options: the
+    /// continuation token of its last page then resumes the listing, as the `continuation` of the
+    /// options.
+    pub fn list_stored_procedures(
+        &self,
+        mut ctx: Context,
+        options: ListStoredProceduresOptions,
+    ) -> impl Stream<Item = Result<ListStoredProceduresResponse, crate::Error>> + Unpin + '_ {
+        enum States {
+            Init,
+            Continuation(String),
+            Done,
+        }
+
+        let max_pages = options.page_limit();
+        name_operation(&mut ctx, "list_stored_procedures");
+        let stream = try_unfold(States::Init, move |state| {
+            let mut ctx = ctx.clone();
+            let options = options.clone();
+            async move {
+                let continuation = match state {
+                    States::Init => None,
+                    States::Continuation(continuation) => Some(continuation),
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("{}/sprocs", self.path()),
+                    http::Method::GET,
+                    ResourceType::StoredProcedures,
+                );
+                options.decorate_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                let page = ListStoredProceduresResponse::try_from(response).await?;
+
+                let next = match &page.continuation_token {
+                    Some(continuation) => States::Continuation(continuation.clone()),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((page, next)))
+            }
+        });
+        Box::pin(stream.take(max_pages))
+    }
+
+    /// create the user defined function `user_defined_function_name` with the JavaScript `body`
+    pub async fn create_user_defined_function<S: Into<ReadonlyString>, B: AsRef<str>>(
+        &self,
+        ctx: Context,
+        user_defined_function_name: S,
+        body: B,
+        options: CreateUserDefinedFunctionOptions,
+    ) -> Result<CreateUserDefinedFunctionResponse, crate::Error> {
+        self.user_defined_function_client(user_defined_function_name)
+            .create_user_defined_function(ctx, body, options)
+            .await
     }
 
-    /// list stored procedures in a collection
-    pub fn list_stored_procedures(&self) -> requests::ListStoredProceduresBuilder<'_, '_> {
-        requests::ListStoredProceduresBuilder::new(self)
+    /// delete the user defined function `user_defined_function_name`
+    pub async fn delete_user_defined_function<S: Into<ReadonlyString>>(
+        &self,
+        ctx: Context,
+        user_defined_function_name: S,
+        options: DeleteUserDefinedFunctionOptions,
+    ) -> Result<DeleteUserDefinedFunctionResponse, crate::Error> {
+        self.user_defined_function_client(user_defined_function_name)
+            .delete_user_defined_function(ctx, options)
+            .await
     }
 
-    /// list user defined functions in a collection
-    pub fn list_user_defined_functions(&self) -> requests::ListUserDefinedFunctionsBuilder<'_, '_> {
-        requests::ListUserDefinedFunctionsBuilder::new(self)
+    /// list the user defined functions of a collection, one page after the other, following the continuation
+    /// tokens
+    ///
+    /// The stream ends after the first error, or after the `max_pages` of the options: the
+    /// continuation token of its last page then resumes the listing, as the `continuation` of the
+    /// options.
+    pub fn list_user_defined_functions(
+        &self,
+        mut ctx: Context,
+        options: ListUserDefinedFunctionsOptions,
+    ) -> impl Stream<Item = Result<ListUserDefinedFunctionsResponse, crate::Error>> + Unpin + '_
+    {
+        enum States {
+            Init,
+            Continuation(String),
+            Done,
+        }
+
+        let max_pages = options.page_limit();
+        name_operation(&mut ctx, "list_user_defined_functions");
+        let stream = try_unfold(States::Init, move |state| {
+            let mut ctx = ctx.clone();
+            let options = options.clone();
+            async move {
+                let continuation = match state {
+                    States::Init => None,
+                    States::Continuation(continuation) => Some(continuation),
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("{}/udfs", self.path()),
+                    http::Method::GET,
+                    ResourceType::UserDefinedFunctions,
+                );
+                options.decorate_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                let page = ListUserDefinedFunctionsResponse::try_from(response).await?;
+
+                let next = match &page.continuation_token {
+                    Some(continuation) => States::Continuation(continuation.clone()),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((page, next)))
+            }
+        });
+        Box::pin(stream.take(max_pages))
     }
 
-    /// list triggers in a collection
-    pub fn list_triggers(&self) -> requests::ListTriggersBuilder<'_, '_> {
-        requests::ListTriggersBuilder::new(self)
+    /// create the trigger `trigger_name`, run with `body` before or after the `trigger_operation`
+    /// on a document when a write includes it
+    pub async fn create_trigger<S: Into<ReadonlyString>, B: AsRef<str>>(
+        &self,
+        ctx: Context,
+        trigger_name: S,
+        trigger_type: TriggerType,
+        trigger_operation: TriggerOperation,
+        body: B,
+        options: CreateTriggerOptions,
+    ) -> Result<CreateTriggerResponse, crate::Error> {
+        self.trigger_client(trigger_name)
+            .create_trigger(ctx, trigger_type, trigger_operation, body, options)
+            .await
+    }
+
+    /// delete the trigger `trigger_name`
+    pub async fn delete_trigger<S: Into<ReadonlyString>>(
+        &self,
+        ctx: Context,
+        trigger_name: S,
+        options: DeleteTriggerOptions,
+    ) -> Result<DeleteTriggerResponse, crate::Error> {
+        self.trigger_client(trigger_name)
+            .delete_trigger(ctx, options)
+            .await
+    }
+
+    /// list the triggers of a collection, one page after the other, following the continuation
+    /// tokens
+    ///
+    /// The stream ends after the first error, or after the `max_pages` of the options: the
+    /// continuation token of its last page then resumes the listing, as the `continuation` of the
+    /// options.
+    pub fn list_triggers(
+        &self,
+        mut ctx: Context,
+        options: ListTriggersOptions,
+    ) -> impl Stream<Item = Result<ListTriggersResponse, crate::Error>> + Unpin + '_ {
+        enum States {
+            Init,
+            Continuation(String),
+            Done,
+        }
+
+        let max_pages = options.page_limit();
+        name_operation(&mut ctx, "list_triggers");
+        let stream = try_unfold(States::Init, move |state| {
+            let mut ctx = ctx.clone();
+            let options = options.clone();
+            async move {
+                let continuation = match state {
+                    States::Init => None,
+                    States::Continuation(continuation) => Some(continuation),
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("{}/triggers", self.path()),
+                    http::Method::GET,
+                    ResourceType::Triggers,
+                );
+                options.decorate_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                let page = ListTriggersResponse::try_from(response).await?;
+
+                let next = match &page.continuation_token {
+                    Some(continuation) => States::Continuation(continuation.clone()),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((page, next)))
+            }
+        });
+        Box::pin(stream.take(max_pages))
     }
 
     /// list the partition key ranges in a collection
@@ -89,6 +638,60 @@ impl CollectionClient {
         requests::GetPartitionKeyRangesBuilder::new(self)
     }
 
+    /// list the partition key ranges of a collection, following the continuation tokens
+    ///
+    /// The stream yields the ranges of each page in order and ends after the first error. Its
+    /// [`total_charge`](ChargedStream::total_charge) sums the request charges of the pages.
+    pub fn list_partition_key_ranges(
+        &self,
+        mut ctx: Context,
+    ) -> ChargedStream<impl Stream<Item = Result<PartitionKeyRange, crate::Error>> + Unpin + '_>
+    {
+        enum States {
+            Init(Context),
+            Continuation(Context, String),
+            Done,
+        }
+
+        name_operation(&mut ctx, "list_partition_key_ranges");
+        let total_charge = TotalCharge::default();
+        let page_charges = total_charge.clone();
+        let stream = try_unfold(States::Init(ctx), move |state| {
+            let page_charges = page_charges.clone();
+            async move {
+                let (mut ctx, continuation) = match state {
+                    States::Init(ctx) => (ctx, None),
+                    States::Continuation(ctx, continuation) => (ctx, Some(continuation)),
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("{}/pkranges", self.path()),
+                    http::Method::GET,
+                    ResourceType::PartitionKeyRanges,
+                );
+                decorate_list_partition_key_ranges_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                page_charges.add_page(response.headers())?;
+                let page = PartitionKeyRangesPage::try_from(response).await?;
+
+                let next = match page.continuation_token {
+                    Some(continuation) => States::Continuation(ctx, continuation),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((page.partition_key_ranges, next)))
+            }
+        })
+        .map_ok(|ranges| stream::iter(ranges.into_iter().map(Ok)))
+        .try_flatten();
+        ChargedStream::new(Box::pin(stream), total_charge)
+    }
+
     /// convert into a [`DocumentClient`]
     pub fn into_document_client<S: Into<String>, PK: Serialize>(
         self,
@@ -103,6 +706,11 @@ impl CollectionClient {
         TriggerClient::new(self, trigger_name)
     }
 
+    /// get a [`TriggerClient`] for a trigger of this collection, keeping this client
+    pub fn trigger_client<S: Into<ReadonlyString>>(&self, trigger_name: S) -> TriggerClient {
+        TriggerClient::new(self.clone(), trigger_name)
+    }
+
     /// convert into a [`UserDefinedFunctionClient`]
     pub fn into_user_defined_function_client<S: Into<ReadonlyString>>(
         self,
@@ -111,6 +719,15 @@ impl CollectionClient {
         UserDefinedFunctionClient::new(self, user_defined_function_name)
     }
 
+    /// get a [`UserDefinedFunctionClient`] for a user defined function of this collection, keeping
+    /// this client
+    pub fn user_defined_function_client<S: Into<ReadonlyString>>(
+        &self,
+        user_defined_function_name: S,
+    ) -> UserDefinedFunctionClient {
+        UserDefinedFunctionClient::new(self.clone(), user_defined_function_name)
+    }
+
     /// convert into a [`StoredProcedureClient`]
     pub fn into_stored_procedure_client<S: Into<ReadonlyString>>(
         self,
@@ -119,22 +736,1171 @@ impl CollectionClient {
         StoredProcedureClient::new(self, stored_procedure_name)
     }
 
+    /// get a [`StoredProcedureClient`] for a stored procedure of this collection, keeping this
+    /// client
+    pub fn stored_procedure_client<S: Into<ReadonlyString>>(
+        &self,
+        stored_procedure_name: S,
+    ) -> StoredProcedureClient {
+        StoredProcedureClient::new(self.clone(), stored_procedure_name)
+    }
+
+    async fn write_document<'a, T>(
+        &self,
+        mut ctx: Context,
+        document: &'a T,
+        options: CreateDocumentOptions,
+        is_upsert: IsUpsert,
+        expected: &[http::StatusCode],
+    ) -> Result<CreateDocumentResponse<T>, crate::Error>
+    where
+        T: Serialize + DeserializeOwned + CosmosEntity<'a>,
+    {
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/docs", self.path()),
+            http::Method::POST,
+            ResourceType::Documents,
+        );
+        options.decorate_request(&mut request, document, is_upsert)?;
+        self.omit_partition_key_if_non_partitioned(&mut request);
+        let response = self.pipeline().send(&mut ctx, &mut request).await?;
+        let response = validate_document_write(response, expected).await?;
+
+        CreateDocumentResponse::try_from(response).await
+    }
+
     pub(crate) fn prepare_request_with_collection_name(
         &self,
         method: http::Method,
     ) -> http::request::Builder {
-        self.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}",
-                self.database_client().database_name(),
-                self.collection_name()
-            ),
-            method,
-            ResourceType::Collections,
-        )
+        self.cosmos_client()
+            .prepare_request(self.path(), method, ResourceType::Collections)
     }
 
     pub(crate) fn http_client(&self) -> &dyn HttpClient {
         self.cosmos_client().http_client()
     }
+
+    fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::cosmos_client::EMULATOR_ACCOUNT_KEY;
+    use crate::mock_transport::{MockResponse, MockTransport};
+    use crate::resources::permission::AuthorizationToken;
+    use azure_core::headers::CONTINUATION;
+    use azure_core::prelude::IfMatchCondition;
+    use azure_core::Body;
+    use http::HeaderValue;
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        id: String,
+        n: u32,
+    }
+
+    impl<'a> CosmosEntity<'a> for Item {
+        type Entity = &'a str;
+
+        fn partition_key(&'a self) -> Self::Entity {
+            &self.id
+        }
+    }
+
+    fn collection_client(transport: Arc<MockTransport>) -> CollectionClient {
+        CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport),
+        )
+        .into_database_client("db")
+        .into_collection_client("coll")
+    }
+
+    fn page(ids: &[(&str, u32)]) -> MockResponse {
+        let documents = ids
+            .iter()
+            .map(|(id, n)| serde_json::json!({ "id": id, "n": n, "_rid": "AAAAAA==" }))
+            .collect::<Vec<_>>();
+        let body = serde_json::json!({
+            "_rid": "JHwXAA==",
+            "Documents": documents,
+            "_count": documents.len(),
+        });
+        MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn query_documents_follows_continuation_tokens() {
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1), ("b", 2)]).header(CONTINUATION, "page-2"),
+            page(&[("c", 3)]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let items: Vec<Item> = client
+            .query_documents(
+                Context::new(),
+                "SELECT * FROM c WHERE c.n > @n",
+                vec![("@n".to_owned(), serde_json::json!(0))],
+                QueryDocumentsOptions::new(),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(items[2].n, 3);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::POST);
+            assert_eq!(request.uri().path(), "/dbs/db/colls/coll/docs");
+            assert_eq!(
+                request.headers().get("x-ms-documentdb-isquery"),
+                Some(&HeaderValue::from_static("true"))
+            );
+            assert_eq!(
+                request.headers().get(http::header::CONTENT_TYPE),
+                Some(&HeaderValue::from_static("application/query+json"))
+            );
+            match request.body() {
+                Body::Bytes(body) => assert_eq!(
+                    serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+                    serde_json::json!({
+                        "query": "SELECT * FROM c WHERE c.n > @n",
+                        "parameters": [{ "name": "@n", "value": 0 }],
+                    })
+                ),
+                _ => panic!("the query should be sent as bytes"),
+            }
+        }
+        assert!(requests[0].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn query_documents_can_stream_the_documents_of_the_pages() {
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1), ("b", 2)]).header(CONTINUATION, "page-2"),
+            page(&[("c", 3)]),
+            // cut after the first document
+            MockResponse::new(
+                http::StatusCode::OK,
+                r#"{"_rid":"JHwXAA==","Documents":[{"id":"d","n":4},{"id":"e""#,
+            ),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let items: Vec<Item> = client
+            .query_documents(
+                Context::new(),
+                "SELECT * FROM c",
+                Vec::new(),
+                QueryDocumentsOptions::new().stream_documents(true),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(
+            transport.requests()[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+
+        // the documents received before a body is cut short are still yielded
+        let mut items = client.query_documents::<Item>(
+            Context::new(),
+            "SELECT * FROM c",
+            Vec::new(),
+            QueryDocumentsOptions::new().stream_documents(true),
+        );
+        assert_eq!(items.next().await.unwrap().unwrap().id, "d");
+        assert!(items.next().await.unwrap().is_err());
+        assert!(items.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn query_documents_targets_a_partition_key_range() {
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1)]).header(CONTINUATION, "page-2"),
+            page(&[("b", 2)]),
+            page(&[("c", 3)]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let items: Vec<Item> = client
+            .query_documents(
+                Context::new(),
+                "SELECT * FROM c",
+                vec![],
+                QueryDocumentsOptions::new().partition_key_range_id("1"),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 2);
+        client
+            .query_documents::<Item>(
+                Context::new(),
+                "SELECT * FROM c",
+                vec![],
+                QueryDocumentsOptions::new(),
+            )
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        // every page of the query is read from the range
+        for request in &requests[..2] {
+            assert_eq!(
+                request.headers().get("x-ms-documentdb-partitionkeyrangeid"),
+                Some(&HeaderValue::from_static("1"))
+            );
+        }
+        assert!(requests[2]
+            .headers()
+            .get("x-ms-documentdb-partitionkeyrangeid")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn query_documents_reports_the_query_metrics_asked_for() {
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1)])
+                .header(CONTINUATION, "page-2")
+                .header(
+                    "x-ms-documentdb-query-metrics",
+                    "totalExecutionTimeInMs=0.50;indexLookupTimeInMs=0.10;documentLoadTimeInMs=0.20;retrievedDocumentCount=1",
+                ),
+            page(&[("b", 2)]).header(
+                "x-ms-documentdb-query-metrics",
+                "totalExecutionTimeInMs=0.25;retrievedDocumentCount=1",
+            ),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let mut stream = client.query_documents::<Item>(
+            Context::new(),
+            "SELECT * FROM c",
+            vec![],
+            QueryDocumentsOptions::new().populate_query_metrics(true),
+        );
+        assert!(stream.query_metrics().is_empty());
+        while stream.try_next().await.unwrap().is_some() {}
+
+        let query_metrics = stream.query_metrics();
+        assert_eq!(query_metrics.len(), 2);
+        assert_eq!(
+            query_metrics[0].index_lookup_time,
+            std::time::Duration::from_micros(100)
+        );
+        assert_eq!(query_metrics[1].retrieved_document_count, 1);
+        for request in transport.requests() {
+            assert_eq!(
+                request
+                    .headers()
+                    .get("x-ms-documentdb-populatequerymetrics"),
+                Some(&HeaderValue::from_static("true"))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn query_documents_sums_the_request_charges_of_the_pages() {
+        use futures::StreamExt;
+
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1), ("b", 2)])
+                .header(CONTINUATION, "page-2")
+                .header("x-ms-request-charge", "2.79"),
+            page(&[("c", 3)]).header("x-ms-request-charge", "3.5"),
+        ]));
+        let client = collection_client(transport);
+
+        let mut stream = client.query_documents::<Item>(
+            Context::new(),
+            "SELECT * FROM c",
+            vec![],
+            QueryDocumentsOptions::new(),
+        );
+        assert_eq!(stream.total_charge(), 0.0);
+        assert_eq!(stream.next().await.unwrap().unwrap().id, "a");
+        assert_eq!(stream.total_charge(), 2.79);
+        while let Some(item) = stream.next().await {
+            item.unwrap();
+        }
+        assert_eq!(stream.total_charge(), 2.79 + 3.5);
+    }
+
+    fn partition_key_range(
+        id: &str,
+        min_inclusive: &str,
+        max_exclusive: &str,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "_rid": "3XQpAJqxH4ACAAAAAAAAUA==",
+            "id": id,
+            "_etag": "\"00006b00-0000-0d00-0000-5f1fc1bb0000\"",
+            "minInclusive": min_inclusive,
+            "maxExclusive": max_exclusive,
+            "ridPrefix": 0,
+            "_self": "dbs/3XQpAA==/colls/3XQpAJqxH4A=/pkranges/3XQpAJqxH4ACAAAAAAAAUA==/",
+            "throughputFraction": 0.5,
+            "status": "online",
+            "parents": ["0"],
+            "_ts": 1595916731,
+            "_lsn": 17,
+        })
+    }
+
+    #[tokio::test]
+    async fn list_partition_key_ranges_follows_continuation_tokens() {
+        let pkranges = |ranges: Vec<serde_json::Value>| {
+            let body = serde_json::json!({
+                "_rid": "3XQpAJqxH4A=",
+                "PartitionKeyRanges": ranges,
+                "_count": ranges.len(),
+            });
+            MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            pkranges(vec![partition_key_range("1", "", "3FFFFFFFFFFFFFFF")])
+                .header(CONTINUATION, "page-2"),
+            pkranges(vec![partition_key_range("2", "3FFFFFFFFFFFFFFF", "FF")]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let ranges: Vec<PartitionKeyRange> = client
+            .list_partition_key_ranges(Context::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            ranges
+                .iter()
+                .map(|range| (
+                    range.id.as_str(),
+                    range.min_inclusive.as_str(),
+                    range.max_exclusive.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("1", "", "3FFFFFFFFFFFFFFF"),
+                ("2", "3FFFFFFFFFFFFFFF", "FF")
+            ]
+        );
+        assert_eq!(ranges[0].parents, ["0"]);
+        assert_eq!(ranges[0].throughput_fraction, 0.5);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::GET);
+            assert_eq!(request.uri().path(), "/dbs/db/colls/coll/pkranges");
+        }
+        assert!(requests[0].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn read_change_feed_resumes_from_the_etag_of_the_previous_read() {
+        let transport = Arc::new(MockTransport::new(vec![
+            page(&[("a", 1), ("b", 2)]).header("etag", "\"17\""),
+            MockResponse::new(http::StatusCode::NOT_MODIFIED, "").header("etag", "\"17\""),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let first = client
+            .read_change_feed::<Item>(Context::new(), "0", None)
+            .await
+            .unwrap();
+        assert!(first.has_changes());
+        assert_eq!(
+            first
+                .documents
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(first.continuation.as_deref(), Some("\"17\""));
+
+        let second = client
+            .read_change_feed::<Item>(Context::new(), "0", first.continuation.clone())
+            .await
+            .unwrap();
+        assert!(!second.has_changes());
+        assert_eq!(second.continuation, first.continuation);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::GET);
+            assert_eq!(request.uri().path(), "/dbs/db/colls/coll/docs");
+            assert_eq!(
+                request.headers().get("a-im"),
+                Some(&HeaderValue::from_static("Incremental feed"))
+            );
+            assert_eq!(
+                request.headers().get("x-ms-documentdb-partitionkeyrangeid"),
+                Some(&HeaderValue::from_static("0"))
+            );
+        }
+        assert!(requests[0]
+            .headers()
+            .get(http::header::IF_NONE_MATCH)
+            .is_none());
+        assert_eq!(
+            requests[1].headers().get(http::header::IF_NONE_MATCH),
+            Some(&HeaderValue::from_static("\"17\""))
+        );
+    }
+
+    #[tokio::test]
+    async fn upserts_differ_from_creates_by_their_header() {
+        let item = Item {
+            id: "a".to_owned(),
+            n: 1,
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            MockResponse::document(http::StatusCode::CREATED, serde_json::json!(item)),
+            MockResponse::document(http::StatusCode::OK, serde_json::json!(item)),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let created = client
+            .create_document(Context::new(), &item, CreateDocumentOptions::new())
+            .await
+            .unwrap();
+        assert!(!created.is_update);
+        assert_eq!(created.document.document, item);
+        assert_eq!(
+            created.document.document_attributes.rid(),
+            "JHwXAKVFRU4BAAAAAAAAAA=="
+        );
+        let upserted = client
+            .upsert_document(Context::new(), &item, CreateDocumentOptions::new())
+            .await
+            .unwrap();
+        assert!(upserted.is_update);
+
+        let requests = transport.requests();
+        let is_upsert = |request: &azure_core::Request| {
+            request.headers().get("x-ms-documentdb-is-upsert").cloned()
+        };
+        assert_eq!(
+            is_upsert(&requests[0]),
+            Some(HeaderValue::from_static("false"))
+        );
+        assert_eq!(
+            is_upsert(&requests[1]),
+            Some(HeaderValue::from_static("true"))
+        );
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::POST);
+            assert_eq!(request.uri().path(), "/dbs/db/colls/coll/docs");
+            assert_eq!(
+                request.headers().get("x-ms-documentdb-partitionkey"),
+                Some(&HeaderValue::from_static("[\"a\"]"))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn writes_include_the_triggers_asked_for() {
+        let item = Item {
+            id: "a".to_owned(),
+            n: 1,
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            MockResponse::document(http::StatusCode::CREATED, serde_json::json!(item)),
+            MockResponse::document(http::StatusCode::OK, serde_json::json!(item)),
+        ]));
+        let client = collection_client(transport.clone());
+
+        client
+            .create_document(
+                Context::new(),
+                &item,
+                CreateDocumentOptions::new()
+                    .pre_triggers(vec!["validate".to_owned(), "stamp".to_owned()])
+                    .post_triggers(vec!["audit".to_owned()]),
+            )
+            .await
+            .unwrap();
+        client
+            .replace_document(Context::new(), "a", &item, ReplaceDocumentOptions::new())
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(
+            requests[0]
+                .headers()
+                .get("x-ms-documentdb-pre-trigger-include"),
+            Some(&HeaderValue::from_static("validate,stamp"))
+        );
+        assert_eq!(
+            requests[0]
+                .headers()
+                .get("x-ms-documentdb-post-trigger-include"),
+            Some(&HeaderValue::from_static("audit"))
+        );
+        assert!(requests[1]
+            .headers()
+            .get("x-ms-documentdb-pre-trigger-include")
+            .is_none());
+        assert!(requests[1]
+            .headers()
+            .get("x-ms-documentdb-post-trigger-include")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn replace_document_puts_the_document_by_id() {
+        let item = Item {
+            id: "a".to_owned(),
+            n: 2,
+        };
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::document(
+            http::StatusCode::OK,
+            serde_json::json!(item),
+        )]));
+        let client = collection_client(transport.clone());
+
+        let replaced = client
+            .replace_document(
+                Context::new(),
+                "a",
+                &item,
+                ReplaceDocumentOptions::new().if_match_condition(IfMatchCondition::Match("etag")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(replaced.document.document, item);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method(), http::Method::PUT);
+        assert_eq!(request.uri().path(), "/dbs/db/colls/coll/docs/a");
+        assert_eq!(
+            request.headers().get(http::header::IF_MATCH),
+            Some(&HeaderValue::from_static("etag"))
+        );
+        assert!(request.headers().get("x-ms-documentdb-is-upsert").is_none());
+    }
+
+    #[tokio::test]
+    async fn existing_documents_are_conflicts() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::CONFLICT,
+            r#"{"code":"Conflict","message":"Entity with the specified id already exists in the system."}"#,
+        )]));
+        let client = collection_client(transport);
+
+        let item = Item {
+            id: "a".to_owned(),
+            n: 1,
+        };
+        match client
+            .create_document(Context::new(), &item, CreateDocumentOptions::new())
+            .await
+        {
+            Err(crate::Error::Conflict { body }) => assert!(body.contains("already exists")),
+            other => panic!("expected a conflict, got {:?}", other),
+        }
+    }
+
+    fn deleted() -> MockResponse {
+        MockResponse::new(http::StatusCode::NO_CONTENT, "")
+            .header("x-ms-session-token", "0:-1#3")
+            .header("x-ms-request-charge", "6.29")
+            .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+    }
+
+    #[tokio::test]
+    async fn delete_document_sends_the_partition_key_and_etag() {
+        let transport = Arc::new(MockTransport::new(vec![deleted(), deleted()]));
+        let client = collection_client(transport.clone());
+
+        let response = client
+            .delete_document(Context::new(), "a", &"pk", None)
+            .await
+            .unwrap();
+        assert_eq!(response.session_token, "0:-1#3");
+        assert_eq!(response.charge, 6.29);
+        client
+            .delete_document(
+                Context::new(),
+                "b",
+                &42,
+                Some("\"0200d9a6-0000-0d00-0000-5e1f9d200000\"".into()),
+            )
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].method(), http::Method::DELETE);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/colls/coll/docs/a");
+        assert_eq!(
+            requests[0].headers().get("x-ms-documentdb-partitionkey"),
+            Some(&HeaderValue::from_static("[\"pk\"]"))
+        );
+        assert!(requests[0].headers().get(http::header::IF_MATCH).is_none());
+        assert_eq!(requests[1].uri().path(), "/dbs/db/colls/coll/docs/b");
+        assert_eq!(
+            requests[1].headers().get("x-ms-documentdb-partitionkey"),
+            Some(&HeaderValue::from_static("[42]"))
+        );
+        assert_eq!(
+            requests[1].headers().get(http::header::IF_MATCH),
+            Some(&HeaderValue::from_static(
+                "\"0200d9a6-0000-0d00-0000-5e1f9d200000\""
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn non_partitioned_collections_are_sent_no_partition_key() {
+        let item = Item {
+            id: "a".to_owned(),
+            n: 1,
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            MockResponse::document(http::StatusCode::CREATED, serde_json::json!(item)),
+            MockResponse::document(http::StatusCode::CREATED, serde_json::json!(item)),
+            deleted(),
+        ]));
+        let partitioned = collection_client(transport.clone());
+        let non_partitioned = partitioned.clone().non_partitioned();
+        assert!(partitioned.is_partitioned());
+        assert!(!non_partitioned.is_partitioned());
+
+        for client in &[&partitioned, &non_partitioned] {
+            client
+                .create_document(Context::new(), &item, CreateDocumentOptions::new())
+                .await
+                .unwrap();
+        }
+        non_partitioned
+            .delete_document(Context::new(), "a", &"a", None)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[0].headers().get("x-ms-documentdb-partitionkey"),
+            Some(&HeaderValue::from_static("[\"a\"]"))
+        );
+        assert!(requests[1]
+            .headers()
+            .get("x-ms-documentdb-partitionkey")
+            .is_none());
+        assert!(requests[2]
+            .headers()
+            .get("x-ms-documentdb-partitionkey")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn deleting_a_missing_document_is_not_found() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::NOT_FOUND,
+            r#"{"code":"NotFound","message":"Entity with the specified id does not exist in the system."}"#,
+        )]));
+        let client = collection_client(transport);
+
+        match client
+            .delete_document(Context::new(), "a", &"a", None)
+            .await
+        {
+            Err(crate::Error::NotFound { body }) => assert!(body.contains("does not exist")),
+            other => panic!("expected not found, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn not_found_does_not_parse_the_body() {
+        let transport = Arc::new(MockTransport::new(vec![
+            MockResponse::new(http::StatusCode::NOT_FOUND, "<html>not an envelope</html>"),
+            MockResponse::new(
+                http::StatusCode::NOT_FOUND,
+                r#"{"code":"NotFound","message":"Entity with the specified id does not exist in the system."}"#,
+            ),
+        ]));
+        let client = collection_client(transport);
+
+        // a body that would fail to parse still makes a `NotFound`
+        let error = client
+            .delete_document(Context::new(), "a", &"a", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::NotFound { .. }));
+        assert_eq!(error.envelope(), None);
+
+        let error = client
+            .delete_document(Context::new(), "a", &"a", None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.envelope(),
+            Some(crate::ErrorEnvelope {
+                code: "NotFound".to_owned(),
+                message: "Entity with the specified id does not exist in the system.".to_owned(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn deleting_a_changed_document_fails_its_precondition() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::PRECONDITION_FAILED,
+            r#"{"code":"PreconditionFailed","message":"Operation cannot be performed because one of the specified precondition is not met."}"#,
+        )]));
+        let client = collection_client(transport);
+
+        match client
+            .delete_document(Context::new(), "a", &"a", Some("\"stale\"".into()))
+            .await
+        {
+            Err(crate::Error::PreconditionFailed { body }) => {
+                assert!(body.contains("precondition"))
+            }
+            other => panic!("expected a failed precondition, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_stored_procedure_returns_its_result() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::OK,
+            r#"{"id":"a","n":3}"#,
+        )]));
+        let client = collection_client(transport.clone());
+
+        let item: Item = client
+            .execute_stored_procedure(
+                Context::new(),
+                "increment",
+                vec![serde_json::json!("a"), serde_json::json!(2)],
+                &"a",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            item,
+            Item {
+                id: "a".to_owned(),
+                n: 3
+            }
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(
+            requests[0].uri().path(),
+            "/dbs/db/colls/coll/sprocs/increment"
+        );
+        assert_eq!(
+            requests[0].headers().get("x-ms-documentdb-partitionkey"),
+            Some(&HeaderValue::from_static("[\"a\"]"))
+        );
+        match requests[0].body() {
+            Body::Bytes(body) => assert_eq!(&body[..], br#"["a",2]"#),
+            _ => panic!("the parameters should be sent as bytes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn transactional_batch_sends_its_operations_in_one_request() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::OK,
+            r#"[
+                { "statusCode": 201, "requestCharge": 6.2, "eTag": "\"1\"", "resourceBody": { "id": "a", "n": 1 } },
+                { "statusCode": 204, "requestCharge": 5.1 }
+            ]"#,
+        )
+        .header("x-ms-session-token", "0:-1#4")
+        .header("x-ms-request-charge", "11.3")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")]));
+        let client = collection_client(transport.clone());
+        let batch = TransactionalBatch::new(&"a")
+            .unwrap()
+            .create_document(&Item {
+                id: "a".to_owned(),
+                n: 1,
+            })
+            .unwrap()
+            .delete_document("b");
+
+        let response = client
+            .execute_transactional_batch(Context::new(), &batch)
+            .await
+            .unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.charge, 11.3);
+        assert_eq!(
+            response
+                .results
+                .iter()
+                .map(|result| result.status_code)
+                .collect::<Vec<_>>(),
+            [http::StatusCode::CREATED, http::StatusCode::NO_CONTENT]
+        );
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/colls/coll/docs");
+        let headers = requests[0].headers();
+        assert_eq!(
+            headers.get("x-ms-documentdb-partitionkey"),
+            Some(&HeaderValue::from_static("[\"a\"]"))
+        );
+        assert_eq!(
+            headers.get("x-ms-cosmos-is-batch-request"),
+            Some(&HeaderValue::from_static("True"))
+        );
+        assert_eq!(
+            headers.get("x-ms-cosmos-batch-atomic"),
+            Some(&HeaderValue::from_static("True"))
+        );
+        match requests[0].body() {
+            Body::Bytes(body) => assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+                serde_json::json!([
+                    { "operationType": "Create", "resourceBody": { "id": "a", "n": 1 } },
+                    { "operationType": "Delete", "id": "b" }
+                ])
+            ),
+            _ => panic!("the operations should be sent as bytes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_failed_transactional_batch_tells_which_operation_failed() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::MULTI_STATUS,
+            r#"[{ "statusCode": 409, "requestCharge": 1.2 }, { "statusCode": 424 }]"#,
+        )
+        .header("x-ms-session-token", "0:-1#4")
+        .header("x-ms-request-charge", "1.2")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")]));
+        let client = collection_client(transport);
+        let batch = TransactionalBatch::new(&"a")
+            .unwrap()
+            .create_document(&Item {
+                id: "a".to_owned(),
+                n: 1,
+            })
+            .unwrap()
+            .replace_document(
+                "b",
+                &Item {
+                    id: "b".to_owned(),
+                    n: 2,
+                },
+            )
+            .unwrap();
+
+        let response = client
+            .execute_transactional_batch(Context::new(), &batch)
+            .await
+            .unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(response.results[0].status_code, http::StatusCode::CONFLICT);
+        assert_eq!(
+            response.results[1].status_code,
+            http::StatusCode::FAILED_DEPENDENCY
+        );
+    }
+
+    fn trigger(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "body": "function audit() {}",
+            "triggerType": "Post",
+            "triggerOperation": "Create",
+            "_rid": "JHwXAN5LAAABAAAAAAAAcA==",
+            "_ts": 1579130144,
+            "_self": "dbs/JHwXAA==/colls/JHwXAN5LAAA=/triggers/JHwXAN5LAAABAAAAAAAAcA==/",
+            "_etag": "\"00001b01-0000-0d00-0000-5e1f9d200000\"",
+        })
+    }
+
+    #[tokio::test]
+    async fn create_trigger_posts_its_definition() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::CREATED,
+            serde_json::to_vec(&trigger("audit")).unwrap(),
+        )
+        .header("etag", "\"00001b01-0000-0d00-0000-5e1f9d200000\"")
+        .header("x-ms-session-token", "0:-1#3")
+        .header("x-ms-request-charge", "4.95")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")]));
+        let client = collection_client(transport.clone());
+
+        let response = client
+            .create_trigger(
+                Context::new(),
+                "audit",
+                TriggerType::Post,
+                TriggerOperation::Create,
+                "function audit() {}",
+                CreateTriggerOptions::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.trigger.id, "audit");
+        assert_eq!(response.trigger.trigger_type, TriggerType::Post);
+        assert_eq!(response.trigger.trigger_operation, TriggerOperation::Create);
+        assert_eq!(response.charge, 4.95);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/colls/coll/triggers");
+        match requests[0].body() {
+            Body::Bytes(body) => assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+                serde_json::json!({
+                    "id": "audit",
+                    "triggerType": "Post",
+                    "triggerOperation": "Create",
+                    "body": "function audit() {}",
+                })
+            ),
+            _ => panic!("the trigger should be sent as bytes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_triggers_follows_continuation_tokens() {
+        use azure_core::headers::MAX_ITEM_COUNT;
+
+        let triggers = |ids: &[&str]| {
+            let triggers = ids.iter().map(|id| trigger(id)).collect::<Vec<_>>();
+            let body = serde_json::json!({
+                "_rid": "JHwXAN5LAAA=",
+                "Triggers": triggers,
+                "_count": triggers.len(),
+            });
+            MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+                .header("x-ms-session-token", "0:-1#3")
+                .header("x-ms-request-charge", "1")
+                .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            triggers(&["audit", "stamp"]).header(CONTINUATION, "page-2"),
+            triggers(&["validate"]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let pages: Vec<ListTriggersResponse> = client
+            .list_triggers(Context::new(), ListTriggersOptions::new().max_item_count(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.triggers.iter().map(|t| t.id.as_str()).collect())
+                .collect::<Vec<Vec<_>>>(),
+            [vec!["audit", "stamp"], vec!["validate"]]
+        );
+        assert_eq!(pages[0].continuation_token.as_deref(), Some("page-2"));
+        assert_eq!(pages[1].continuation_token, None);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::GET);
+            assert_eq!(request.uri().path(), "/dbs/db/colls/coll/triggers");
+            assert_eq!(
+                request.headers().get(MAX_ITEM_COUNT),
+                Some(&HeaderValue::from(2))
+            );
+        }
+        assert!(requests[0].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+    }
+
+    fn script(id: &str, kind: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "body": "function () {}",
+            "_rid": "JHwXAN5LAAABAAAAAAAAgA==",
+            "_ts": 1579130144,
+            "_self": format!("dbs/JHwXAA==/colls/JHwXAN5LAAA=/{}/JHwXAN5LAAABAAAAAAAAgA==/", kind),
+            "_etag": "\"00001c01-0000-0d00-0000-5e1f9d200000\"",
+        })
+    }
+
+    fn created_script(id: &str, kind: &str) -> MockResponse {
+        MockResponse::new(
+            http::StatusCode::CREATED,
+            serde_json::to_vec(&script(id, kind)).unwrap(),
+        )
+        .header("etag", "\"00001c01-0000-0d00-0000-5e1f9d200000\"")
+        .header("x-ms-session-token", "0:-1#3")
+        .header("x-ms-request-charge", "4.95")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+    }
+
+    fn scripts(field: &str, kind: &str, ids: &[&str]) -> MockResponse {
+        let scripts = ids.iter().map(|id| script(id, kind)).collect::<Vec<_>>();
+        let mut body = serde_json::json!({ "_rid": "JHwXAN5LAAA=", "_count": scripts.len() });
+        body[field] = scripts.into();
+        MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+            .header("x-ms-session-token", "0:-1#3")
+            .header("x-ms-request-charge", "1")
+            .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+    }
+
+    fn assert_script_posted(request: &azure_core::Request, path: &str, id: &str) {
+        assert_eq!(request.method(), http::Method::POST);
+        assert_eq!(request.uri().path(), path);
+        match request.body() {
+            Body::Bytes(body) => assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+                serde_json::json!({ "id": id, "body": "function () {}" })
+            ),
+            _ => panic!("the script should be sent as bytes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_stored_procedure_and_user_defined_function_post_their_body() {
+        let transport = Arc::new(MockTransport::new(vec![
+            created_script("bulk_import", "sprocs"),
+            created_script("tax", "udfs"),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let response = client
+            .create_stored_procedure(
+                Context::new(),
+                "bulk_import",
+                "function () {}",
+                CreateStoredProcedureOptions::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.stored_procedure.id, "bulk_import");
+        assert_eq!(response.charge, 4.95);
+
+        let response = client
+            .create_user_defined_function(
+                Context::new(),
+                "tax",
+                "function () {}",
+                CreateUserDefinedFunctionOptions::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.user_defined_function.id, "tax");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_script_posted(&requests[0], "/dbs/db/colls/coll/sprocs", "bulk_import");
+        assert_script_posted(&requests[1], "/dbs/db/colls/coll/udfs", "tax");
+    }
+
+    #[tokio::test]
+    async fn list_stored_procedures_follows_continuation_tokens() {
+        let transport = Arc::new(MockTransport::new(vec![
+            scripts("StoredProcedures", "sprocs", &["a", "b"]).header(CONTINUATION, "page-2"),
+            scripts("StoredProcedures", "sprocs", &["c"]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let pages: Vec<ListStoredProceduresResponse> = client
+            .list_stored_procedures(Context::new(), ListStoredProceduresOptions::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page
+                    .stored_procedures
+                    .iter()
+                    .map(|s| s.id.as_str())
+                    .collect())
+                .collect::<Vec<Vec<_>>>(),
+            [vec!["a", "b"], vec!["c"]]
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/colls/coll/sprocs");
+        assert!(requests[0].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn list_user_defined_functions_follows_continuation_tokens() {
+        let transport = Arc::new(MockTransport::new(vec![
+            scripts("UserDefinedFunctions", "udfs", &["tax"]).header(CONTINUATION, "page-2"),
+            scripts("UserDefinedFunctions", "udfs", &["discount"]),
+        ]));
+        let client = collection_client(transport.clone());
+
+        let pages: Vec<ListUserDefinedFunctionsResponse> = client
+            .list_user_defined_functions(Context::new(), ListUserDefinedFunctionsOptions::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page
+                    .user_defined_functions
+                    .iter()
+                    .map(|f| f.id.as_str())
+                    .collect())
+                .collect::<Vec<Vec<_>>>(),
+            [vec!["tax"], vec!["discount"]]
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].uri().path(), "/dbs/db/colls/coll/udfs");
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&HeaderValue::from_static("page-2"))
+        );
+    }
 }
@@ -0,0 +1,205 @@
+use super::CosmosClient;
+use crate::operations::{CreateDatabaseOptions, CreateDatabaseResponse};
+use crate::resources::Database;
+use crate::responses::DeleteDatabaseResponse;
+use azure_core::Context;
+use futures::stream::TryStreamExt;
+
+/// The database operations of a [`CosmosClient`], as a trait
+///
+/// Code calling these operations can accept an `impl CosmosDatabaseApi` instead of a
+/// `CosmosClient`: its tests then pass a fake implementation of the trait, with neither an account
+/// nor a transport.
+///
+/// ```
+/// use azure_core::Context;
+/// use azure_cosmos::prelude::*;
+///
+/// /// Creates the database `name` unless it already exists
+/// async fn ensure_database(
+///     client: &impl CosmosDatabaseApi,
+///     name: &str,
+/// ) -> Result<(), azure_cosmos::Error> {
+///     if !client.list_databases().await?.iter().any(|database| database.id == name) {
+///         client
+///             .create_database(Context::new(), name, CreateDatabaseOptions::new())
+///             .await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait CosmosDatabaseApi: Send + Sync {
+    /// Create a database, see [`CosmosClient::create_database`]
+    async fn create_database(
+        &self,
+        ctx: Context,
+        database_name: &str,
+        options: CreateDatabaseOptions,
+    ) -> Result<CreateDatabaseResponse, crate::Error>;
+
+    /// List all the databases of the account, following the continuation tokens until the last
+    /// page
+    async fn list_databases(&self) -> Result<Vec<Database>, crate::Error>;
+
+    /// Delete a database and everything it holds
+    async fn delete_database(
+        &self,
+        database_name: &str,
+    ) -> Result<DeleteDatabaseResponse, crate::Error>;
+}
+
+#[async_trait::async_trait]
+impl CosmosDatabaseApi for CosmosClient {
+    async fn create_database(
+        &self,
+        ctx: Context,
+        database_name: &str,
+        options: CreateDatabaseOptions,
+    ) -> Result<CreateDatabaseResponse, crate::Error> {
+        CosmosClient::create_database(self, ctx, database_name, options).await
+    }
+
+    async fn list_databases(&self) -> Result<Vec<Database>, crate::Error> {
+        CosmosClient::list_databases(self)
+            .stream()
+            .map_ok(|page| page.databases)
+            .try_concat()
+            .await
+    }
+
+    async fn delete_database(
+        &self,
+        database_name: &str,
+    ) -> Result<DeleteDatabaseResponse, crate::Error> {
+        self.clone()
+            .into_database_client(database_name.to_owned())
+            .delete_database()
+            .execute()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CosmosHeaders;
+    use std::sync::Mutex;
+
+    /// A fake account, holding its databases in memory
+    #[derive(Default)]
+    struct FakeAccount {
+        databases: Mutex<Vec<String>>,
+    }
+
+    fn database(name: &str) -> Database {
+        Database {
+            id: name.to_owned(),
+            rid: "AAAAAA==".to_owned(),
+            ts: 1_600_000_000,
+            _self: format!("dbs/{}/", name),
+            etag: "\"00000000-0000-0000-0000-000000000000\"".to_owned(),
+            colls: "colls/".to_owned(),
+            users: "users/".to_owned(),
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CosmosDatabaseApi for FakeAccount {
+        async fn create_database(
+            &self,
+            _ctx: Context,
+            database_name: &str,
+            _options: CreateDatabaseOptions,
+        ) -> Result<CreateDatabaseResponse, crate::Error> {
+            self.databases
+                .lock()
+                .unwrap()
+                .push(database_name.to_owned());
+            Ok(CreateDatabaseResponse {
+                database: Some(database(database_name)),
+                system_properties: None,
+                charge: 1.0,
+                etag: "\"00000000-0000-0000-0000-000000000000\"".to_owned(),
+                session_token: "0:-1#1".to_owned(),
+                last_state_change: chrono::Utc::now(),
+                resource_quota: Vec::new(),
+                resource_usage: Vec::new(),
+                quorum_acked_lsn: 1,
+                current_write_quorum: 1,
+                current_replica_set_size: 1,
+                schema_version: "1.10".to_owned(),
+                service_version: "version=2.11.0.0".to_owned(),
+                activity_id: uuid::Uuid::nil(),
+                gateway_version: "version=2.11.0".to_owned(),
+                client_request_id: None,
+                ms_headers: CosmosHeaders::default(),
+            })
+        }
+
+        async fn list_databases(&self) -> Result<Vec<Database>, crate::Error> {
+            Ok(self
+                .databases
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|name| database(name))
+                .collect())
+        }
+
+        async fn delete_database(
+            &self,
+            database_name: &str,
+        ) -> Result<DeleteDatabaseResponse, crate::Error> {
+            let mut databases = self.databases.lock().unwrap();
+            let count = databases.len();
+            databases.retain(|name| name != database_name);
+            if databases.len() == count {
+                return Err(crate::Error::NotFound {
+                    body: database_name.to_owned(),
+                });
+            }
+            Ok(DeleteDatabaseResponse {
+                charge: 1.0,
+                activity_id: uuid::Uuid::nil(),
+                session_token: "0:-1#2".to_owned(),
+                resource_quota: Vec::new(),
+                resource_usage: Vec::new(),
+            })
+        }
+    }
+
+    /// Code under test, written against the trait
+    async fn recreate_database(
+        client: &impl CosmosDatabaseApi,
+        name: &str,
+    ) -> Result<(), crate::Error> {
+        if client
+            .list_databases()
+            .await?
+            .iter()
+            .any(|database| database.id == name)
+        {
+            client.delete_database(name).await?;
+        }
+        client
+            .create_database(Context::new(), name, CreateDatabaseOptions::new())
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn code_written_against_the_trait_runs_on_a_fake() {
+        let account = FakeAccount::default();
+
+        recreate_database(&account, "first").await.unwrap();
+        recreate_database(&account, "second").await.unwrap();
+        recreate_database(&account, "first").await.unwrap();
+
+        assert_eq!(*account.databases.lock().unwrap(), ["second", "first"]);
+        assert!(matches!(
+            account.delete_database("third").await,
+            Err(crate::Error::NotFound { .. })
+        ));
+    }
+}
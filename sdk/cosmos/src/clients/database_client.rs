@@ -1,10 +1,14 @@
 use super::*;
+use crate::operation_name::name_operation;
 use crate::operations::*;
-use crate::resources::ResourceType;
-use crate::{requests, ReadonlyString};
+use crate::resources::collection::PartitionKeyDefinition;
+use crate::resources::offer::Offer;
+use crate::resources::{validate_resource_name, ResourceType};
+use crate::{requests, ChargedStream, ReadonlyString, TotalCharge};
 
 use azure_core::pipeline::Pipeline;
 use azure_core::{Context, HttpClient};
+use futures::stream::{self, try_unfold, Stream, StreamExt, TryStreamExt};
 
 /// A client for Cosmos database resources.
 #[derive(Debug, Clone)]
@@ -40,6 +44,7 @@ impl DatabaseClient {
         mut ctx: Context,
         options: GetDatabaseOptions,
     ) -> Result<GetDatabaseResponse, crate::Error> {
+        name_operation(&mut ctx, "get_database");
         let mut request = self
             .prepare_request_with_database_name(http::Method::GET)
             .body(bytes::Bytes::new())
@@ -66,19 +71,25 @@ impl DatabaseClient {
         requests::DeleteDatabaseBuilder::new(self)
     }
 
-    /// Create a collection
-    pub async fn create_collection<S: AsRef<str>>(
+    /// Create a collection partitioned by `partition_key`, such as `"/id"` or a
+    /// [`PartitionKeyDefinition::hierarchical`] key
+    pub async fn create_collection<S: AsRef<str>, P: Into<PartitionKeyDefinition>>(
         &self,
         mut ctx: Context,
         collection_name: S,
+        partition_key: P,
         options: CreateCollectionOptions,
     ) -> Result<CreateCollectionResponse, crate::Error> {
+        name_operation(&mut ctx, "create_collection");
+        validate_resource_name(collection_name.as_ref())?;
+        let partition_key = partition_key.into();
+        partition_key.validate()?;
         let mut request = self.cosmos_client().prepare_request2(
             &format!("dbs/{}/colls", self.database_name()),
             http::Method::POST,
             ResourceType::Collections,
         );
-        options.decorate_request(&mut request, collection_name.as_ref())?;
+        options.decorate_request(&mut request, collection_name.as_ref(), &partition_key)?;
         let response = self
             .pipeline()
             .send(&mut ctx, &mut request)
@@ -89,9 +100,147 @@ impl DatabaseClient {
         Ok(CreateCollectionResponse::try_from(response).await?)
     }
 
-    /// List users
-    pub fn list_users(&self) -> requests::ListUsersBuilder<'_, '_> {
-        requests::ListUsersBuilder::new(self)
+    /// Create the user `user_name`, to grant permissions to
+    pub async fn create_user<S: Into<ReadonlyString>>(
+        &self,
+        ctx: Context,
+        user_name: S,
+        options: CreateUserOptions,
+    ) -> Result<CreateUserResponse, crate::Error> {
+        self.user_client(user_name).create_user(ctx, options).await
+    }
+
+    /// Delete the user `user_name`, along with its permissions
+    pub async fn delete_user<S: Into<ReadonlyString>>(
+        &self,
+        ctx: Context,
+        user_name: S,
+        options: DeleteUserOptions,
+    ) -> Result<DeleteUserResponse, crate::Error> {
+        self.user_client(user_name).delete_user(ctx, options).await
+    }
+
+    /// List the users of the database, one page after the other, following the continuation
+    /// tokens
+    ///
+    /// The stream ends after the first error, or after the `max_pages` of the options: the
+    /// continuation token of its last page then resumes the listing, as the `continuation` of the
+    /// options.
+    pub fn list_users(
+        &self,
+        mut ctx: Context,
+        options: ListUsersOptions,
+    ) -> impl Stream<Item = Result<ListUsersResponse, crate::Error>> + Unpin + '_ {
+        enum States {
+            Init,
+            Continuation(String),
+            Done,
+        }
+
+        let max_pages = options.page_limit();
+        name_operation(&mut ctx, "list_users");
+        let stream = try_unfold(States::Init, move |state| {
+            let mut ctx = ctx.clone();
+            let options = options.clone();
+            async move {
+                let continuation = match state {
+                    States::Init => None,
+                    States::Continuation(continuation) => Some(continuation),
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    &format!("dbs/{}/users", self.database_name()),
+                    http::Method::GET,
+                    ResourceType::Users,
+                );
+                options.decorate_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                let page = ListUsersResponse::try_from(response).await?;
+
+                let next = match &page.continuation_token {
+                    Some(continuation) => States::Continuation(continuation.clone()),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((page, next)))
+            }
+        });
+        Box::pin(stream.take(max_pages))
+    }
+
+    /// List the offers of the database and of its collections, following the continuation tokens
+    ///
+    /// Offers belong to the account: the database is read for its `_self` link, then the offers
+    /// of the account are listed and the ones provisioning the database or one of its
+    /// collections, whose `resource` links start with the one of the database, are yielded with
+    /// their throughput. The stream ends after the first error. Its
+    /// [`total_charge`](ChargedStream::total_charge) sums the request charges of reading the
+    /// database and of the pages.
+    pub fn list_offers(
+        &self,
+        mut ctx: Context,
+    ) -> ChargedStream<impl Stream<Item = Result<Offer, crate::Error>> + Unpin + '_> {
+        enum States {
+            Init(Context),
+            Continuation(Context, String, String),
+            Done,
+        }
+
+        name_operation(&mut ctx, "list_offers");
+        let total_charge = TotalCharge::default();
+        let page_charges = total_charge.clone();
+        let stream = try_unfold(States::Init(ctx), move |state| {
+            let page_charges = page_charges.clone();
+            async move {
+                let (mut ctx, database_link, continuation) = match state {
+                    States::Init(ctx) => {
+                        let response = self
+                            .get_database(ctx.clone(), GetDatabaseOptions::new())
+                            .await?;
+                        page_charges.add(response.charge);
+                        (ctx, response.database._self, None)
+                    }
+                    States::Continuation(ctx, database_link, continuation) => {
+                        (ctx, database_link, Some(continuation))
+                    }
+                    States::Done => return Ok(None),
+                };
+
+                let mut request = self.cosmos_client().prepare_request2(
+                    "offers",
+                    http::Method::GET,
+                    ResourceType::Offers,
+                );
+                decorate_list_offers_request(&mut request, continuation.as_deref())?;
+                let response = self
+                    .pipeline()
+                    .send(&mut ctx, &mut request)
+                    .await?
+                    .validate(http::StatusCode::OK)
+                    .await?;
+                page_charges.add_page(response.headers())?;
+                let page = OffersPage::try_from(response).await?;
+
+                let offers = page
+                    .offers
+                    .into_iter()
+                    .filter(|offer| offer.resource.starts_with(&database_link))
+                    .collect::<Vec<_>>();
+                let next = match page.continuation_token {
+                    Some(continuation) => States::Continuation(ctx, database_link, continuation),
+                    None => States::Done,
+                };
+                Ok::<_, crate::Error>(Some((offers, next)))
+            }
+        })
+        .map_ok(|offers| stream::iter(offers.into_iter().map(Ok)))
+        .try_flatten();
+        ChargedStream::new(Box::pin(stream), total_charge)
     }
 
     /// Convert into a [`CollectionClient`]
@@ -102,11 +251,24 @@ impl DatabaseClient {
         CollectionClient::new(self, collection_name)
     }
 
+    /// Get a [`CollectionClient`] for a collection of this database, keeping this client
+    pub fn collection_client<S: Into<ReadonlyString>>(
+        &self,
+        collection_name: S,
+    ) -> CollectionClient {
+        CollectionClient::new(self.clone(), collection_name)
+    }
+
     /// Convert into a [`UserClient`]
     pub fn into_user_client<S: Into<ReadonlyString>>(self, user_name: S) -> UserClient {
         UserClient::new(self, user_name)
     }
 
+    /// Get a [`UserClient`] for a user of this database, keeping this client
+    pub fn user_client<S: Into<ReadonlyString>>(&self, user_name: S) -> UserClient {
+        UserClient::new(self.clone(), user_name)
+    }
+
     pub(crate) fn prepare_request_with_database_name(
         &self,
         method: http::Method,
@@ -126,3 +288,473 @@ impl DatabaseClient {
         self.cosmos_client.pipeline()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::cosmos_client::EMULATOR_ACCOUNT_KEY;
+    use crate::mock_transport::{MockResponse, MockTransport};
+    use crate::resources::collection::*;
+    use crate::resources::permission::AuthorizationToken;
+    use azure_core::Body;
+    use std::sync::Arc;
+
+    /// The body of the request `create_collection` sends for `partition_key` and `options`.
+    async fn create_collection_body<P: Into<PartitionKeyDefinition>>(
+        partition_key: P,
+        options: CreateCollectionOptions,
+    ) -> serde_json::Value {
+        // the response does not matter, only the request is checked
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::CONFLICT,
+            "{}",
+        )]));
+        let _ = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db")
+        .create_collection(Context::new(), "coll", partition_key, options)
+        .await;
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/colls");
+        match requests[0].body() {
+            Body::Bytes(body) => serde_json::from_slice(body).unwrap(),
+            _ => panic!("the collection should be sent as bytes"),
+        }
+    }
+
+    #[test]
+    fn collection_clients_carry_the_path_of_their_collection() {
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(Arc::new(MockTransport::default())),
+        )
+        .into_database_client("db");
+
+        let borrowed = database_client.collection_client("coll");
+        assert_eq!(borrowed.path(), "dbs/db/colls/coll");
+        assert_eq!(borrowed.database_client().database_name(), "db");
+        assert_eq!(borrowed.collection_name(), "coll");
+
+        let owned = database_client.into_collection_client("other");
+        assert_eq!(owned.path(), "dbs/db/colls/other");
+    }
+
+    #[tokio::test]
+    async fn create_collection_with_a_single_path() {
+        assert_eq!(
+            create_collection_body("/id", CreateCollectionOptions::new()).await,
+            serde_json::json!({
+                "id": "coll",
+                "partitionKey": { "paths": ["/id"], "kind": "Hash", "version": 1 },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_collection_with_a_hierarchical_partition_key() {
+        assert_eq!(
+            create_collection_body(
+                PartitionKeyDefinition::hierarchical(vec!["/tenantId", "/userId"]),
+                CreateCollectionOptions::new()
+            )
+            .await,
+            serde_json::json!({
+                "id": "coll",
+                "partitionKey": {
+                    "paths": ["/tenantId", "/userId"],
+                    "kind": "MultiHash",
+                    "version": 2,
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_collection_with_an_indexing_policy() {
+        let indexing_policy = IndexingPolicy {
+            automatic: true,
+            indexing_mode: IndexingMode::Consistent,
+            included_paths: vec![IncludedPath {
+                path: "/*".to_owned(),
+                indexes: None,
+            }],
+            excluded_paths: vec!["/\"_etag\"/?".to_owned().into()],
+            composite_indexes: vec![vec![
+                CompositePath {
+                    path: "/name".to_owned(),
+                    order: CompositePathOrder::Ascending,
+                },
+                CompositePath {
+                    path: "/age".to_owned(),
+                    order: CompositePathOrder::Descending,
+                },
+            ]],
+        };
+
+        let body = create_collection_body(
+            "/id",
+            CreateCollectionOptions::new().indexing_policy(indexing_policy.clone()),
+        )
+        .await;
+        assert_eq!(
+            body["indexingPolicy"],
+            serde_json::json!({
+                "automatic": true,
+                "indexingMode": "consistent",
+                "includedPaths": [{ "path": "/*" }],
+                "excludedPaths": [{ "path": "/\"_etag\"/?" }],
+                "compositeIndexes": [[
+                    { "path": "/name", "order": "ascending" },
+                    { "path": "/age", "order": "descending" },
+                ]],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<IndexingPolicy>(body["indexingPolicy"].clone()).unwrap(),
+            indexing_policy
+        );
+    }
+
+    #[tokio::test]
+    async fn create_collection_with_a_default_ttl() {
+        for (default_ttl, expected) in &[
+            (Some(-1), Some(serde_json::json!(-1))),
+            (Some(3600), Some(serde_json::json!(3600))),
+            (None, None),
+        ] {
+            let body = create_collection_body(
+                "/id",
+                CreateCollectionOptions::new().default_ttl(*default_ttl),
+            )
+            .await;
+            assert_eq!(body.get("defaultTtl"), expected.as_ref());
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_default_ttls_are_not_sent() {
+        let transport = Arc::new(MockTransport::default());
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        for ttl in &[0, -2] {
+            let result = database_client
+                .create_collection(
+                    Context::new(),
+                    "coll",
+                    "/id",
+                    CreateCollectionOptions::new().default_ttl(*ttl),
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(crate::Error::InvalidDefaultTtl { ttl: rejected }) if rejected == *ttl
+            ));
+        }
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_partition_key_definitions_are_not_sent() {
+        let transport = Arc::new(MockTransport::default());
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        for partition_key in &[
+            PartitionKeyDefinition::hierarchical(Vec::<String>::new()),
+            PartitionKeyDefinition::from("id"),
+        ] {
+            let result = database_client
+                .create_collection(
+                    Context::new(),
+                    "coll",
+                    partition_key.clone(),
+                    CreateCollectionOptions::new(),
+                )
+                .await;
+            assert!(matches!(
+                result,
+                Err(crate::Error::InvalidPartitionKeyDefinition { .. })
+            ));
+        }
+        assert!(transport.requests().is_empty());
+    }
+
+    fn offer(id: &str, resource: &str, content: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "resource": resource,
+            "offerType": "Invalid",
+            "offerResourceId": "JHwXAKVFRU4=",
+            "offerVersion": "V2",
+            "content": content,
+            "id": id,
+            "_rid": id,
+            "_self": format!("offers/{}/", id),
+            "_etag": "\"00000200-0000-0000-0000-5e1f9d200000\"",
+            "_ts": 1579130144,
+        })
+    }
+
+    #[tokio::test]
+    async fn list_offers_yields_the_offers_of_the_database() {
+        use azure_core::headers::CONTINUATION;
+        use futures::TryStreamExt;
+
+        let offers = |offers: Vec<serde_json::Value>| {
+            let body = serde_json::json!({
+                "_rid": "",
+                "Offers": offers,
+                "_count": offers.len(),
+            });
+            MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            MockResponse::database(http::StatusCode::OK, "db"),
+            offers(vec![
+                offer(
+                    "q7Pk",
+                    "dbs/JHwXAA==/colls/JHwXAKVFRU4=/",
+                    serde_json::json!({ "offerThroughput": 400 }),
+                ),
+                offer(
+                    "zb9S",
+                    "dbs/Xz0AAA==/colls/Xz0AAJ2tXQE=/",
+                    serde_json::json!({ "offerThroughput": 1000 }),
+                ),
+            ])
+            .header(CONTINUATION, "page-2"),
+            offers(vec![offer(
+                "T0pm",
+                "dbs/JHwXAA==/",
+                serde_json::json!({
+                    "offerThroughput": 400,
+                    "offerAutopilotSettings": { "maxThroughput": 4000 },
+                }),
+            )]),
+        ]));
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        let offers: Vec<crate::resources::offer::Offer> = database_client
+            .list_offers(Context::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            offers
+                .iter()
+                .map(|offer| (offer.id.as_str(), offer.resource.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("q7Pk", "dbs/JHwXAA==/colls/JHwXAKVFRU4=/"),
+                ("T0pm", "dbs/JHwXAA==/")
+            ]
+        );
+        assert_eq!(offers[0].content.offer_throughput, Some(400));
+        assert!(!offers[0].is_autoscale());
+        assert_eq!(
+            offers[1]
+                .content
+                .autoscale_settings
+                .as_ref()
+                .map(|settings| settings.max_throughput),
+            Some(4000)
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].uri().path(), "/dbs/db");
+        for request in &requests[1..] {
+            assert_eq!(request.method(), http::Method::GET);
+            assert_eq!(request.uri().path(), "/offers");
+        }
+        assert!(requests[1].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[2].headers().get(CONTINUATION),
+            Some(&http::HeaderValue::from_static("page-2"))
+        );
+    }
+
+    fn user(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "_rid": "JHwXAF5LAAA=",
+            "_self": "dbs/JHwXAA==/users/JHwXAF5LAAA=/",
+            "_etag": "\"00001a01-0000-0d00-0000-5e1f9d200000\"",
+            "_permissions": "permissions/",
+            "_ts": 1579130144,
+        })
+    }
+
+    #[tokio::test]
+    async fn create_user_posts_its_id() {
+        let transport = Arc::new(MockTransport::new(vec![MockResponse::new(
+            http::StatusCode::CREATED,
+            serde_json::to_vec(&user("someone@cool.net")).unwrap(),
+        )
+        .header("etag", "\"00001a01-0000-0d00-0000-5e1f9d200000\"")
+        .header("x-ms-session-token", "0:-1#3")
+        .header("x-ms-request-charge", "4.95")
+        .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")]));
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        let response = database_client
+            .create_user(Context::new(), "someone@cool.net", CreateUserOptions::new())
+            .await
+            .unwrap();
+        assert_eq!(response.user.id, "someone@cool.net");
+        assert_eq!(response.user._self, "dbs/JHwXAA==/users/JHwXAF5LAAA=/");
+        assert_eq!(response.user.rid, "JHwXAF5LAAA=");
+        assert_eq!(response.charge, 4.95);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri().path(), "/dbs/db/users");
+        match requests[0].body() {
+            Body::Bytes(body) => assert_eq!(
+                serde_json::from_slice::<serde_json::Value>(body).unwrap(),
+                serde_json::json!({ "id": "someone@cool.net" })
+            ),
+            _ => panic!("the user should be sent as bytes"),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_users_follows_continuation_tokens() {
+        use azure_core::headers::{CONTINUATION, MAX_ITEM_COUNT};
+        use futures::TryStreamExt;
+
+        let users = |ids: &[&str]| {
+            let users = ids.iter().map(|id| user(id)).collect::<Vec<_>>();
+            let body = serde_json::json!({
+                "_rid": "JHwXAA==",
+                "Users": users,
+                "_count": users.len(),
+            });
+            MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+                .header("x-ms-session-token", "0:-1#3")
+                .header("x-ms-request-charge", "1")
+                .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            users(&["alice", "bob"]).header(CONTINUATION, "page-2"),
+            users(&["carol"]),
+        ]));
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        let pages: Vec<ListUsersResponse> = database_client
+            .list_users(Context::new(), ListUsersOptions::new().max_item_count(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.users.iter().map(|user| user.id.as_str()).collect())
+                .collect::<Vec<Vec<_>>>(),
+            [vec!["alice", "bob"], vec!["carol"]]
+        );
+        assert_eq!(pages[0].continuation_token.as_deref(), Some("page-2"));
+        assert_eq!(pages[1].continuation_token, None);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.method(), http::Method::GET);
+            assert_eq!(request.uri().path(), "/dbs/db/users");
+            assert_eq!(
+                request.headers().get(MAX_ITEM_COUNT),
+                Some(&http::HeaderValue::from(2))
+            );
+        }
+        assert!(requests[0].headers().get(CONTINUATION).is_none());
+        assert_eq!(
+            requests[1].headers().get(CONTINUATION),
+            Some(&http::HeaderValue::from_static("page-2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn list_users_stops_after_max_pages() {
+        use azure_core::headers::CONTINUATION;
+        use futures::TryStreamExt;
+
+        let users = |id: &str| {
+            let body = serde_json::json!({
+                "_rid": "JHwXAA==",
+                "Users": [user(id)],
+                "_count": 1,
+            });
+            MockResponse::new(http::StatusCode::OK, serde_json::to_vec(&body).unwrap())
+                .header("x-ms-session-token", "0:-1#3")
+                .header("x-ms-request-charge", "1")
+                .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+        };
+        let transport = Arc::new(MockTransport::new(vec![
+            users("alice").header(CONTINUATION, "page-2"),
+            users("bob").header(CONTINUATION, "page-3"),
+            users("carol"),
+        ]));
+        let database_client = CosmosClient::new(
+            "account".to_owned(),
+            AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap(),
+            CosmosOptions::with_client(transport.clone()),
+        )
+        .into_database_client("db");
+
+        let pages: Vec<ListUsersResponse> = database_client
+            .list_users(Context::new(), ListUsersOptions::new().max_pages(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(transport.requests().len(), 2);
+        let continuation = pages[1].continuation_token.clone().unwrap();
+        assert_eq!(continuation, "page-3");
+
+        let pages: Vec<ListUsersResponse> = database_client
+            .list_users(
+                Context::new(),
+                ListUsersOptions::new().continuation(continuation),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].users[0].id, "carol");
+        assert_eq!(
+            transport.requests()[2].headers().get(CONTINUATION),
+            Some(&http::HeaderValue::from_static("page-3"))
+        );
+    }
+}
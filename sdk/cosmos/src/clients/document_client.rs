@@ -55,9 +55,20 @@ impl DocumentClient {
         &self.partition_key_serialized
     }
 
-    /// replace a document in a collection
-    pub fn replace_document<'a>(&'a self) -> requests::ReplaceDocumentBuilder<'a, '_> {
-        requests::ReplaceDocumentBuilder::new(self)
+    /// Adds the partition key header, unless the collection is
+    /// [non-partitioned](CollectionClient::non_partitioned)
+    pub(crate) fn add_partition_key_header(
+        &self,
+        builder: http::request::Builder,
+    ) -> http::request::Builder {
+        if self.collection_client.is_partitioned() {
+            crate::cosmos_entity::add_as_partition_key_header_serialized(
+                &self.partition_key_serialized,
+                builder,
+            )
+        } else {
+            builder
+        }
     }
 
     /// Get a document
@@ -65,11 +76,6 @@ impl DocumentClient {
         requests::GetDocumentBuilder::new(self)
     }
 
-    /// Delete a document
-    pub fn delete_document(&self) -> requests::DeleteDocumentBuilder<'_> {
-        requests::DeleteDocumentBuilder::new(self)
-    }
-
     /// List all attachments for a document
     pub fn list_attachments(&self) -> requests::ListAttachmentsBuilder<'_, '_> {
         requests::ListAttachmentsBuilder::new(self)
@@ -89,9 +95,8 @@ impl DocumentClient {
     ) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/docs/{}",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/docs/{}",
+                self.collection_client().path(),
                 self.document_name()
             ),
             method,
@@ -9,7 +9,8 @@
 //! use azure_cosmos::prelude::*;
 //!
 //! let account = todo!("Get Cosmos account name from the Azure Portal");
-//! let authorization_token = todo!("Get Cosmos authorization token from the Azure Portal");
+//! let authorization_token: AuthorizationToken =
+//!     todo!("Get Cosmos authorization token from the Azure Portal");
 //! let database_name: String = todo!("Think of some database name");
 //!
 //! // Create an http client, then a `CosmosClient`, and then a `DatabaseClient`
@@ -20,6 +21,7 @@
 mod attachment_client;
 mod collection_client;
 mod cosmos_client;
+mod cosmos_database_api;
 mod database_client;
 mod document_client;
 mod permission_client;
@@ -30,7 +32,11 @@ mod user_defined_function_client;
 
 pub use attachment_client::AttachmentClient;
 pub use collection_client::CollectionClient;
-pub use cosmos_client::{CosmosClient, CosmosOptions};
+pub(crate) use cosmos_client::sign_request_at;
+pub use cosmos_client::{
+    canonical_resource_link, verify_authorization, CosmosClient, CosmosOptions,
+};
+pub use cosmos_database_api::CosmosDatabaseApi;
 pub use database_client::DatabaseClient;
 pub use document_client::DocumentClient;
 pub use permission_client::PermissionClient;
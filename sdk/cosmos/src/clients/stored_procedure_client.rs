@@ -1,7 +1,10 @@
 use super::*;
+use crate::operation_name::name_operation;
+use crate::operations::*;
 use crate::resources::ResourceType;
 use crate::{requests, ReadonlyString};
-use azure_core::HttpClient;
+use azure_core::pipeline::Pipeline;
+use azure_core::{Context, HttpClient};
 
 /// A client for Cosmos stored procedure resources.
 #[derive(Debug, Clone)]
@@ -41,9 +44,28 @@ impl StoredProcedureClient {
         &self.stored_procedure_name
     }
 
-    /// Create the stored procedure
-    pub fn create_stored_procedure(&self) -> requests::CreateStoredProcedureBuilder<'_, '_> {
-        requests::CreateStoredProcedureBuilder::new(self)
+    /// Create the stored procedure with the JavaScript `body`
+    pub async fn create_stored_procedure<B: AsRef<str>>(
+        &self,
+        mut ctx: Context,
+        body: B,
+        options: CreateStoredProcedureOptions,
+    ) -> Result<CreateStoredProcedureResponse, crate::Error> {
+        name_operation(&mut ctx, "create_stored_procedure");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/sprocs", self.collection_client().path()),
+            http::Method::POST,
+            ResourceType::StoredProcedures,
+        );
+        options.decorate_request(&mut request, self.stored_procedure_name(), body.as_ref())?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::CREATED)
+            .await?;
+
+        CreateStoredProcedureResponse::try_from(response).await
     }
 
     /// Replace the stored procedure
@@ -57,20 +79,30 @@ impl StoredProcedureClient {
     }
 
     /// Delete the stored procedure
-    pub fn delete_stored_procedure(&self) -> requests::DeleteStoredProcedureBuilder<'_, '_> {
-        requests::DeleteStoredProcedureBuilder::new(self)
-    }
-
-    pub(crate) fn prepare_request(&self, method: http::Method) -> http::request::Builder {
-        self.cosmos_client().prepare_request(
+    pub async fn delete_stored_procedure(
+        &self,
+        mut ctx: Context,
+        options: DeleteStoredProcedureOptions,
+    ) -> Result<DeleteStoredProcedureResponse, crate::Error> {
+        name_operation(&mut ctx, "delete_stored_procedure");
+        let mut request = self.cosmos_client().prepare_request2(
             &format!(
-                "dbs/{}/colls/{}/sprocs",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/sprocs/{}",
+                self.collection_client().path(),
+                self.stored_procedure_name()
             ),
-            method,
+            http::Method::DELETE,
             ResourceType::StoredProcedures,
-        )
+        );
+        options.decorate_request(&mut request)?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::NO_CONTENT)
+            .await?;
+
+        DeleteStoredProcedureResponse::try_from(response).await
     }
 
     pub(crate) fn prepare_request_with_stored_procedure_name(
@@ -79,9 +111,8 @@ impl StoredProcedureClient {
     ) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/sprocs/{}",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/sprocs/{}",
+                self.collection_client().path(),
                 self.stored_procedure_name()
             ),
             method,
@@ -92,4 +123,8 @@ impl StoredProcedureClient {
     pub(crate) fn http_client(&self) -> &dyn HttpClient {
         self.cosmos_client().http_client()
     }
+
+    fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
+    }
 }
@@ -1,7 +1,11 @@
 use super::*;
+use crate::operation_name::name_operation;
+use crate::operations::*;
+use crate::resources::trigger::{TriggerOperation, TriggerType};
 use crate::resources::ResourceType;
 use crate::{requests, ReadonlyString};
-use azure_core::HttpClient;
+use azure_core::pipeline::Pipeline;
+use azure_core::{Context, HttpClient};
 
 /// A client for Cosmos trigger resources.
 #[derive(Debug, Clone)]
@@ -42,19 +46,68 @@ impl TriggerClient {
         &self.trigger_name
     }
 
-    /// Create a trigger
-    pub fn create_trigger(&self) -> requests::CreateOrReplaceTriggerBuilder<'_> {
-        requests::CreateOrReplaceTriggerBuilder::new(self, true)
+    /// Create the trigger, run with `body` before or after the `trigger_operation` on a document
+    pub async fn create_trigger<B: AsRef<str>>(
+        &self,
+        mut ctx: Context,
+        trigger_type: TriggerType,
+        trigger_operation: TriggerOperation,
+        body: B,
+        options: CreateTriggerOptions,
+    ) -> Result<CreateTriggerResponse, crate::Error> {
+        name_operation(&mut ctx, "create_trigger");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/triggers", self.collection_client().path()),
+            http::Method::POST,
+            ResourceType::Triggers,
+        );
+        options.decorate_request(
+            &mut request,
+            self.trigger_name(),
+            trigger_type,
+            trigger_operation,
+            body.as_ref(),
+        )?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::CREATED)
+            .await?;
+
+        CreateTriggerResponse::try_from(response).await
     }
 
-    /// Replace a trigger
-    pub fn replace_trigger(&self) -> requests::CreateOrReplaceTriggerBuilder<'_> {
-        requests::CreateOrReplaceTriggerBuilder::new(self, false)
+    /// Replace the trigger
+    pub fn replace_trigger(&self) -> requests::ReplaceTriggerBuilder<'_> {
+        requests::ReplaceTriggerBuilder::new(self)
     }
 
-    /// Delete a trigger
-    pub fn delete_trigger(&self) -> requests::DeleteTriggerBuilder<'_, '_> {
-        requests::DeleteTriggerBuilder::new(self)
+    /// Delete the trigger
+    pub async fn delete_trigger(
+        &self,
+        mut ctx: Context,
+        options: DeleteTriggerOptions,
+    ) -> Result<DeleteTriggerResponse, crate::Error> {
+        name_operation(&mut ctx, "delete_trigger");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!(
+                "{}/triggers/{}",
+                self.collection_client().path(),
+                self.trigger_name()
+            ),
+            http::Method::DELETE,
+            ResourceType::Triggers,
+        );
+        options.decorate_request(&mut request)?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::NO_CONTENT)
+            .await?;
+
+        DeleteTriggerResponse::try_from(response).await
     }
 
     pub(crate) fn http_client(&self) -> &dyn HttpClient {
@@ -67,9 +120,8 @@ impl TriggerClient {
     ) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/triggers/{}",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/triggers/{}",
+                self.collection_client().path(),
                 self.trigger_name()
             ),
             method,
@@ -77,15 +129,7 @@ impl TriggerClient {
         )
     }
 
-    pub(crate) fn prepare_request(&self, method: http::Method) -> http::request::Builder {
-        self.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/triggers",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
-            ),
-            method,
-            ResourceType::Triggers,
-        )
+    fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
     }
 }
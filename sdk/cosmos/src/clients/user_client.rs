@@ -1,7 +1,10 @@
 use super::*;
+use crate::operation_name::name_operation;
+use crate::operations::*;
 use crate::resources::ResourceType;
 use crate::{requests, ReadonlyString};
-use azure_core::HttpClient;
+use azure_core::pipeline::Pipeline;
+use azure_core::{Context, HttpClient};
 
 /// A client for Cosmos user resources.
 #[derive(Debug, Clone)]
@@ -37,8 +40,26 @@ impl UserClient {
     }
 
     /// Create the user
-    pub fn create_user(&self) -> requests::CreateUserBuilder<'_, '_> {
-        requests::CreateUserBuilder::new(self)
+    pub async fn create_user(
+        &self,
+        mut ctx: Context,
+        options: CreateUserOptions,
+    ) -> Result<CreateUserResponse, crate::Error> {
+        name_operation(&mut ctx, "create_user");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("dbs/{}/users", self.database_client().database_name()),
+            http::Method::POST,
+            ResourceType::Users,
+        );
+        options.decorate_request(&mut request, self.user_name())?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::CREATED)
+            .await?;
+
+        Ok(CreateUserResponse::try_from(response).await?)
     }
 
     /// Get the user
@@ -52,8 +73,30 @@ impl UserClient {
     }
 
     /// Delete the user
-    pub fn delete_user(&self) -> requests::DeleteUserBuilder<'_, '_> {
-        requests::DeleteUserBuilder::new(self)
+    pub async fn delete_user(
+        &self,
+        mut ctx: Context,
+        options: DeleteUserOptions,
+    ) -> Result<DeleteUserResponse, crate::Error> {
+        name_operation(&mut ctx, "delete_user");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!(
+                "dbs/{}/users/{}",
+                self.database_client().database_name(),
+                self.user_name()
+            ),
+            http::Method::DELETE,
+            ResourceType::Users,
+        );
+        options.decorate_request(&mut request)?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::NO_CONTENT)
+            .await?;
+
+        Ok(DeleteUserResponse::try_from(response).await?)
     }
 
     /// List the user's permissions
@@ -69,14 +112,6 @@ impl UserClient {
         PermissionClient::new(self, permission_name)
     }
 
-    pub(crate) fn prepare_request(&self, method: http::Method) -> http::request::Builder {
-        self.cosmos_client().prepare_request(
-            &format!("dbs/{}/users", self.database_client().database_name()),
-            method,
-            ResourceType::Users,
-        )
-    }
-
     pub(crate) fn prepare_request_with_user_name(
         &self,
         method: http::Method,
@@ -95,4 +130,8 @@ impl UserClient {
     pub(crate) fn http_client(&self) -> &dyn HttpClient {
         self.cosmos_client().http_client()
     }
+
+    fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
+    }
 }
@@ -1,7 +1,10 @@
 use super::*;
+use crate::operation_name::name_operation;
+use crate::operations::*;
 use crate::resources::ResourceType;
 use crate::{requests, ReadonlyString};
-use azure_core::HttpClient;
+use azure_core::pipeline::Pipeline;
+use azure_core::{Context, HttpClient};
 
 /// A client for Cosmos user defined function resources.
 #[derive(Debug, Clone)]
@@ -41,37 +44,66 @@ impl UserDefinedFunctionClient {
         &self.user_defined_function_name
     }
 
-    /// Create the user defined function
-    pub fn create_user_defined_function(
+    /// Create the user defined function with the JavaScript `body`
+    pub async fn create_user_defined_function<B: AsRef<str>>(
         &self,
-    ) -> requests::CreateOrReplaceUserDefinedFunctionBuilder<'_, '_> {
-        requests::CreateOrReplaceUserDefinedFunctionBuilder::new(self, true)
+        mut ctx: Context,
+        body: B,
+        options: CreateUserDefinedFunctionOptions,
+    ) -> Result<CreateUserDefinedFunctionResponse, crate::Error> {
+        name_operation(&mut ctx, "create_user_defined_function");
+        let mut request = self.cosmos_client().prepare_request2(
+            &format!("{}/udfs", self.collection_client().path()),
+            http::Method::POST,
+            ResourceType::UserDefinedFunctions,
+        );
+        options.decorate_request(
+            &mut request,
+            self.user_defined_function_name(),
+            body.as_ref(),
+        )?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::CREATED)
+            .await?;
+
+        CreateUserDefinedFunctionResponse::try_from(response).await
     }
 
     /// Replace the user defined function
     pub fn replace_user_defined_function(
         &self,
-    ) -> requests::CreateOrReplaceUserDefinedFunctionBuilder<'_, '_> {
-        requests::CreateOrReplaceUserDefinedFunctionBuilder::new(self, false)
+    ) -> requests::ReplaceUserDefinedFunctionBuilder<'_, '_> {
+        requests::ReplaceUserDefinedFunctionBuilder::new(self)
     }
 
     /// Delete the user defined function
-    pub fn delete_user_defined_function(
+    pub async fn delete_user_defined_function(
         &self,
-    ) -> requests::DeleteUserDefinedFunctionBuilder<'_, '_> {
-        requests::DeleteUserDefinedFunctionBuilder::new(self)
-    }
-
-    pub(crate) fn prepare_request(&self, method: http::Method) -> http::request::Builder {
-        self.cosmos_client().prepare_request(
+        mut ctx: Context,
+        options: DeleteUserDefinedFunctionOptions,
+    ) -> Result<DeleteUserDefinedFunctionResponse, crate::Error> {
+        name_operation(&mut ctx, "delete_user_defined_function");
+        let mut request = self.cosmos_client().prepare_request2(
             &format!(
-                "dbs/{}/colls/{}/udfs",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/udfs/{}",
+                self.collection_client().path(),
+                self.user_defined_function_name()
             ),
-            method,
+            http::Method::DELETE,
             ResourceType::UserDefinedFunctions,
-        )
+        );
+        options.decorate_request(&mut request)?;
+        let response = self
+            .pipeline()
+            .send(&mut ctx, &mut request)
+            .await?
+            .validate(http::StatusCode::NO_CONTENT)
+            .await?;
+
+        DeleteUserDefinedFunctionResponse::try_from(response).await
     }
 
     pub(crate) fn prepare_request_with_user_defined_function_name(
@@ -80,9 +112,8 @@ impl UserDefinedFunctionClient {
     ) -> http::request::Builder {
         self.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/udfs/{}",
-                self.database_client().database_name(),
-                self.collection_client().collection_name(),
+                "{}/udfs/{}",
+                self.collection_client().path(),
                 self.user_defined_function_name()
             ),
             method,
@@ -93,4 +124,8 @@ impl UserDefinedFunctionClient {
     pub(crate) fn http_client(&self) -> &dyn HttpClient {
         self.cosmos_client().http_client()
     }
+
+    fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
+    }
 }
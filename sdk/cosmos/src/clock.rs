@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+
+/// The `chrono` format of the `x-ms-date` header, such as `Thu, 27 Apr 2017 00:51:12 GMT`.
+///
+/// This is the RFC 1123 date Cosmos DB expects, in this exact casing: only the string to sign
+/// lowercases it.
+pub const TIME_FORMAT: &str = "%a, %d %h %Y %T GMT";
+
+/// The source of the time signed in the `x-ms-date` header of every request.
+///
+/// The client uses the [`SystemClock`] unless another one is set with
+/// [`CosmosOptions::clock`](crate::clients::CosmosOptions::clock), which makes the signatures
+/// reproducible in tests.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A fixed time to sign the requests of one operation with, instead of the time of the
+/// [`Clock`].
+///
+/// Inserted in the [`Context`](azure_core::Context) of an operation, it pins the `x-ms-date`
+/// header and the signature of its requests, as replaying recorded requests requires. The
+/// operations without a context, and so the legacy request builders, keep using the clock. The
+/// requests are signed again with the token the client was created with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningTime(pub DateTime<Utc>);
+
+/// The clock of the system, used by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(rfc3339: &str) -> String {
+        let time = DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc);
+        format!("{}", time.format(TIME_FORMAT))
+    }
+
+    #[test]
+    fn times_are_formatted_as_azure_expects() {
+        assert_eq!(
+            format("2017-04-27T00:51:12Z"),
+            "Thu, 27 Apr 2017 00:51:12 GMT"
+        );
+        assert_eq!(
+            format("1900-01-01T01:00:00Z"),
+            "Mon, 01 Jan 1900 01:00:00 GMT"
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::headers;
+use crate::operations::*;
 use crate::responses::*;
 use azure_core::AddAsHeader;
 use http::request;
+use http::HeaderValue;
 use serde::de::DeserializeOwned;
 
 /// The consistency guarantee provided by Cosmos.
@@ -70,19 +72,14 @@ implement_from!(ReplaceReferenceAttachmentResponse);
 implement_from!(CreateReferenceAttachmentResponse);
 implement_from!(ListAttachmentsResponse);
 implement_from!(GetAttachmentResponse);
-implement_from!(CreateDocumentResponse);
-implement_from!(ReplaceDocumentResponse);
+implement_from!(CreateDocumentResponse, T);
+implement_from!(ReplaceDocumentResponse, T);
 implement_from!(DeleteDocumentResponse);
 implement_from!(CreateUserDefinedFunctionResponse);
-implement_from!(DeleteUserDefinedFunctionResponse);
 implement_from!(ListUserDefinedFunctionsResponse);
 implement_from!(CreateTriggerResponse);
 implement_from!(ListTriggersResponse);
-implement_from!(DeleteTriggerResponse);
 implement_from!(ListDocumentsResponse, T);
-implement_from!(QueryDocumentsResponse, T);
-implement_from!(QueryDocumentsResponseRaw, T);
-implement_from!(QueryDocumentsResponseDocuments, T);
 
 impl<T> From<&GetDocumentResponse<T>> for ConsistencyLevel {
     fn from(get_document_response: &GetDocumentResponse<T>) -> Self {
@@ -90,6 +87,9 @@ impl<T> From<&GetDocumentResponse<T>> for ConsistencyLevel {
             GetDocumentResponse::Found(response) => {
                 ConsistencyLevel::Session(response.session_token.clone())
             }
+            GetDocumentResponse::NotModified(response) => {
+                ConsistencyLevel::Session(response.session_token.clone())
+            }
             GetDocumentResponse::NotFound(response) => {
                 ConsistencyLevel::Session(response.session_token.clone())
             }
@@ -121,4 +121,19 @@ impl AddAsHeader for ConsistencyLevel {
             builder
         }
     }
+
+    fn add_as_header2(&self, request: &mut azure_core::Request) -> Result<(), http::Error> {
+        let headers = request.headers_mut();
+        headers.insert(
+            headers::HEADER_CONSISTENCY_LEVEL,
+            HeaderValue::from_static(self.to_consistency_level_header()),
+        );
+        if let ConsistencyLevel::Session(session_token) = self {
+            headers.insert(
+                headers::HEADER_SESSION_TOKEN,
+                HeaderValue::from_str(session_token)?,
+            );
+        }
+        Ok(())
+    }
 }
@@ -18,18 +18,15 @@ pub(crate) fn serialize_partition_key<PK: Serialize>(pk: &PK) -> Result<String,
     serde_json::to_string(&[pk])
 }
 
-// Here we do not implement add_as_header because the trait does not support errors and serializing
-// with serde_json returns a Result. I am not sure why a serialization could fail (memory
-// allocation)? In case we are confident that no errors should arise we can implement the trait and just
-// unwrap the result of serde_json::to_string.
-pub(crate) fn add_as_partition_key_header<'a, P: CosmosEntity<'a>>(
-    pk: &'a P,
-    builder: Builder,
-) -> Result<Builder, serde_json::Error> {
-    Ok(builder.header(
+pub(crate) fn add_as_partition_key_header_serialized2(
+    partition_key_serialized: &str,
+    request: &mut azure_core::Request,
+) -> Result<(), http::Error> {
+    request.headers_mut().insert(
         headers::HEADER_DOCUMENTDB_PARTITIONKEY,
-        &serialize_partition_key(&pk.partition_key())?,
-    ))
+        http::HeaderValue::from_str(partition_key_serialized)?,
+    );
+    Ok(())
 }
 
 pub(crate) fn add_as_partition_key_header_serialized(
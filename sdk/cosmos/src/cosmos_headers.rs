@@ -0,0 +1,146 @@
+use http::HeaderMap;
+use std::time::Duration;
+
+/// The `x-ms-*` headers of a response
+///
+/// Cosmos DB attaches many diagnostic headers to its responses, and adds more over time. The
+/// common ones have typed getters, returning `None` when the header is missing or malformed. The
+/// others can be read with [`raw`](Self::raw).
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+pub struct CosmosHeaders {
+    /// The `x-ms-*` headers, with lowercase names, sorted by name
+    headers: Vec<(String, String)>,
+}
+
+impl CosmosHeaders {
+    /// Keeps the `x-ms-*` headers of `headers` whose value is visible ASCII
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        let mut headers: Vec<(String, String)> = headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with("x-ms-"))
+            .filter_map(|(name, value)| {
+                let value = value.to_str().ok()?;
+                Some((name.as_str().to_owned(), value.to_owned()))
+            })
+            .collect();
+        headers.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self { headers }
+    }
+
+    /// The value of the header `name`, compared case-insensitively
+    ///
+    /// The first value is returned when the header is repeated.
+    pub fn raw(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The headers, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// The request units the operation consumed, from `x-ms-request-charge`
+    pub fn request_charge(&self) -> Option<f64> {
+        self.raw("x-ms-request-charge")?.parse().ok()
+    }
+
+    /// The id the service traces the operation with, from `x-ms-activity-id`
+    pub fn activity_id(&self) -> Option<uuid::Uuid> {
+        self.raw("x-ms-activity-id")?.parse().ok()
+    }
+
+    /// The session token to read the writes of the operation with, from `x-ms-session-token`
+    pub fn session_token(&self) -> Option<&str> {
+        self.raw("x-ms-session-token")
+    }
+
+    /// The token of the next page of a listing, from `x-ms-continuation`
+    pub fn continuation(&self) -> Option<&str> {
+        self.raw("x-ms-continuation")
+    }
+
+    /// How long to wait before retrying a throttled operation, from `x-ms-retry-after-ms`
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.raw("x-ms-retry-after-ms")?
+            .parse()
+            .ok()
+            .map(Duration::from_millis)
+    }
+
+    /// The number of items in the response, from `x-ms-item-count`
+    pub fn item_count(&self) -> Option<u32> {
+        self.raw("x-ms-item-count")?.parse().ok()
+    }
+
+    /// The version of the gateway that served the operation, from `x-ms-gatewayversion`
+    pub fn gateway_version(&self) -> Option<&str> {
+        self.raw("x-ms-gatewayversion")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn typed_and_raw_access() {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &[
+            ("x-ms-request-charge", "6.29"),
+            ("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44"),
+            ("x-ms-session-token", "0:-1#2"),
+            ("x-ms-continuation", "+RID:~JHwXAKVFRU4BAAAAAAAAAA==#RT:1"),
+            ("x-ms-retry-after-ms", "250"),
+            ("x-ms-item-count", "3"),
+            ("x-ms-gatewayversion", "version=2.14.0"),
+            (
+                "x-ms-cosmos-query-execution-info",
+                "{\"reverseRidEnabled\":false}",
+            ),
+            ("etag", "\"0200d9a6-0000-0d00-0000-5e1f9d200000\""),
+        ] {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        let headers = CosmosHeaders::from_headers(&headers);
+
+        assert_eq!(headers.request_charge(), Some(6.29));
+        assert_eq!(
+            headers.activity_id(),
+            Some("0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44".parse().unwrap())
+        );
+        assert_eq!(headers.session_token(), Some("0:-1#2"));
+        assert_eq!(
+            headers.continuation(),
+            Some("+RID:~JHwXAKVFRU4BAAAAAAAAAA==#RT:1")
+        );
+        assert_eq!(headers.retry_after(), Some(Duration::from_millis(250)));
+        assert_eq!(headers.item_count(), Some(3));
+        assert_eq!(headers.gateway_version(), Some("version=2.14.0"));
+
+        assert_eq!(
+            headers.raw("X-MS-Cosmos-Query-Execution-Info"),
+            Some("{\"reverseRidEnabled\":false}")
+        );
+        assert_eq!(headers.raw("x-ms-request-charge"), Some("6.29"));
+        assert_eq!(headers.raw("etag"), None);
+        assert_eq!(headers.iter().count(), 8);
+    }
+
+    #[test]
+    fn missing_and_malformed_headers_are_none() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ms-request-charge", HeaderValue::from_static("a lot"));
+        let headers = CosmosHeaders::from_headers(&headers);
+
+        assert_eq!(headers.request_charge(), None);
+        assert_eq!(headers.raw("x-ms-request-charge"), Some("a lot"));
+        assert_eq!(headers.session_token(), None);
+        assert_eq!(headers.retry_after(), None);
+    }
+}
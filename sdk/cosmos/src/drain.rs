@@ -0,0 +1,92 @@
+use futures::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// The requests of a client, and of its clones, handed to the pipeline and not answered yet.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InFlight {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    count: AtomicUsize,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl InFlight {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.inner.count.load(Ordering::SeqCst)
+    }
+
+    /// Counts a request until the returned guard is dropped.
+    pub(crate) fn start(&self) -> InFlightGuard {
+        self.inner.count.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard {
+            in_flight: self.clone(),
+        }
+    }
+
+    /// A future completing once no request is in flight.
+    pub(crate) fn idle(&self) -> Idle {
+        Idle {
+            in_flight: self.clone(),
+        }
+    }
+}
+
+pub(crate) struct InFlightGuard {
+    in_flight: InFlight,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let inner = &self.in_flight.inner;
+        if inner.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            for waker in inner.wakers.lock().unwrap().drain(..) {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Future returned by [`InFlight::idle`].
+#[derive(Debug)]
+pub(crate) struct Idle {
+    in_flight: InFlight,
+}
+
+impl Future for Idle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.in_flight.count() == 0 {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = self.in_flight.inner.wakers.lock().unwrap();
+        // check again now that the last guard cannot drain the wakers under our feet
+        if self.in_flight.count() == 0 {
+            return Poll::Ready(());
+        }
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+/// [`CosmosClient::drain`](crate::clients::CosmosClient::drain) gave up with requests still in
+/// flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("{in_flight} requests were still in flight when draining timed out")]
+pub struct DrainTimeout {
+    /// The number of requests in flight at the timeout
+    pub in_flight: usize,
+}
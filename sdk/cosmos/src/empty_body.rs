@@ -0,0 +1,13 @@
+/// Whether a POST or PUT request without body says so with a `Content-Length: 0` header.
+///
+/// Cosmos expects the header, and some gateways answer `411 Length Required` without it, so
+/// it is sent by default. Inserted in the [`Context`](azure_core::Context) of an operation,
+/// [`Omit`](Self::Omit) leaves it out for the proxies that reject it instead. The requests
+/// with a body, and the other methods, are left untouched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyBodyContentLength {
+    /// `Content-Length: 0` is set on the empty POST and PUT requests, the default
+    Send,
+    /// No `Content-Length` is set on the empty POST and PUT requests
+    Omit,
+}
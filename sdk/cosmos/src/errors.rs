@@ -5,7 +5,7 @@
 pub enum Error {
     /// An error as defined in the `azure_core` crate
     #[error(transparent)]
-    Core(#[from] azure_core::Error),
+    Core(azure_core::Error),
     /// An error related to parsing
     #[error(transparent)]
     ParsingError(#[from] ParsingError),
@@ -15,6 +15,87 @@ pub enum Error {
     AuthorizationTokenParsing(#[from] crate::resources::permission::AuthorizationTokenParsingError),
     #[error("error parsing permission token: {0}")]
     PermissionTokenParsing(#[from] crate::resources::permission::PermissionTokenParsingError),
+    /// A resource name breaking the Cosmos DB naming rules, rejected before sending the request
+    #[error("invalid resource name: {reason}")]
+    InvalidResourceName { reason: String },
+    /// A partition key definition Cosmos DB would not accept, rejected before sending the request
+    #[error("invalid partition key definition: {reason}")]
+    InvalidPartitionKeyDefinition { reason: String },
+    /// A default time to live that is neither `-1` nor a positive number of seconds
+    #[error("invalid default time to live {ttl}: it must be -1 or a positive number of seconds")]
+    InvalidDefaultTtl { ttl: i32 },
+    /// The environment does not hold enough variables to create a client
+    #[error("missing environment variables: {}", .0.join(", "))]
+    MissingEnvironmentVariables(Vec<String>),
+    #[error("invalid connection string: {0}")]
+    InvalidConnectionString(String),
+    /// The write conflicts with an existing resource, such as a document with the same id
+    #[error("conflict: {body}")]
+    Conflict { body: String },
+    /// The resource does not exist, such as a document deleted or never created
+    ///
+    /// The body is kept as it was received, without parsing it: existence checks only need the
+    /// variant. [`Error::envelope`] parses it when the code and message are wanted.
+    #[error("not found: {body}")]
+    NotFound { body: String },
+    /// The resource changed since the etag of an `If-Match` condition was read
+    #[error("precondition failed: {body}")]
+    PreconditionFailed { body: String },
+    /// TLS settings were changed on options created with a transport of the caller
+    #[error(
+        "the TLS settings of a transport passed to CosmosOptions::with_client cannot be changed"
+    )]
+    TlsOptionsOfCustomTransport,
+    /// The certificate of the emulator at `uri` is not trusted by the transport
+    #[error("the TLS certificate of the emulator at {uri} is not trusted: export it from the emulator and add it to the trusted certificates of the system (https://docs.microsoft.com/azure/cosmos-db/local-emulator-export-ssl-certificates), or pass a transport accepting invalid certificates to CosmosOptions::with_client: {source}")]
+    EmulatorCertificate {
+        uri: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// A blocking client was called from a thread already driving an async runtime
+    #[cfg(feature = "blocking")]
+    #[error("the blocking client cannot be called from within an async runtime, use the async client instead")]
+    BlockingInAsyncContext,
+    /// The runtime of a blocking client could not be started
+    #[cfg(feature = "blocking")]
+    #[error("error starting the runtime of the blocking client: {0}")]
+    BlockingRuntime(std::io::Error),
+}
+
+impl Error {
+    /// Parses the error returned by Cosmos DB for a conflict, a missing resource or a failed
+    /// precondition, or `None` for the other errors and bodies that are not an error envelope.
+    pub fn envelope(&self) -> Option<ErrorEnvelope> {
+        match self {
+            Self::Conflict { body }
+            | Self::NotFound { body }
+            | Self::PreconditionFailed { body } => serde_json::from_str(body).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// The body of the failures of Cosmos DB, such as
+/// `{"code":"NotFound","message":"Entity with the specified id does not exist in the system."}`
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ErrorEnvelope {
+    /// The kind of failure, such as `NotFound` or `Conflict`
+    pub code: String,
+    /// The explanation of the failure
+    pub message: String,
+}
+
+impl From<azure_core::Error> for Error {
+    fn from(error: azure_core::Error) -> Self {
+        match error {
+            // the errors of the policies of this crate come out of the pipeline as they were
+            azure_core::Error::PolicyError(error) => match error.downcast::<Error>() {
+                Ok(error) => *error,
+                Err(error) => Self::Core(azure_core::Error::PolicyError(error)),
+            },
+            error => Self::Core(error),
+        }
+    }
 }
 
 impl From<serde_json::Error> for Error {
@@ -50,6 +131,11 @@ pub enum ParsingError {
     Core(azure_core::ParsingError),
     #[error("Resource quota parsing error: {0}")]
     ParseResourceQuotaError(#[from] crate::resource_quota::ResourceQuotaParsingError),
+    #[error("Query metrics parsing error: {0}")]
+    ParseQueryMetricsError(#[from] crate::query_metrics::QueryMetricsParsingError),
+    /// A progress above 100%
+    #[error("invalid index transformation progress: {0} is not a percentage")]
+    InvalidIndexTransformationProgress(u8),
 }
 
 impl<T: Into<azure_core::ParsingError>> From<T> for ParsingError {
@@ -1,9 +1,10 @@
 use crate::errors::Error;
 use crate::errors::ParsingError;
 use crate::headers::*;
+use crate::query_metrics::QueryMetricsParsingError;
 use crate::resource_quota::resource_quotas_from_str;
 use crate::resources::document::IndexingDirective;
-use crate::ResourceQuota;
+use crate::{QueryMetrics, ResourceQuota};
 use azure_core::headers;
 use azure_core::headers::parse_date_from_str;
 use azure_core::headers::parse_int;
@@ -48,6 +49,51 @@ pub(crate) fn resource_usage_from_headers(
     Ok(resource_quotas_from_str(s).map_err(|e| Error::ParsingError(e.into()))?)
 }
 
+pub(crate) fn resource_quota_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<Vec<ResourceQuota>>, Error> {
+    headers
+        .get(HEADER_RESOURCE_QUOTA)
+        .map(|_| resource_quota_from_headers(headers))
+        .transpose()
+}
+
+pub(crate) fn resource_usage_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<Vec<ResourceQuota>>, Error> {
+    headers
+        .get(HEADER_RESOURCE_USAGE)
+        .map(|_| resource_usage_from_headers(headers))
+        .transpose()
+}
+
+/// The percentage of the documents reindexed after a change of the indexing policy, sent when
+/// the quota info is asked for
+pub(crate) fn index_transformation_progress_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<u8>, Error> {
+    match get_option_from_headers(headers, HEADER_DOCUMENTDB_INDEX_TRANSFORMATION_PROGRESS)? {
+        Some(progress) if progress > 100 => Err(Error::ParsingError(
+            ParsingError::InvalidIndexTransformationProgress(progress),
+        )),
+        progress => Ok(progress),
+    }
+}
+
+/// The server-side metrics of a page of query results, sent when they are asked for
+pub(crate) fn query_metrics_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<QueryMetrics>, Error> {
+    match headers.get(HEADER_DOCUMENTDB_QUERY_METRICS) {
+        Some(_) => Ok(Some(
+            get_str_from_headers(headers, HEADER_DOCUMENTDB_QUERY_METRICS)?
+                .parse()
+                .map_err(|e: QueryMetricsParsingError| Error::ParsingError(e.into()))?,
+        )),
+        None => Ok(None),
+    }
+}
+
 pub(crate) fn quorum_acked_lsn_from_headers(headers: &HeaderMap) -> Result<u64, Error> {
     get_from_headers(headers, HEADER_QUORUM_ACKED_LSN)
 }
@@ -198,3 +244,34 @@ where
 {
     Ok(headers::get_option_from_headers(headers, key)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    fn progress(value: Option<&'static str>) -> Result<Option<u8>, Error> {
+        let mut headers = HeaderMap::new();
+        if let Some(value) = value {
+            headers.insert(
+                HEADER_DOCUMENTDB_INDEX_TRANSFORMATION_PROGRESS,
+                HeaderValue::from_static(value),
+            );
+        }
+        index_transformation_progress_from_headers_optional(&headers)
+    }
+
+    #[test]
+    fn index_transformation_progress() {
+        assert_eq!(progress(Some("42")).unwrap(), Some(42));
+        assert_eq!(progress(Some("100")).unwrap(), Some(100));
+        assert_eq!(progress(None).unwrap(), None);
+        assert!(matches!(
+            progress(Some("101")),
+            Err(Error::ParsingError(
+                ParsingError::InvalidIndexTransformationProgress(101)
+            ))
+        ));
+        assert!(progress(Some("-1")).is_err());
+    }
+}
@@ -21,6 +21,16 @@ pub(crate) const HEADER_DOCUMENTDB_QUERY_ENABLECROSSPARTITION: &str =
     "x-ms-documentdb-query-enablecrosspartition"; // [bool]
 pub(crate) const HEADER_DOCUMENTDB_QUERY_PARALLELIZECROSSPARTITIONQUERY: &str =
     "x-ms-documentdb-query-parallelizecrosspartitionquery"; // [bool]
+pub(crate) const HEADER_DOCUMENTDB_POPULATE_QUOTA_INFO: &str = "x-ms-documentdb-populatequotainfo"; // [bool]
+pub(crate) const HEADER_DOCUMENTDB_POPULATE_QUERY_METRICS: &str =
+    "x-ms-documentdb-populatequerymetrics"; // [bool]
+pub(crate) const HEADER_DOCUMENTDB_QUERY_METRICS: &str = "x-ms-documentdb-query-metrics"; // [QueryMetrics]
+pub(crate) const HEADER_DOCUMENTDB_INDEX_TRANSFORMATION_PROGRESS: &str =
+    "x-ms-documentdb-collection-index-transformation-progress"; // [u8]
+pub(crate) const HEADER_DOCUMENTDB_PRE_TRIGGER_INCLUDE: &str =
+    "x-ms-documentdb-pre-trigger-include"; // [String]
+pub(crate) const HEADER_DOCUMENTDB_POST_TRIGGER_INCLUDE: &str =
+    "x-ms-documentdb-post-trigger-include"; // [String]
 pub(crate) const HEADER_DOCUMENTDB_EXPIRY_SECONDS: &str = "x-ms-documentdb-expiry-seconds"; // [u64]
 pub(crate) const HEADER_CONTENT_PATH: &str = "x-ms-content-path"; // [String]
 pub(crate) const HEADER_ALT_CONTENT_PATH: &str = "x-ms-alt-content-path"; // [String]
@@ -45,3 +55,5 @@ pub(crate) const HEADER_COSMOS_QUORUM_ACKED_LLSN: &str = "x-ms-cosmos-quorum-ack
 pub(crate) const HEADER_ROLE: &str = "x-ms-xp-role"; // [u64]
 pub(crate) const HEADER_MAX_MEDIA_STORAGE_USAGE_MB: &str = "x-ms-max-media-storage-usage-mb"; // [u64]
 pub(crate) const HEADER_MEDIA_STORAGE_USAGE_MB: &str = "x-ms-media-storage-usage-mb"; // [u64]
+pub(crate) const HEADER_COSMOS_IS_BATCH_REQUEST: &str = "x-ms-cosmos-is-batch-request"; // [bool]
+pub(crate) const HEADER_COSMOS_BATCH_ATOMIC: &str = "x-ms-cosmos-batch-atomic"; // [bool]
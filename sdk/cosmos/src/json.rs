@@ -0,0 +1,67 @@
+//! Deserialization of response bodies.
+
+use serde::de::DeserializeOwned;
+
+/// Deserializes a JSON response body.
+///
+/// Response types parse their bodies through this function rather than calling `serde_json`
+/// directly, so the JSON backend can be changed in a single place.
+#[cfg(not(feature = "simd-json"))]
+pub(crate) fn from_json<T: DeserializeOwned>(body: &[u8]) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(body)
+}
+
+/// Deserializes a JSON response body with `simd-json`, enabled by the `simd-json` feature.
+///
+/// `simd-json` parses in place, so the body is copied first. It picks the fastest
+/// implementation the CPU supports at runtime, falling back to a portable one. A body it
+/// rejects is parsed again by `serde_json`, for the error to be the same whichever the backend.
+#[cfg(feature = "simd-json")]
+pub(crate) fn from_json<T: DeserializeOwned>(body: &[u8]) -> Result<T, serde_json::Error> {
+    let mut buffer = body.to_vec();
+    simd_json::serde::from_slice(&mut buffer).or_else(|_| serde_json::from_slice(body))
+}
+
+/// Deserializes a JSON response body the service, or a proxy in front of it, may leave empty.
+pub(crate) fn from_json_optional<T: DeserializeOwned>(
+    body: &[u8],
+) -> Result<Option<T>, serde_json::Error> {
+    if body.iter().all(u8::is_ascii_whitespace) {
+        Ok(None)
+    } else {
+        from_json(body).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::Database;
+
+    #[test]
+    fn bodies_parse_as_with_serde_json() {
+        let body = r#"[
+            {"id":"first","_rid":"JHwXAA==","_self":"dbs/JHwXAA==/","_etag":"\"00000d01-0000-0d00-0000-5e1f9d200000\"","_colls":"colls/","_users":"users/","_ts":1579130144},
+            {"id":"second é","_rid":"JHwXAB==","_self":"dbs/JHwXAB==/","_etag":"\"00000d02-0000-0d00-0000-5e1f9d200000\"","_colls":"colls/","_users":"users/","_ts":1579130145}
+        ]"#
+        .as_bytes();
+
+        let databases: Vec<Database> = from_json(body).unwrap();
+        assert_eq!(
+            databases,
+            serde_json::from_slice::<Vec<Database>>(body).unwrap()
+        );
+        assert_eq!(databases[1].id, "second é");
+    }
+
+    #[test]
+    fn invalid_bodies_fail_as_with_serde_json() {
+        let body = br#"{"id":"truncated""#;
+        assert_eq!(
+            from_json::<Database>(body).unwrap_err().to_string(),
+            serde_json::from_slice::<Database>(body)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -9,6 +9,7 @@ should also be possible with this crate.
 
 ```no_run
 // Using the prelude module of the Cosmos crate makes easier to use the Rust Azure SDK for Cosmos DB.
+use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
@@ -73,11 +74,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
             a_timestamp: chrono::Utc::now().timestamp(),
         };
 
-        // insert it
+        // insert it, overwriting a preexisting document (if any)
         collection_client
-            .create_document()
-            .is_upsert(true) // this option will overwrite a preexisting document (if any)
-            .execute(&document_to_insert)
+            .upsert_document(
+                Context::new(),
+                &document_to_insert,
+                CreateDocumentOptions::new(),
+            )
             .await?;
     }
     // wow that was easy and fast, wasn't it? :)
@@ -98,6 +101,8 @@ extern crate serde;
 #[macro_use]
 extern crate azure_core;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod clients;
 pub mod operations;
 pub mod prelude;
@@ -105,17 +110,35 @@ pub mod requests;
 pub mod resources;
 pub mod responses;
 
+mod clock;
 mod consistency_level;
 mod cosmos_entity;
+mod cosmos_headers;
+mod drain;
+mod empty_body;
 mod errors;
 mod headers;
+mod json;
+#[cfg(test)]
+mod mock_transport;
+mod operation_name;
+mod policies;
+mod query_metrics;
 mod resource_quota;
 mod to_json_vector;
+mod total_charge;
 
+pub use clock::{Clock, SigningTime, SystemClock, TIME_FORMAT};
 pub use consistency_level::ConsistencyLevel;
 pub use cosmos_entity::CosmosEntity;
-pub use resource_quota::ResourceQuota;
-
-pub use errors::Error;
+pub use cosmos_headers::CosmosHeaders;
+pub use drain::DrainTimeout;
+pub use empty_body::EmptyBodyContentLength;
+pub use operation_name::OperationName;
+pub use query_metrics::QueryMetrics;
+pub use resource_quota::{CollectionQuota, ResourceQuota};
+pub use total_charge::{ChargedStream, TotalCharge};
+
+pub use errors::{Error, ErrorEnvelope};
 
 type ReadonlyString = std::borrow::Cow<'static, str>;
@@ -0,0 +1,213 @@
+//! Mock transports used by the unit tests.
+
+use azure_core::{BytesStream, HttpClient, HttpError, ResponseBuilder};
+use bytes::Bytes;
+use http::header::{HeaderName, HeaderValue};
+use http::{HeaderMap, StatusCode};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A transport whose requests never complete.
+///
+/// It keeps track of how many requests have been started and how many are still in flight, so
+/// tests can check that dropping a pending operation releases the transport call.
+#[derive(Debug, Default)]
+pub(crate) struct BlockingTransport {
+    started: AtomicUsize,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl BlockingTransport {
+    pub(crate) fn started(&self) -> usize {
+        self.started.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for BlockingTransport {
+    async fn execute_request(
+        &self,
+        _request: http::Request<Bytes>,
+    ) -> Result<http::Response<Bytes>, HttpError> {
+        Err(HttpError::new_unexpected_status_code(
+            StatusCode::OK,
+            StatusCode::NOT_IMPLEMENTED,
+            "BlockingTransport only supports the pipeline architecture",
+        ))
+    }
+
+    async fn execute_request2(
+        &self,
+        _request: &azure_core::Request,
+    ) -> Result<azure_core::Response, HttpError> {
+        self.started.fetch_add(1, Ordering::SeqCst);
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let _guard = InFlightGuard(self.in_flight.clone());
+        futures::future::pending().await
+    }
+}
+
+/// A canned response returned by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub(crate) struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl MockResponse {
+    pub(crate) fn new(status: StatusCode, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    pub(crate) fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub(crate) fn header(mut self, name: &'static str, value: &str) -> Self {
+        self.headers.insert(
+            HeaderName::from_static(name),
+            HeaderValue::from_str(value).unwrap(),
+        );
+        self
+    }
+
+    /// A response with every header the database operations parse.
+    pub(crate) fn database(status: StatusCode, database_name: &str) -> Self {
+        let body = serde_json::json!({
+            "id": database_name,
+            "_rid": "JHwXAA==",
+            "_self": "dbs/JHwXAA==/",
+            "_etag": "\"00000d01-0000-0d00-0000-5e1f9d200000\"",
+            "_colls": "colls/",
+            "_users": "users/",
+            "_ts": 1579130144,
+        });
+        Self::new(status, serde_json::to_vec(&body).unwrap())
+            .header("etag", "\"00000d01-0000-0d00-0000-5e1f9d200000\"")
+            .header("x-ms-session-token", "0:-1#1")
+            .header("x-ms-request-charge", "1")
+            .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+            .header(
+                "x-ms-last-state-change-utc",
+                "Wed, 15 Jan 2020 23:39:44.369 GMT",
+            )
+            .header("x-ms-resource-quota", "databases=1000;")
+            .header("x-ms-resource-usage", "databases=1;")
+            .header("x-ms-quorum-acked-lsn", "1")
+            .header("x-ms-current-write-quorum", "1")
+            .header("x-ms-current-replica-set-size", "1")
+            .header("x-ms-schemaversion", "1.9")
+            .header("x-ms-serviceversion", "version=2.9.0.0")
+            .header("x-ms-gatewayversion", "version=2.9.0")
+    }
+
+    /// A response with every header the document operations parse, storing `document`.
+    pub(crate) fn document(status: StatusCode, mut document: serde_json::Value) -> Self {
+        document["_rid"] = "JHwXAKVFRU4BAAAAAAAAAA==".into();
+        document["_self"] = "dbs/JHwXAA==/colls/JHwXAKVFRU4=/docs/JHwXAKVFRU4BAAAAAAAAAA==/".into();
+        document["_etag"] = "\"0200d9a6-0000-0d00-0000-5e1f9d200000\"".into();
+        document["_attachments"] = "attachments/".into();
+        document["_ts"] = 1579130144.into();
+        Self::new(status, serde_json::to_vec(&document).unwrap())
+            .header("etag", "\"0200d9a6-0000-0d00-0000-5e1f9d200000\"")
+            .header("content-location", "https://account.documents.azure.com/dbs/db/colls/coll/docs/JHwXAKVFRU4BAAAAAAAAAA==/")
+            .header("date", "Wed, 15 Jan 2020 23:39:44.369 GMT")
+            .header("lsn", "2")
+            .header("x-ms-session-token", "0:-1#2")
+            .header("x-ms-request-charge", "6.29")
+            .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+            .header(
+                "x-ms-last-state-change-utc",
+                "Wed, 15 Jan 2020 23:39:44.369 GMT",
+            )
+            .header("x-ms-resource-quota", "documentSize=10240;")
+            .header("x-ms-resource-usage", "documentSize=0;")
+            .header("x-ms-quorum-acked-lsn", "1")
+            .header("x-ms-current-write-quorum", "1")
+            .header("x-ms-current-replica-set-size", "1")
+            .header("x-ms-global-committed-lsn", "-1")
+            .header("x-ms-number-of-read-regions", "0")
+            .header("x-ms-transport-request-id", "1")
+            .header("x-ms-cosmos-llsn", "2")
+            .header("x-ms-cosmos-quorum-acked-llsn", "1")
+            .header("x-ms-xp-role", "1")
+            .header("x-ms-content-path", "JHwXAKVFRU4=")
+            .header("x-ms-alt-content-path", "dbs/db/colls/coll")
+            .header("x-ms-schemaversion", "1.9")
+            .header("x-ms-serviceversion", "version=2.9.0.0")
+            .header("x-ms-gatewayversion", "version=2.9.0")
+    }
+}
+
+/// A transport that records every request and answers with canned responses, in order.
+#[derive(Debug, Default)]
+pub(crate) struct MockTransport {
+    responses: Mutex<VecDeque<MockResponse>>,
+    requests: Mutex<Vec<azure_core::Request>>,
+}
+
+impl MockTransport {
+    pub(crate) fn new(responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into_iter().collect()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The requests received so far.
+    pub(crate) fn requests(&self) -> Vec<azure_core::Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for MockTransport {
+    async fn execute_request(
+        &self,
+        _request: http::Request<Bytes>,
+    ) -> Result<http::Response<Bytes>, HttpError> {
+        Err(HttpError::new_unexpected_status_code(
+            StatusCode::OK,
+            StatusCode::NOT_IMPLEMENTED,
+            "MockTransport only supports the pipeline architecture",
+        ))
+    }
+
+    async fn execute_request2(
+        &self,
+        request: &azure_core::Request,
+    ) -> Result<azure_core::Response, HttpError> {
+        self.requests.lock().unwrap().push(request.clone());
+        let canned = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("MockTransport ran out of canned responses");
+
+        let mut response = ResponseBuilder::new(canned.status);
+        for (name, value) in canned.headers.iter() {
+            response.with_header(name, value.clone());
+        }
+        Ok(response.with_pinned_stream(Box::pin(BytesStream::new(canned.body))))
+    }
+}
@@ -0,0 +1,37 @@
+use azure_core::Context;
+use std::borrow::Cow;
+use std::fmt;
+
+/// The name of the client operation a request is sent for, such as `create_database`.
+///
+/// The operations of the clients insert their name in their [`Context`], and the requests are
+/// logged with it as their `operation`, which tells apart the operations on the same type of
+/// resource. A name already inserted by the caller is kept instead, to label the requests with
+/// the application operation they are sent for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OperationName(Cow<'static, str>);
+
+impl OperationName {
+    /// An operation named `name`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// The name of the operation.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for OperationName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Names the operation of `ctx`, unless the caller already did.
+pub(crate) fn name_operation(ctx: &mut Context, name: &'static str) {
+    if ctx.get::<OperationName>().is_none() {
+        ctx.insert(OperationName::new(name));
+    }
+}
@@ -1,25 +1,36 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
-use crate::resources::collection::{IndexingPolicy, PartitionKey};
-use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use crate::resources::collection::{IndexingPolicy, PartitionKeyDefinition};
+use crate::CosmosHeaders;
+use azure_core::headers::{
+    client_request_id_from_headers_optional, etag_from_headers, session_token_from_headers,
+};
 use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
 use chrono::{DateTime, Utc};
 
-#[derive(Debug, Clone)]
+/// The options of a collection creation.
+///
+/// Without an `indexing_policy` the collection gets the default policy of Cosmos DB, which
+/// consistently indexes every path.
+///
+/// The `default_ttl` turns on the expiry of documents: `-1` lets them live until they set a `ttl`
+/// of their own, and a positive value expires them that many seconds after their last write. It
+/// is off when `None`.
+#[derive(Debug, Clone, Default)]
 pub struct CreateCollectionOptions {
-    partition_key: PartitionKey,
     consistency_level: Option<ConsistencyLevel>,
     indexing_policy: Option<IndexingPolicy>,
     offer: Option<Offer>,
+    default_ttl: Option<i32>,
 }
 
 impl CreateCollectionOptions {
-    pub fn new<P: Into<PartitionKey>>(partition_key: P) -> Self {
+    pub fn new() -> Self {
         Self {
-            partition_key: partition_key.into(),
             consistency_level: None,
             indexing_policy: None,
             offer: None,
+            default_ttl: None,
         }
     }
 
@@ -27,23 +38,31 @@ impl CreateCollectionOptions {
         consistency_level: ConsistencyLevel => Some(consistency_level),
         indexing_policy: IndexingPolicy => Some(indexing_policy),
         offer: Offer => Some(offer),
+        default_ttl: Option<i32>,
     }
 
     pub(crate) fn decorate_request(
         &self,
         request: &mut HttpRequest,
         collection_name: &str,
+        partition_key: &PartitionKeyDefinition,
     ) -> Result<(), crate::Error> {
-        azure_core::headers::add_optional_header2(&self.offer, request);
-        azure_core::headers::add_optional_header2(&self.consistency_level, request);
+        if let Some(ttl) = self.default_ttl {
+            if ttl == 0 || ttl < -1 {
+                return Err(crate::Error::InvalidDefaultTtl { ttl });
+            }
+        }
+        azure_core::headers::add_optional_header2(&self.offer, request)?;
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
 
         let collection = CreateCollectionBody {
             id: collection_name.as_ref(),
             indexing_policy: &self.indexing_policy,
-            partition_key: &self.partition_key,
+            partition_key,
+            default_ttl: self.default_ttl,
         };
 
-        request.set_body(bytes::Bytes::from(serde_json::to_string(&collection)?).into());
+        super::set_json_body(request, serde_json::to_string(&collection)?);
         Ok(())
     }
 }
@@ -55,12 +74,15 @@ struct CreateCollectionBody<'a> {
     #[serde(rename = "indexingPolicy", skip_serializing_if = "Option::is_none")]
     pub indexing_policy: &'a Option<IndexingPolicy>,
     #[serde(rename = "partitionKey")]
-    pub partition_key: &'a PartitionKey,
+    pub partition_key: &'a PartitionKeyDefinition,
+    #[serde(rename = "defaultTtl", skip_serializing_if = "Option::is_none")]
+    pub default_ttl: Option<i32>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateCollectionResponse {
     pub collection: Collection,
+    pub system_properties: CosmosSystemProperties,
     pub charge: f64,
     pub activity_id: uuid::Uuid,
     pub etag: String,
@@ -73,6 +95,9 @@ pub struct CreateCollectionResponse {
     pub quorum_acked_lsn: u64,
     pub current_write_quorum: u64,
     pub current_replica_set_size: u64,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
 }
 
 impl CreateCollectionResponse {
@@ -81,7 +106,8 @@ impl CreateCollectionResponse {
         let body = collect_pinned_stream(pinned_stream).await?;
 
         Ok(Self {
-            collection: serde_json::from_slice(&body)?,
+            collection: crate::json::from_json(&body)?,
+            system_properties: crate::json::from_json(&body)?,
             charge: request_charge_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
@@ -94,6 +120,8 @@ impl CreateCollectionResponse {
             quorum_acked_lsn: quorum_acked_lsn_from_headers(&headers)?,
             current_write_quorum: current_write_quorum_from_headers(&headers)?,
             current_replica_set_size: current_replica_set_size_from_headers(&headers)?,
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
         })
     }
 }
@@ -1,25 +1,36 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
 use crate::resources::Database;
+use crate::CosmosHeaders;
 use crate::ResourceQuota;
-use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use azure_core::headers::{
+    client_request_id_from_headers_optional, etag_from_headers, session_token_from_headers,
+};
 use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
 pub struct CreateDatabaseOptions {
     consistency_level: Option<ConsistencyLevel>,
+    await_completion: bool,
 }
 
 impl CreateDatabaseOptions {
     pub fn new() -> Self {
         Self {
             consistency_level: None,
+            await_completion: false,
         }
     }
 
     setters! {
         consistency_level: ConsistencyLevel => Some(consistency_level),
+        await_completion: bool => await_completion,
+    }
+
+    /// Whether a `202 Accepted` create is polled until the database is created.
+    pub(crate) fn awaits_completion(&self) -> bool {
+        self.await_completion
     }
 }
 
@@ -35,15 +46,17 @@ impl CreateDatabaseOptions {
         }
         let req = CreateDatabaseRequest { id: database_name };
 
-        azure_core::headers::add_optional_header2(&self.consistency_level, request);
-        request.set_body(bytes::Bytes::from(serde_json::to_string(&req)?).into());
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        super::set_json_body(request, serde_json::to_string(&req)?);
         Ok(())
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct CreateDatabaseResponse {
-    pub database: Database,
+    /// The created database, `None` when the response came back as a `204 No Content`
+    pub database: Option<Database>,
+    pub system_properties: Option<CosmosSystemProperties>,
     pub charge: f64,
     pub etag: String,
     pub session_token: String,
@@ -57,6 +70,9 @@ pub struct CreateDatabaseResponse {
     pub service_version: String,
     pub activity_id: uuid::Uuid,
     pub gateway_version: String,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
 }
 
 impl CreateDatabaseResponse {
@@ -65,7 +81,8 @@ impl CreateDatabaseResponse {
         let body = collect_pinned_stream(pinned_stream).await?;
 
         Ok(Self {
-            database: serde_json::from_slice(&body)?,
+            database: crate::json::from_json_optional(&body)?,
+            system_properties: crate::json::from_json_optional(&body)?,
             charge: request_charge_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
@@ -79,6 +96,8 @@ impl CreateDatabaseResponse {
             service_version: service_version_from_headers(&headers)?.to_owned(),
             activity_id: activity_id_from_headers(&headers)?,
             gateway_version: gateway_version_from_headers(&headers)?.to_owned(),
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
         })
     }
 }
@@ -0,0 +1,162 @@
+use crate::cosmos_entity::{add_as_partition_key_header_serialized2, serialize_partition_key};
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use crate::CosmosHeaders;
+use crate::ResourceQuota;
+use azure_core::headers::{
+    client_request_id_from_headers_optional, etag_from_headers, session_token_from_headers,
+};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use chrono::{DateTime, Utc};
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default)]
+pub struct CreateDocumentOptions {
+    consistency_level: Option<ConsistencyLevel>,
+    indexing_directive: Option<IndexingDirective>,
+    pre_triggers: Vec<String>,
+    post_triggers: Vec<String>,
+}
+
+impl CreateDocumentOptions {
+    pub fn new() -> Self {
+        Self {
+            consistency_level: None,
+            indexing_directive: None,
+            pre_triggers: Vec::new(),
+            post_triggers: Vec::new(),
+        }
+    }
+
+    setters! {
+        consistency_level: ConsistencyLevel => Some(consistency_level),
+        indexing_directive: IndexingDirective => Some(indexing_directive),
+        pre_triggers: Vec<String> => pre_triggers,
+        post_triggers: Vec<String> => post_triggers,
+    }
+
+    pub(crate) fn decorate_request<'a, T>(
+        &self,
+        request: &mut HttpRequest,
+        document: &'a T,
+        is_upsert: IsUpsert,
+    ) -> Result<(), crate::Error>
+    where
+        T: Serialize + CosmosEntity<'a>,
+    {
+        add_as_partition_key_header_serialized2(
+            &serialize_partition_key(&document.partition_key())?,
+            request,
+        )?;
+        azure_core::headers::add_mandatory_header2(&is_upsert, request)?;
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        azure_core::headers::add_optional_header2(&self.indexing_directive, request)?;
+        super::add_trigger_headers(request, &self.pre_triggers, &self.post_triggers)?;
+
+        super::set_json_body(request, serde_json::to_string(document)?);
+        Ok(())
+    }
+}
+
+/// Checks the status of a document write, telling a conflict with an existing document, a missing
+/// document and a failed `If-Match` condition apart from the other failures.
+pub(crate) async fn validate_document_write(
+    response: HttpResponse,
+    expected: &[StatusCode],
+) -> Result<HttpResponse, crate::Error> {
+    let status = response.status();
+    if expected.contains(&status) {
+        return Ok(response);
+    }
+
+    // the body is only read, not parsed: a missing document is common enough in existence
+    // checks, and `Error::envelope` parses it for the callers wanting the message
+    let (_status_code, _headers, pinned_stream) = response.deconstruct();
+    let body = collect_pinned_stream(pinned_stream)
+        .await
+        .unwrap_or_else(|_| bytes::Bytes::from_static(b"<INVALID BODY>"));
+    let body = std::str::from_utf8(&body).unwrap_or("<NON-UTF8 BODY>");
+    let body = body.to_owned();
+    Err(match status {
+        StatusCode::CONFLICT => crate::Error::Conflict { body },
+        StatusCode::NOT_FOUND => crate::Error::NotFound { body },
+        StatusCode::PRECONDITION_FAILED => crate::Error::PreconditionFailed { body },
+        _ => azure_core::HttpError::new_multiple_unexpected_status_code(
+            expected.to_vec(),
+            status,
+            &body,
+        )
+        .into(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateDocumentResponse<T> {
+    pub document: Document<T>,
+    pub is_update: bool,
+    pub last_state_change: DateTime<Utc>,
+    pub etag: String,
+    pub resource_quota: Vec<ResourceQuota>,
+    pub resource_usage: Vec<ResourceQuota>,
+    pub lsn: u64,
+    pub schema_version: String,
+    pub alt_content_path: String,
+    pub content_path: String,
+    pub quorum_acked_lsn: u64,
+    pub current_write_quorum: u64,
+    pub current_replica_set_size: u64,
+    pub role: u32,
+    pub global_committed_lsn: u64,
+    pub number_of_read_regions: u32,
+    pub transport_request_id: u64,
+    pub cosmos_llsn: u64,
+    pub cosmos_quorum_acked_llsn: u64,
+    pub session_token: String,
+    pub charge: f64,
+    pub service_version: String,
+    pub activity_id: uuid::Uuid,
+    pub gateway_version: String,
+    pub date: DateTime<Utc>,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
+}
+
+impl<T: DeserializeOwned> CreateDocumentResponse<T> {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+
+        Ok(Self {
+            document: crate::json::from_json(&body)?,
+            is_update: status_code == StatusCode::OK,
+            last_state_change: last_state_change_from_headers(&headers)?,
+            etag: etag_from_headers(&headers)?,
+            resource_quota: resource_quota_from_headers(&headers)?,
+            resource_usage: resource_usage_from_headers(&headers)?,
+            lsn: lsn_from_headers(&headers)?,
+            schema_version: schema_version_from_headers(&headers)?.to_owned(),
+            alt_content_path: alt_content_path_from_headers(&headers)?.to_owned(),
+            content_path: content_path_from_headers(&headers)?.to_owned(),
+            quorum_acked_lsn: quorum_acked_lsn_from_headers(&headers)?,
+            current_write_quorum: current_write_quorum_from_headers(&headers)?,
+            current_replica_set_size: current_replica_set_size_from_headers(&headers)?,
+            role: role_from_headers(&headers)?,
+            global_committed_lsn: global_committed_lsn_from_headers(&headers)?,
+            number_of_read_regions: number_of_read_regions_from_headers(&headers)?,
+            transport_request_id: transport_request_id_from_headers(&headers)?,
+            cosmos_llsn: cosmos_llsn_from_headers(&headers)?,
+            cosmos_quorum_acked_llsn: cosmos_quorum_acked_llsn_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+            charge: request_charge_from_headers(&headers)?,
+            service_version: service_version_from_headers(&headers)?.to_owned(),
+            activity_id: activity_id_from_headers(&headers)?,
+            gateway_version: gateway_version_from_headers(&headers)?.to_owned(),
+            date: date_from_headers(&headers)?,
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use crate::resources::UserDefinedFunction;
+use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+
+#[derive(Debug, Clone, Default)]
+pub struct CreateUserDefinedFunctionOptions {
+    consistency_level: Option<ConsistencyLevel>,
+}
+
+impl CreateUserDefinedFunctionOptions {
+    pub fn new() -> Self {
+        Self {
+            consistency_level: None,
+        }
+    }
+
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(self, consistency_level: T) -> Self {
+        Self {
+            consistency_level: Some(consistency_level.into()),
+        }
+    }
+
+    pub(crate) fn decorate_request(
+        &self,
+        request: &mut HttpRequest,
+        user_defined_function_name: &str,
+        body: &str,
+    ) -> Result<(), crate::Error> {
+        #[derive(Serialize)]
+        struct CreateUserDefinedFunctionRequest<'a> {
+            id: &'a str,
+            body: &'a str,
+        }
+        let req = CreateUserDefinedFunctionRequest {
+            id: user_defined_function_name,
+            body,
+        };
+
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        super::set_json_body(request, serde_json::to_string(&req)?);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateUserDefinedFunctionResponse {
+    pub user_defined_function: UserDefinedFunction,
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub etag: String,
+    pub session_token: String,
+}
+
+impl CreateUserDefinedFunctionResponse {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+
+        Ok(Self {
+            user_defined_function: crate::json::from_json(&body)?,
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+            etag: etag_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+        })
+    }
+}
+
+/// The conversion of the replace user defined function requests, which are still sent without the
+/// pipeline
+impl std::convert::TryFrom<http::Response<bytes::Bytes>> for CreateUserDefinedFunctionResponse {
+    type Error = crate::Error;
+
+    fn try_from(response: http::Response<bytes::Bytes>) -> Result<Self, Self::Error> {
+        let headers = response.headers();
+
+        Ok(Self {
+            user_defined_function: crate::json::from_json(response.body())?,
+            charge: request_charge_from_headers(headers)?,
+            activity_id: activity_id_from_headers(headers)?,
+            etag: etag_from_headers(headers)?,
+            session_token: session_token_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::cosmos_entity::{add_as_partition_key_header_serialized2, serialize_partition_key};
+use crate::headers::from_headers::*;
+use crate::CosmosHeaders;
+use azure_core::headers::{client_request_id_from_headers_optional, session_token_from_headers};
+use azure_core::prelude::{Etag, IfMatchCondition};
+use azure_core::{Request as HttpRequest, Response as HttpResponse};
+use serde::Serialize;
+
+/// Sets the partition key of the deleted document and, with `if_match`, makes the delete fail unless
+/// the document still has that etag.
+pub(crate) fn decorate_delete_document_request<PK: Serialize>(
+    request: &mut HttpRequest,
+    partition_key: &PK,
+    if_match: Option<&Etag>,
+) -> Result<(), crate::Error> {
+    add_as_partition_key_header_serialized2(&serialize_partition_key(partition_key)?, request)?;
+    azure_core::headers::add_optional_header2(
+        &if_match.map(|etag| IfMatchCondition::Match(etag.as_ref())),
+        request,
+    )?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteDocumentResponse {
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub session_token: String,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
+}
+
+impl DeleteDocumentResponse {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, _pinned_stream) = response.deconstruct();
+
+        Ok(Self {
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
+        })
+    }
+}
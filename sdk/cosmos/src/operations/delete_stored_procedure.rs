@@ -0,0 +1,44 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use azure_core::{Request as HttpRequest, Response as HttpResponse};
+
+#[derive(Debug, Clone, Default)]
+pub struct DeleteStoredProcedureOptions {
+    consistency_level: Option<ConsistencyLevel>,
+}
+
+impl DeleteStoredProcedureOptions {
+    pub fn new() -> Self {
+        Self {
+            consistency_level: None,
+        }
+    }
+
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(self, consistency_level: T) -> Self {
+        Self {
+            consistency_level: Some(consistency_level.into()),
+        }
+    }
+
+    pub(crate) fn decorate_request(&self, request: &mut HttpRequest) -> Result<(), crate::Error> {
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteStoredProcedureResponse {
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+}
+
+impl DeleteStoredProcedureResponse {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, _pinned_stream) = response.deconstruct();
+
+        Ok(Self {
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+        })
+    }
+}
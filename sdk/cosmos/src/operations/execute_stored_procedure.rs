@@ -0,0 +1,25 @@
+use crate::cosmos_entity::{add_as_partition_key_header_serialized2, serialize_partition_key};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Sends `params` as the arguments of the stored procedure, which runs in the logical partition of
+/// `partition_key`.
+pub(crate) fn decorate_execute_stored_procedure_request<PK: Serialize>(
+    request: &mut HttpRequest,
+    params: &[serde_json::Value],
+    partition_key: &PK,
+) -> Result<(), crate::Error> {
+    add_as_partition_key_header_serialized2(&serialize_partition_key(partition_key)?, request)?;
+    super::set_json_body(request, serde_json::to_vec(params)?);
+    Ok(())
+}
+
+/// The value the stored procedure passed to `getContext().getResponse().setBody`.
+pub(crate) async fn stored_procedure_result<T: DeserializeOwned>(
+    response: HttpResponse,
+) -> Result<T, crate::Error> {
+    let (_status_code, _headers, pinned_stream) = response.deconstruct();
+    let body = collect_pinned_stream(pinned_stream).await?;
+    Ok(crate::json::from_json(&body)?)
+}
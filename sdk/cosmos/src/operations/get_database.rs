@@ -1,8 +1,11 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
+use crate::CosmosHeaders;
 use crate::ResourceQuota;
 
-use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use azure_core::headers::{
+    client_request_id_from_headers_optional, etag_from_headers, session_token_from_headers,
+};
 use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
 use chrono::{DateTime, Utc};
 
@@ -23,8 +26,8 @@ impl GetDatabaseOptions {
     }
 
     pub(crate) fn decorate_request(&self, request: &mut HttpRequest) -> Result<(), crate::Error> {
-        azure_core::headers::add_optional_header2(&self.consistency_level, request);
-        request.set_body(bytes::Bytes::from_static(&[]).into());
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        super::set_json_body(request, bytes::Bytes::new());
 
         Ok(())
     }
@@ -33,6 +36,7 @@ impl GetDatabaseOptions {
 #[derive(Debug, Clone)]
 pub struct GetDatabaseResponse {
     pub database: Database,
+    pub system_properties: CosmosSystemProperties,
     pub charge: f64,
     pub activity_id: uuid::Uuid,
     pub session_token: String,
@@ -43,6 +47,9 @@ pub struct GetDatabaseResponse {
     pub schema_version: String,
     pub service_version: String,
     pub gateway_version: String,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
 }
 
 impl GetDatabaseResponse {
@@ -51,7 +58,8 @@ impl GetDatabaseResponse {
         let body = collect_pinned_stream(pinned_stream).await?;
 
         Ok(Self {
-            database: serde_json::from_slice(&body)?,
+            database: crate::json::from_json(&body)?,
+            system_properties: crate::json::from_json(&body)?,
             charge: request_charge_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
@@ -62,6 +70,8 @@ impl GetDatabaseResponse {
             schema_version: schema_version_from_headers(&headers)?.to_owned(),
             service_version: service_version_from_headers(&headers)?.to_owned(),
             gateway_version: gateway_version_from_headers(&headers)?.to_owned(),
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
         })
     }
 }
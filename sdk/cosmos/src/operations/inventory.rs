@@ -0,0 +1,86 @@
+use crate::resources::offer::Offer;
+use crate::resources::{Collection, Database};
+use azure_core::headers::{continuation_token_from_headers_optional, CONTINUATION};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::HeaderValue;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone)]
+pub struct InventoryOptions {
+    include_offers: bool,
+    max_concurrency: usize,
+}
+
+impl InventoryOptions {
+    pub fn new() -> Self {
+        Self {
+            include_offers: false,
+            max_concurrency: 4,
+        }
+    }
+
+    setters! {
+        include_offers: bool => include_offers,
+        max_concurrency: usize => max_concurrency.max(1),
+    }
+
+    pub(crate) fn includes_offers(&self) -> bool {
+        self.include_offers
+    }
+
+    pub(crate) fn concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
+
+impl Default for InventoryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A database of the account with its collections, see
+/// [`CosmosClient::inventory`](crate::clients::CosmosClient::inventory).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatabaseInventory {
+    pub database: Database,
+    pub collections: Vec<Collection>,
+    /// The offers of the database and of its collections, empty unless
+    /// [`include_offers`](InventoryOptions::include_offers) is set.
+    pub offers: Vec<Offer>,
+}
+
+/// Asks for the page of resources after `continuation`, or the first one.
+pub(crate) fn decorate_inventory_request(
+    request: &mut HttpRequest,
+    continuation: Option<&str>,
+) -> Result<(), crate::Error> {
+    if let Some(continuation) = continuation {
+        request.headers_mut().insert(
+            CONTINUATION,
+            HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+        );
+    }
+    Ok(())
+}
+
+/// One page of the resources listed in the `field` of the body, such as `Databases`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct InventoryPage<T> {
+    pub resources: Vec<T>,
+    pub continuation_token: Option<String>,
+}
+
+impl<T: DeserializeOwned> InventoryPage<T> {
+    pub async fn try_from(response: HttpResponse, field: &str) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+        let mut body: serde_json::Map<String, serde_json::Value> = crate::json::from_json(&body)?;
+        let resources = body.remove(field).unwrap_or_default();
+
+        Ok(Self {
+            resources: serde_json::from_value(resources)?,
+            continuation_token: continuation_token_from_headers_optional(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::resources::offer::Offer;
+use azure_core::headers::{continuation_token_from_headers_optional, CONTINUATION};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::HeaderValue;
+
+/// Asks for the page of offers after `continuation`, or the first one.
+pub(crate) fn decorate_list_offers_request(
+    request: &mut HttpRequest,
+    continuation: Option<&str>,
+) -> Result<(), crate::Error> {
+    if let Some(continuation) = continuation {
+        request.headers_mut().insert(
+            CONTINUATION,
+            HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+        );
+    }
+    Ok(())
+}
+
+/// One page of the offers of an account.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OffersPage {
+    pub offers: Vec<Offer>,
+    pub continuation_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OffersBody {
+    #[serde(rename = "Offers")]
+    offers: Vec<Offer>,
+}
+
+impl OffersPage {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+        let body: OffersBody = crate::json::from_json(&body)?;
+
+        Ok(Self {
+            offers: body.offers,
+            continuation_token: continuation_token_from_headers_optional(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::resources::collection::PartitionKeyRange;
+use azure_core::headers::{continuation_token_from_headers_optional, CONTINUATION};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::HeaderValue;
+
+/// Asks for the page of partition key ranges after `continuation`, or the first one.
+pub(crate) fn decorate_list_partition_key_ranges_request(
+    request: &mut HttpRequest,
+    continuation: Option<&str>,
+) -> Result<(), crate::Error> {
+    if let Some(continuation) = continuation {
+        request.headers_mut().insert(
+            CONTINUATION,
+            HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+        );
+    }
+    Ok(())
+}
+
+/// One page of the partition key ranges of a collection.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PartitionKeyRangesPage {
+    pub partition_key_ranges: Vec<PartitionKeyRange>,
+    pub continuation_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PartitionKeyRangesBody {
+    #[serde(rename = "PartitionKeyRanges")]
+    partition_key_ranges: Vec<PartitionKeyRange>,
+}
+
+impl PartitionKeyRangesPage {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+        let body: PartitionKeyRangesBody = crate::json::from_json(&body)?;
+
+        Ok(Self {
+            partition_key_ranges: body.partition_key_ranges,
+            continuation_token: continuation_token_from_headers_optional(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use crate::resources::User;
+use azure_core::headers::{
+    continuation_token_from_headers_optional, session_token_from_headers, CONTINUATION,
+};
+use azure_core::prelude::MaxItemCount;
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::HeaderValue;
+
+#[derive(Debug, Clone)]
+pub struct ListUsersOptions {
+    consistency_level: Option<ConsistencyLevel>,
+    max_item_count: MaxItemCount,
+    continuation: Option<String>,
+    max_pages: Option<usize>,
+}
+
+impl ListUsersOptions {
+    pub fn new() -> Self {
+        Self {
+            consistency_level: None,
+            max_item_count: MaxItemCount::new(-1),
+            continuation: None,
+            max_pages: None,
+        }
+    }
+
+    setters! {
+        consistency_level: ConsistencyLevel => Some(consistency_level),
+        max_item_count: i32 => MaxItemCount::new(max_item_count),
+        continuation: String => Some(continuation),
+    }
+
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
+    /// The number of pages the stream yields at most, all of them without `max_pages`.
+    pub(crate) fn page_limit(&self) -> usize {
+        self.max_pages.unwrap_or(usize::MAX)
+    }
+
+    /// Asks for the page of users after `continuation`, or the first one, which follows the
+    /// `continuation` of the options if there is one.
+    pub(crate) fn decorate_request(
+        &self,
+        request: &mut HttpRequest,
+        continuation: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        azure_core::headers::add_mandatory_header2(&self.max_item_count, request)?;
+        if let Some(continuation) = continuation.or(self.continuation.as_deref()) {
+            request.headers_mut().insert(
+                CONTINUATION,
+                HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Default for ListUsersOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One page of the users of a database.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ListUsersResponse {
+    #[serde(rename = "Users")]
+    pub users: Vec<User>,
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    #[serde(rename = "_count")]
+    pub count: u64,
+
+    #[serde(skip_deserializing)]
+    pub charge: f64,
+    #[serde(skip_deserializing)]
+    pub activity_id: uuid::Uuid,
+    #[serde(skip_deserializing)]
+    pub session_token: String,
+    #[serde(skip_deserializing)]
+    pub continuation_token: Option<String>,
+}
+
+impl ListUsersResponse {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+
+        let mut list_users_response: ListUsersResponse = crate::json::from_json(&body)?;
+        list_users_response.charge = request_charge_from_headers(&headers)?;
+        list_users_response.activity_id = activity_id_from_headers(&headers)?;
+        list_users_response.session_token = session_token_from_headers(&headers)?;
+        list_users_response.continuation_token =
+            continuation_token_from_headers_optional(&headers)?;
+
+        Ok(list_users_response)
+    }
+}
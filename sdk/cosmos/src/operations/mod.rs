@@ -4,8 +4,98 @@
 
 mod create_collection;
 mod create_database;
+mod create_document;
+mod create_stored_procedure;
+mod create_trigger;
+mod create_user;
+mod create_user_defined_function;
+mod delete_document;
+mod delete_stored_procedure;
+mod delete_trigger;
+mod delete_user;
+mod delete_user_defined_function;
+mod execute_stored_procedure;
 mod get_database;
+mod inventory;
+mod list_offers;
+mod list_partition_key_ranges;
+mod list_stored_procedures;
+mod list_triggers;
+mod list_user_defined_functions;
+mod list_users;
+mod query_documents;
+mod read_change_feed;
+mod replace_document;
+mod transactional_batch;
 
 pub use create_collection::*;
 pub use create_database::*;
+pub use create_document::*;
+pub use create_stored_procedure::*;
+pub use create_trigger::*;
+pub use create_user::*;
+pub use create_user_defined_function::*;
+pub use delete_document::*;
+pub use delete_stored_procedure::*;
+pub use delete_trigger::*;
+pub use delete_user::*;
+pub use delete_user_defined_function::*;
+pub(crate) use execute_stored_procedure::*;
 pub use get_database::*;
+pub use inventory::*;
+pub(crate) use list_offers::*;
+pub(crate) use list_partition_key_ranges::*;
+pub use list_stored_procedures::*;
+pub use list_triggers::*;
+pub use list_user_defined_functions::*;
+pub use list_users::*;
+pub use query_documents::*;
+pub use read_change_feed::*;
+pub use replace_document::*;
+pub use transactional_batch::*;
+
+use crate::headers::{
+    HEADER_DOCUMENTDB_POST_TRIGGER_INCLUDE, HEADER_DOCUMENTDB_PRE_TRIGGER_INCLUDE,
+};
+use azure_core::Request as HttpRequest;
+use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use http::HeaderValue;
+
+/// Attaches `body` to `request` along with the matching `Content-Type` and `Content-Length`.
+///
+/// An empty body is not typed: a `Content-Type` set earlier is removed.
+pub(crate) fn set_body(request: &mut HttpRequest, body: bytes::Bytes, content_type: &'static str) {
+    let headers = request.headers_mut();
+    if body.is_empty() {
+        headers.remove(CONTENT_TYPE);
+    } else {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        headers.insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+    }
+    request.set_body(body.into());
+}
+
+/// Includes the `pre_triggers` and `post_triggers` of a collection in a document write, by name.
+pub(crate) fn add_trigger_headers(
+    request: &mut HttpRequest,
+    pre_triggers: &[String],
+    post_triggers: &[String],
+) -> Result<(), crate::Error> {
+    for (header, triggers) in [
+        (HEADER_DOCUMENTDB_PRE_TRIGGER_INCLUDE, pre_triggers),
+        (HEADER_DOCUMENTDB_POST_TRIGGER_INCLUDE, post_triggers),
+    ] {
+        if !triggers.is_empty() {
+            request.headers_mut().insert(
+                header,
+                HeaderValue::from_str(&triggers.join(",")).map_err(http::Error::from)?,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Attaches a JSON `body` to `request`, see [`set_body`].
+pub(crate) fn set_json_body(request: &mut HttpRequest, body: impl Into<bytes::Bytes>) {
+    set_body(request, body.into(), "application/json");
+}
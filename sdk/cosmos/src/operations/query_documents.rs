@@ -0,0 +1,150 @@
+use crate::headers::from_headers::query_metrics_from_headers_optional;
+use crate::headers::{
+    HEADER_DOCUMENTDB_ISQUERY, HEADER_DOCUMENTDB_PARTITIONRANGEID,
+    HEADER_DOCUMENTDB_POPULATE_QUERY_METRICS, HEADER_DOCUMENTDB_QUERY_ENABLECROSSPARTITION,
+};
+use crate::QueryMetrics;
+use azure_core::headers::{continuation_token_from_headers_optional, CONTINUATION};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use futures::{Stream, TryStreamExt};
+use http::HeaderValue;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryDocumentsOptions {
+    partition_key_range_id: Option<String>,
+    populate_query_metrics: bool,
+    stream_documents: bool,
+}
+
+impl QueryDocumentsOptions {
+    pub fn new() -> Self {
+        Self {
+            partition_key_range_id: None,
+            populate_query_metrics: false,
+            stream_documents: false,
+        }
+    }
+
+    setters! {
+        populate_query_metrics: bool,
+    }
+
+    /// Only query the documents of one partition key range, such as to query the ranges of a
+    /// collection in parallel. The ids of the ranges come from
+    /// [`list_partition_key_ranges`](crate::clients::CollectionClient::list_partition_key_ranges).
+    /// The id is not checked against them: Cosmos DB rejects the query of a range that does not
+    /// exist, or no longer does after a split.
+    pub fn partition_key_range_id(self, partition_key_range_id: &str) -> Self {
+        Self {
+            partition_key_range_id: Some(partition_key_range_id.to_owned()),
+            ..self
+        }
+    }
+
+    /// Deserialize the documents of a page as its body is received, instead of once it has
+    /// been read entirely
+    ///
+    /// Only the document being parsed is kept in memory, which bounds the memory used by the
+    /// pages of large documents. The documents are always parsed with `serde_json`, even with
+    /// the `simd-json` feature.
+    pub fn stream_documents(self, stream_documents: bool) -> Self {
+        Self {
+            stream_documents,
+            ..self
+        }
+    }
+
+    pub(crate) fn streams_documents(&self) -> bool {
+        self.stream_documents
+    }
+
+    /// Turns `request` into the query of one page: `body` is the serialized query, sent again
+    /// with the continuation token of the previous page, if any.
+    pub(crate) fn decorate_request(
+        &self,
+        request: &mut HttpRequest,
+        body: bytes::Bytes,
+        continuation: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        let headers = request.headers_mut();
+        headers.insert(HEADER_DOCUMENTDB_ISQUERY, HeaderValue::from_static("true"));
+        headers.insert(
+            HEADER_DOCUMENTDB_QUERY_ENABLECROSSPARTITION,
+            HeaderValue::from_static("true"),
+        );
+        if let Some(partition_key_range_id) = &self.partition_key_range_id {
+            headers.insert(
+                HEADER_DOCUMENTDB_PARTITIONRANGEID,
+                HeaderValue::from_str(partition_key_range_id).map_err(http::Error::from)?,
+            );
+        }
+        if self.populate_query_metrics {
+            headers.insert(
+                HEADER_DOCUMENTDB_POPULATE_QUERY_METRICS,
+                HeaderValue::from_static("true"),
+            );
+        }
+        if let Some(continuation) = continuation {
+            headers.insert(
+                CONTINUATION,
+                HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+            );
+        }
+
+        super::set_body(request, body, "application/query+json");
+        Ok(())
+    }
+}
+
+/// One page of query results.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct QueryDocumentsPage<T> {
+    pub documents: Vec<T>,
+    pub continuation_token: Option<String>,
+    pub query_metrics: Option<QueryMetrics>,
+}
+
+#[derive(Deserialize)]
+struct QueryDocumentsBody<T> {
+    #[serde(rename = "Documents")]
+    documents: Vec<T>,
+}
+
+impl<T: DeserializeOwned> QueryDocumentsPage<T> {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+        let body: QueryDocumentsBody<T> = crate::json::from_json(&body)?;
+
+        Ok(Self {
+            documents: body.documents,
+            continuation_token: continuation_token_from_headers_optional(&headers)?,
+            query_metrics: query_metrics_from_headers_optional(&headers)?,
+        })
+    }
+}
+
+/// One page of query results, whose documents are deserialized as its body is received.
+pub(crate) struct QueryDocumentsPageStream<S> {
+    pub documents: S,
+    pub continuation_token: Option<String>,
+    pub query_metrics: Option<QueryMetrics>,
+}
+
+/// Reads the continuation token and the query metrics of a page, leaving its documents to be
+/// deserialized as the rest of the body is received.
+pub(crate) fn stream_query_documents_page<T: DeserializeOwned>(
+    response: HttpResponse,
+) -> Result<QueryDocumentsPageStream<impl Stream<Item = Result<T, crate::Error>>>, crate::Error> {
+    let continuation_token = continuation_token_from_headers_optional(response.headers())?;
+    let query_metrics = query_metrics_from_headers_optional(response.headers())?;
+
+    Ok(QueryDocumentsPageStream {
+        documents: response
+            .into_json_array_stream("Documents")
+            .map_err(crate::Error::from),
+        continuation_token,
+        query_metrics,
+    })
+}
@@ -0,0 +1,75 @@
+use crate::headers::{HEADER_A_IM, HEADER_DOCUMENTDB_PARTITIONRANGEID};
+use azure_core::headers::etag_from_headers_optional;
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::header::IF_NONE_MATCH;
+use http::{HeaderValue, StatusCode};
+use serde::de::DeserializeOwned;
+
+/// Turns `request` into a read of the changes of one partition key range, since the
+/// `continuation` etag or, without it, since the creation of the collection.
+pub(crate) fn decorate_read_change_feed_request(
+    request: &mut HttpRequest,
+    partition_key_range_id: &str,
+    continuation: Option<&str>,
+) -> Result<(), crate::Error> {
+    let headers = request.headers_mut();
+    headers.insert(HEADER_A_IM, HeaderValue::from_static("Incremental feed"));
+    headers.insert(
+        HEADER_DOCUMENTDB_PARTITIONRANGEID,
+        HeaderValue::from_str(partition_key_range_id).map_err(http::Error::from)?,
+    );
+    if let Some(continuation) = continuation {
+        headers.insert(
+            IF_NONE_MATCH,
+            HeaderValue::from_str(continuation).map_err(http::Error::from)?,
+        );
+    }
+    Ok(())
+}
+
+/// The documents of a partition key range changed since the previous read of its change feed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeFeedPage<T> {
+    /// The latest version of each changed document, empty when nothing changed
+    pub documents: Vec<T>,
+    /// The etag to read the next changes from, passed back as the `continuation`
+    pub continuation: Option<String>,
+}
+
+impl<T> ChangeFeedPage<T> {
+    /// Whether the range changed since the previous read
+    pub fn has_changes(&self) -> bool {
+        !self.documents.is_empty()
+    }
+}
+
+#[derive(Deserialize)]
+struct ChangeFeedBody<T> {
+    #[serde(rename = "Documents")]
+    documents: Vec<T>,
+}
+
+impl<T: DeserializeOwned> ChangeFeedPage<T> {
+    /// Parses a `200 OK` holding the changes or a `304 Not Modified` without any, in which case
+    /// the `continuation` that was sent stays valid.
+    pub(crate) async fn try_from(
+        response: HttpResponse,
+        continuation: Option<String>,
+    ) -> Result<Self, crate::Error> {
+        let (status_code, headers, pinned_stream) = response.deconstruct();
+        let continuation = etag_from_headers_optional(&headers)?.or(continuation);
+        if status_code == StatusCode::NOT_MODIFIED {
+            return Ok(Self {
+                documents: Vec::new(),
+                continuation,
+            });
+        }
+
+        let body = collect_pinned_stream(pinned_stream).await?;
+        let body: ChangeFeedBody<T> = crate::json::from_json(&body)?;
+        Ok(Self {
+            documents: body.documents,
+            continuation,
+        })
+    }
+}
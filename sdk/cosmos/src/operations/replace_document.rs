@@ -0,0 +1,129 @@
+use crate::cosmos_entity::{add_as_partition_key_header_serialized2, serialize_partition_key};
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use crate::CosmosHeaders;
+use crate::ResourceQuota;
+use azure_core::headers::{client_request_id_from_headers_optional, session_token_from_headers};
+use azure_core::prelude::IfMatchCondition;
+use azure_core::SessionToken;
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default)]
+pub struct ReplaceDocumentOptions<'a> {
+    consistency_level: Option<ConsistencyLevel>,
+    indexing_directive: Option<IndexingDirective>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    pre_triggers: Vec<String>,
+    post_triggers: Vec<String>,
+}
+
+impl<'a> ReplaceDocumentOptions<'a> {
+    pub fn new() -> Self {
+        Self {
+            consistency_level: None,
+            indexing_directive: None,
+            if_match_condition: None,
+            pre_triggers: Vec::new(),
+            post_triggers: Vec::new(),
+        }
+    }
+
+    setters! {
+        consistency_level: ConsistencyLevel => Some(consistency_level),
+        indexing_directive: IndexingDirective => Some(indexing_directive),
+        if_match_condition: IfMatchCondition<'a> => Some(if_match_condition),
+        pre_triggers: Vec<String> => pre_triggers,
+        post_triggers: Vec<String> => post_triggers,
+    }
+
+    pub(crate) fn decorate_request<'b, T>(
+        &self,
+        request: &mut HttpRequest,
+        document: &'b T,
+    ) -> Result<(), crate::Error>
+    where
+        T: Serialize + CosmosEntity<'b>,
+    {
+        add_as_partition_key_header_serialized2(
+            &serialize_partition_key(&document.partition_key())?,
+            request,
+        )?;
+        azure_core::headers::add_optional_header2(&self.consistency_level, request)?;
+        azure_core::headers::add_optional_header2(&self.indexing_directive, request)?;
+        azure_core::headers::add_optional_header2(&self.if_match_condition, request)?;
+        super::add_trigger_headers(request, &self.pre_triggers, &self.post_triggers)?;
+
+        super::set_json_body(request, serde_json::to_string(document)?);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplaceDocumentResponse<T> {
+    pub document: Document<T>,
+    pub content_location: String,
+    pub last_state_change: DateTime<Utc>,
+    pub resource_quota: Vec<ResourceQuota>,
+    pub resource_usage: Vec<ResourceQuota>,
+    pub lsn: u64,
+    pub schema_version: String,
+    pub alt_content_path: String,
+    pub content_path: String,
+    pub quorum_acked_lsn: Option<u64>,
+    pub current_write_quorum: Option<u64>,
+    pub current_replica_set_size: Option<u64>,
+    pub role: u32,
+    pub global_committed_lsn: u64,
+    pub number_of_read_regions: u32,
+    pub transport_request_id: u64,
+    pub cosmos_llsn: u64,
+    pub cosmos_quorum_acked_llsn: Option<u64>,
+    pub session_token: SessionToken,
+    pub charge: f64,
+    pub service_version: String,
+    pub activity_id: uuid::Uuid,
+    pub gateway_version: String,
+    pub date: DateTime<Utc>,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
+}
+
+impl<T: DeserializeOwned> ReplaceDocumentResponse<T> {
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+
+        Ok(Self {
+            document: crate::json::from_json(&body)?,
+            content_location: content_location_from_headers(&headers)?.to_owned(),
+            last_state_change: last_state_change_from_headers(&headers)?,
+            resource_quota: resource_quota_from_headers(&headers)?,
+            resource_usage: resource_usage_from_headers(&headers)?,
+            lsn: lsn_from_headers(&headers)?,
+            schema_version: schema_version_from_headers(&headers)?.to_owned(),
+            alt_content_path: alt_content_path_from_headers(&headers)?.to_owned(),
+            content_path: content_path_from_headers(&headers)?.to_owned(),
+            quorum_acked_lsn: quorum_acked_lsn_from_headers_optional(&headers)?,
+            current_write_quorum: current_write_quorum_from_headers_optional(&headers)?,
+            current_replica_set_size: current_replica_set_size_from_headers_optional(&headers)?,
+            role: role_from_headers(&headers)?,
+            global_committed_lsn: global_committed_lsn_from_headers(&headers)?,
+            number_of_read_regions: number_of_read_regions_from_headers(&headers)?,
+            transport_request_id: transport_request_id_from_headers(&headers)?,
+            cosmos_llsn: cosmos_llsn_from_headers(&headers)?,
+            cosmos_quorum_acked_llsn: cosmos_quorum_acked_llsn_from_headers_optional(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+            charge: request_charge_from_headers(&headers)?,
+            service_version: service_version_from_headers(&headers)?.to_owned(),
+            activity_id: activity_id_from_headers(&headers)?,
+            gateway_version: gateway_version_from_headers(&headers)?.to_owned(),
+            date: date_from_headers(&headers)?,
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
+        })
+    }
+}
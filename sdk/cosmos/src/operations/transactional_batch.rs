@@ -0,0 +1,162 @@
+use crate::cosmos_entity::{add_as_partition_key_header_serialized2, serialize_partition_key};
+use crate::headers::from_headers::*;
+use crate::headers::{HEADER_COSMOS_BATCH_ATOMIC, HEADER_COSMOS_IS_BATCH_REQUEST};
+use crate::CosmosHeaders;
+use azure_core::headers::{client_request_id_from_headers_optional, session_token_from_headers};
+use azure_core::{collect_pinned_stream, Request as HttpRequest, Response as HttpResponse};
+use http::{HeaderValue, StatusCode};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Writes to the documents of one logical partition, applied all together or not at all.
+///
+/// The operations are sent in a single request, in the order they were added, by
+/// [`CollectionClient::execute_transactional_batch`](crate::clients::CollectionClient::execute_transactional_batch).
+#[derive(Debug, Clone)]
+pub struct TransactionalBatch {
+    partition_key: String,
+    operations: Vec<BatchOperation>,
+}
+
+/// One operation of a batch, as the service expects it in the body.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "operationType")]
+enum BatchOperation {
+    Create {
+        #[serde(rename = "resourceBody")]
+        resource_body: serde_json::Value,
+    },
+    Replace {
+        id: String,
+        #[serde(rename = "resourceBody")]
+        resource_body: serde_json::Value,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+impl TransactionalBatch {
+    /// An empty batch on the documents whose partition key is `partition_key`.
+    pub fn new<PK: Serialize>(partition_key: &PK) -> Result<Self, crate::Error> {
+        Ok(Self {
+            partition_key: serialize_partition_key(partition_key)?,
+            operations: Vec::new(),
+        })
+    }
+
+    /// Adds the creation of `document`, which fails if a document with the same id exists.
+    pub fn create_document<T: Serialize>(mut self, document: &T) -> Result<Self, crate::Error> {
+        self.operations.push(BatchOperation::Create {
+            resource_body: serde_json::to_value(document)?,
+        });
+        Ok(self)
+    }
+
+    /// Adds the replacement of the document `document_id` with `document`.
+    pub fn replace_document<S: Into<String>, T: Serialize>(
+        mut self,
+        document_id: S,
+        document: &T,
+    ) -> Result<Self, crate::Error> {
+        self.operations.push(BatchOperation::Replace {
+            id: document_id.into(),
+            resource_body: serde_json::to_value(document)?,
+        });
+        Ok(self)
+    }
+
+    /// Adds the deletion of the document `document_id`.
+    pub fn delete_document<S: Into<String>>(mut self, document_id: S) -> Self {
+        self.operations.push(BatchOperation::Delete {
+            id: document_id.into(),
+        });
+        self
+    }
+
+    /// The number of operations in the batch
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Whether the batch has no operation
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub(crate) fn decorate_request(&self, request: &mut HttpRequest) -> Result<(), crate::Error> {
+        add_as_partition_key_header_serialized2(&self.partition_key, request)?;
+        let headers = request.headers_mut();
+        headers.insert(
+            HEADER_COSMOS_IS_BATCH_REQUEST,
+            HeaderValue::from_static("True"),
+        );
+        headers.insert(HEADER_COSMOS_BATCH_ATOMIC, HeaderValue::from_static("True"));
+
+        super::set_json_body(request, serde_json::to_string(&self.operations)?);
+        Ok(())
+    }
+}
+
+/// The outcome of a transactional batch.
+///
+/// Either every operation succeeded, or none was applied: the service then answers
+/// `207 Multi-Status`, the failed operation has its own status code and the others
+/// `424 Failed Dependency`.
+#[derive(Debug, Clone)]
+pub struct TransactionalBatchResponse {
+    /// The results of the operations, in the order of the batch
+    pub results: Vec<TransactionalBatchOperationResult>,
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub session_token: String,
+    pub client_request_id: Option<String>,
+    /// All the `x-ms-*` headers, including the ones without a field of their own
+    pub ms_headers: CosmosHeaders,
+}
+
+impl TransactionalBatchResponse {
+    /// Whether the operations were applied
+    pub fn is_success(&self) -> bool {
+        self.results
+            .iter()
+            .all(|result| result.status_code.is_success())
+    }
+
+    pub async fn try_from(response: HttpResponse) -> Result<Self, crate::Error> {
+        let (_status_code, headers, pinned_stream) = response.deconstruct();
+        let body = collect_pinned_stream(pinned_stream).await?;
+
+        Ok(Self {
+            results: crate::json::from_json(&body)?,
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+            client_request_id: client_request_id_from_headers_optional(&headers),
+            ms_headers: CosmosHeaders::from_headers(&headers),
+        })
+    }
+}
+
+/// The result of one operation of a transactional batch.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TransactionalBatchOperationResult {
+    /// The status the operation would have had on its own
+    #[serde(rename = "statusCode", deserialize_with = "deserialize_status_code")]
+    pub status_code: StatusCode,
+    /// The request units consumed by the operation
+    #[serde(rename = "requestCharge", default)]
+    pub request_charge: f64,
+    /// The etag of the written document
+    #[serde(rename = "eTag", default)]
+    pub etag: Option<String>,
+    /// The written document, for the creations and replacements
+    #[serde(rename = "resourceBody", default)]
+    pub resource_body: Option<serde_json::Value>,
+}
+
+fn deserialize_status_code<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<StatusCode, D::Error> {
+    StatusCode::from_u16(u16::deserialize(deserializer)?).map_err(D::Error::custom)
+}
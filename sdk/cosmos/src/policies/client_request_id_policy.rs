@@ -0,0 +1,74 @@
+use azure_core::headers::CLIENT_REQUEST_ID;
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use http::HeaderValue;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+/// Generates the client request ids, a random UUID unless configured otherwise.
+#[derive(Clone)]
+pub(crate) struct RequestIdGenerator(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl RequestIdGenerator {
+    pub(crate) fn new<F>(generator: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        Self(Arc::new(generator))
+    }
+}
+
+impl Default for RequestIdGenerator {
+    fn default() -> Self {
+        Self::new(|| uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl Debug for RequestIdGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestIdGenerator")
+    }
+}
+
+/// Tags every operation with a client generated `x-ms-client-request-id`.
+///
+/// The id is generated once per operation, so every retry of it carries the same id. An id
+/// already set on the request is kept. The response always carries the id, even when the
+/// service does not echo it back.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientRequestIdPolicy {
+    generator: RequestIdGenerator,
+}
+
+impl ClientRequestIdPolicy {
+    pub(crate) fn new(generator: RequestIdGenerator) -> Self {
+        Self { generator }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for ClientRequestIdPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let client_request_id = match request.headers().get(CLIENT_REQUEST_ID) {
+            Some(client_request_id) => client_request_id.clone(),
+            None => {
+                let client_request_id = HeaderValue::from_str(&(self.generator.0)())?;
+                request
+                    .headers_mut()
+                    .insert(CLIENT_REQUEST_ID, client_request_id.clone());
+                client_request_id
+            }
+        };
+
+        let mut response = next[0].send(ctx, request, &next[1..]).await?;
+        response
+            .headers_mut()
+            .entry(CLIENT_REQUEST_ID)
+            .or_insert(client_request_id);
+        Ok(response)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::headers::HEADER_CONSISTENCY_LEVEL;
+use crate::ConsistencyLevel;
+
+use azure_core::{AddAsHeader, Context, Policy, PolicyResult, Request, Response};
+use std::sync::Arc;
+
+/// Sets the default consistency level of the client on every request without one.
+///
+/// The consistency level an operation sets itself wins: the default is only added when the
+/// request has no `x-ms-consistency-level` header, along with the session token of a
+/// `Session` default.
+#[derive(Debug, Clone)]
+pub(crate) struct ConsistencyLevelPolicy {
+    consistency_level: ConsistencyLevel,
+}
+
+impl ConsistencyLevelPolicy {
+    pub(crate) fn new(consistency_level: ConsistencyLevel) -> Self {
+        Self { consistency_level }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for ConsistencyLevelPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        if !request.headers().contains_key(HEADER_CONSISTENCY_LEVEL) {
+            self.consistency_level.add_as_header2(request)?;
+        }
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -0,0 +1,407 @@
+use azure_core::{Context, Policy, PolicyResult, Request, Response, ResponseBuilder, StreamError};
+use bytes::Bytes;
+use flate2::write::GzDecoder;
+use flate2::{Decompress, FlushDecompress, Status};
+use futures::{Stream, StreamExt};
+use http::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH};
+use http::HeaderValue;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+
+type Body = Pin<Box<dyn Stream<Item = Result<Bytes, StreamError>> + Send + Sync>>;
+
+/// The largest decompressed body accepted by default: 64 MiB.
+pub(crate) const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+/// Asks for compressed responses and decompresses them as they are received.
+///
+/// `Accept-Encoding: gzip, deflate` is set unless the request already has an `Accept-Encoding`.
+/// `gzip` and `deflate` encoded bodies are decoded chunk by chunk and the `Content-Encoding` and
+/// `Content-Length` headers, which no longer match the body, are removed. A body decompressing
+/// to more than `max_decompressed_size` bytes fails with an error instead of exhausting memory.
+#[derive(Debug, Clone)]
+pub(crate) struct DecompressionPolicy {
+    max_decompressed_size: usize,
+}
+
+impl DecompressionPolicy {
+    pub(crate) fn new(max_decompressed_size: usize) -> Self {
+        Self {
+            max_decompressed_size,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for DecompressionPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        request
+            .headers_mut()
+            .entry(ACCEPT_ENCODING)
+            .or_insert_with(|| HeaderValue::from_static("gzip, deflate"));
+
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        let encoding = match response.headers().get(CONTENT_ENCODING) {
+            Some(encoding) if encoding == "gzip" => Encoding::Gzip,
+            Some(encoding) if encoding == "deflate" => Encoding::Deflate,
+            _ => return Ok(response),
+        };
+
+        let (status, headers, body) = response.deconstruct();
+        let mut response = ResponseBuilder::new(status);
+        for (name, value) in headers.iter() {
+            if name != CONTENT_ENCODING && name != CONTENT_LENGTH {
+                response.with_header(name, value.clone());
+            }
+        }
+        Ok(
+            response.with_pinned_stream(Box::pin(DecompressingStream::new(
+                body,
+                encoding,
+                self.max_decompressed_size,
+            ))),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    Deflate,
+}
+
+/// Collects the output of a decoder, refusing to grow past `max_size` bytes in total.
+#[derive(Debug)]
+struct LimitedBuffer {
+    buffer: Vec<u8>,
+    written: usize,
+    max_size: usize,
+}
+
+impl Write for LimitedBuffer {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.written + data.len() > self.max_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "the decompressed body is larger than {} bytes",
+                    self.max_size
+                ),
+            ));
+        }
+        self.written += data.len();
+        self.buffer.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+enum Decoder {
+    Gzip(GzDecoder<LimitedBuffer>),
+    /// zlib wrapped or raw DEFLATE, whose writers do not report truncated streams
+    Inflate {
+        decompress: Decompress,
+        output: LimitedBuffer,
+        finished: bool,
+    },
+}
+
+impl Decoder {
+    /// Picks the decoder from the first bytes of the body.
+    ///
+    /// `deflate` is meant to be zlib wrapped, but some servers send raw DEFLATE streams: they
+    /// are told apart by the zlib header checksum.
+    fn new(encoding: Encoding, first_bytes: &[u8], output: LimitedBuffer) -> Self {
+        match encoding {
+            Encoding::Gzip => Decoder::Gzip(GzDecoder::new(output)),
+            Encoding::Deflate => Decoder::Inflate {
+                decompress: Decompress::new(is_zlib_header(first_bytes)),
+                output,
+                finished: false,
+            },
+        }
+    }
+
+    fn write_all(&mut self, mut data: &[u8]) -> io::Result<()> {
+        let (decompress, output, finished) = match self {
+            Decoder::Gzip(decoder) => return decoder.write_all(data),
+            Decoder::Inflate {
+                decompress,
+                output,
+                finished,
+            } => (decompress, output, finished),
+        };
+        while !data.is_empty() && !*finished {
+            let mut decompressed = Vec::with_capacity(32 * 1024);
+            let total_in = decompress.total_in();
+            let status = decompress
+                .decompress_vec(data, &mut decompressed, FlushDecompress::None)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            let consumed = (decompress.total_in() - total_in) as usize;
+            if consumed == 0 && decompressed.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "the compressed body is corrupted",
+                ));
+            }
+            data = &data[consumed..];
+            output.write_all(&decompressed)?;
+            *finished = status == Status::StreamEnd;
+        }
+        Ok(())
+    }
+
+    fn try_finish(&mut self) -> io::Result<()> {
+        match self {
+            Decoder::Gzip(decoder) => decoder.try_finish(),
+            Decoder::Inflate { finished: true, .. } => Ok(()),
+            Decoder::Inflate { .. } => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the compressed body is truncated",
+            )),
+        }
+    }
+
+    fn take_output(&mut self) -> Vec<u8> {
+        let output = match self {
+            Decoder::Gzip(decoder) => decoder.get_mut(),
+            Decoder::Inflate { output, .. } => output,
+        };
+        std::mem::take(&mut output.buffer)
+    }
+}
+
+fn is_zlib_header(bytes: &[u8]) -> bool {
+    match bytes {
+        [cmf, flg, ..] => cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0,
+        _ => false,
+    }
+}
+
+/// Decodes a compressed body as its chunks are received.
+struct DecompressingStream {
+    body: Body,
+    encoding: Encoding,
+    max_size: usize,
+    /// `None` until enough of the body is received to tell a zlib stream from a raw DEFLATE one
+    decoder: Option<Decoder>,
+    /// The bytes received before the decoder was picked
+    header: Vec<u8>,
+    done: bool,
+}
+
+impl DecompressingStream {
+    fn new(body: Body, encoding: Encoding, max_size: usize) -> Self {
+        Self {
+            body,
+            encoding,
+            max_size,
+            decoder: None,
+            header: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn start_decoder(&mut self) -> io::Result<&mut Decoder> {
+        let output = LimitedBuffer {
+            buffer: Vec::new(),
+            written: 0,
+            max_size: self.max_size,
+        };
+        let header = std::mem::take(&mut self.header);
+        let decoder = self
+            .decoder
+            .insert(Decoder::new(self.encoding, &header, output));
+        decoder.write_all(&header)?;
+        Ok(decoder)
+    }
+
+    fn decode(&mut self, chunk: &[u8]) -> io::Result<Vec<u8>> {
+        let decoder = match &mut self.decoder {
+            Some(decoder) => {
+                decoder.write_all(chunk)?;
+                decoder
+            }
+            None => {
+                self.header.extend_from_slice(chunk);
+                if self.header.len() < 2 {
+                    return Ok(Vec::new());
+                }
+                self.start_decoder()?
+            }
+        };
+        Ok(decoder.take_output())
+    }
+
+    fn finish(&mut self) -> io::Result<Vec<u8>> {
+        let decoder = match &mut self.decoder {
+            Some(decoder) => decoder,
+            // an empty body, as the one of a `HEAD` request, has nothing to decode
+            None if self.header.is_empty() => return Ok(Vec::new()),
+            None => self.start_decoder()?,
+        };
+        decoder.try_finish()?;
+        Ok(decoder.take_output())
+    }
+}
+
+impl Stream for DecompressingStream {
+    type Item = Result<Bytes, StreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        while !self.done {
+            let output = match futures::ready!(self.body.poll_next_unpin(cx)) {
+                Some(Ok(chunk)) => self.decode(&chunk),
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+                None => {
+                    self.done = true;
+                    self.finish()
+                }
+            };
+            match output {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => return Poll::Ready(Some(Ok(output.into()))),
+                Err(error) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(StreamError::PollError(error))));
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::collect_pinned_stream;
+
+    const JSON: &[u8] = br#"{"id":"compressed","_rid":"JHwXAA=="}"#;
+    // `JSON` gzip compressed, with a file name, as a single fixed Huffman block
+    const GZIP_FIXED: &[u8] = &[
+        0x1f, 0x8b, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x64, 0x62, 0x2e, 0x6a, 0x73,
+        0x6f, 0x6e, 0x00, 0xab, 0x56, 0xca, 0x4c, 0x51, 0xb2, 0x52, 0x4a, 0xce, 0xcf, 0x2d, 0x28,
+        0x4a, 0x2d, 0x2e, 0x4e, 0x4d, 0x51, 0xd2, 0x51, 0x8a, 0x2f, 0x02, 0x8b, 0x79, 0x79, 0x94,
+        0x47, 0x38, 0x3a, 0xda, 0xda, 0x2a, 0xd5, 0x02, 0x00, 0x8a, 0xf0, 0x9c, 0xff, 0x25, 0x00,
+        0x00, 0x00,
+    ];
+    // `many_documents()` zlib compressed, as a single dynamic Huffman block
+    const ZLIB_DYNAMIC: &[u8] = &[
+        0x78, 0xda, 0x6d, 0xd3, 0xbb, 0x4e, 0x83, 0x31, 0x10, 0x44, 0xe1, 0x57, 0x41, 0xae, 0x53,
+        0x78, 0x76, 0x7d, 0xfb, 0x79, 0x1b, 0x94, 0xa4, 0xa0, 0x48, 0x68, 0x92, 0x2a, 0xe2, 0xdd,
+        0x01, 0x51, 0x78, 0x8a, 0xd3, 0x59, 0x3a, 0x53, 0x7d, 0xf2, 0xbe, 0xca, 0xe7, 0xa5, 0xbc,
+        0x97, 0x5a, 0x4e, 0xe5, 0xfe, 0x71, 0xbb, 0xfe, 0x3e, 0x2f, 0x5f, 0xe7, 0xe7, 0xed, 0x7a,
+        0x7f, 0xbc, 0xd5, 0xf2, 0x7d, 0x7a, 0xfd, 0x67, 0x41, 0x9e, 0x3b, 0x07, 0x64, 0xb5, 0xdd,
+        0x13, 0x7a, 0x68, 0xf7, 0x46, 0x7d, 0xed, 0xde, 0xa1, 0x67, 0xdf, 0x7d, 0x40, 0x6f, 0xb1,
+        0xfb, 0xa4, 0x7e, 0xec, 0xbe, 0xa0, 0xf7, 0xb1, 0xfb, 0x01, 0x7d, 0xa4, 0xf1, 0x10, 0xdf,
+        0x74, 0x3f, 0x04, 0x34, 0x41, 0x11, 0xe1, 0x32, 0x42, 0x91, 0xe1, 0x61, 0x86, 0x22, 0xc4,
+        0xc3, 0x10, 0x45, 0x8a, 0xaa, 0xc6, 0x28, 0x72, 0x94, 0x0c, 0x52, 0x13, 0x17, 0x46, 0x29,
+        0xb2, 0x54, 0x18, 0xa6, 0x48, 0x53, 0x69, 0x9c, 0x51, 0xf1, 0x3f, 0x99, 0x67, 0x08, 0x17,
+        0xfe, 0x25, 0xf1, 0x4f, 0x76, 0x13, 0x0d, 0x12, 0xd5, 0x30, 0xd2, 0x68, 0xb8, 0x30, 0xd3,
+        0x40, 0xd3, 0x69, 0xa6, 0x81, 0xa6, 0xcb, 0x4c, 0x03, 0x4d, 0x97, 0x99, 0x06, 0x9a, 0x1e,
+        0x66, 0x1a, 0x64, 0x1a, 0xd5, 0x4c, 0xb3, 0xe2, 0x0d, 0x9a, 0x69, 0x0a, 0x17, 0x66, 0x9a,
+        0x64, 0x1a, 0xe1, 0x87, 0x8e, 0x97, 0x9e, 0x66, 0x9a, 0x78, 0xeb, 0x69, 0xa6, 0x49, 0xa6,
+        0xd1, 0xcc, 0x34, 0xc9, 0x34, 0xba, 0x99, 0xe6, 0xc4, 0x85, 0x99, 0x26, 0x99, 0xc6, 0x30,
+        0xd3, 0x44, 0xd3, 0xf9, 0x67, 0xfa, 0x03, 0xff, 0xe2, 0x81, 0x18,
+    ];
+    // `JSON` as a raw DEFLATE stored block
+    const RAW_STORED: &[u8] = &[
+        0x01, 0x25, 0x00, 0xda, 0xff, 0x7b, 0x22, 0x69, 0x64, 0x22, 0x3a, 0x22, 0x63, 0x6f, 0x6d,
+        0x70, 0x72, 0x65, 0x73, 0x73, 0x65, 0x64, 0x22, 0x2c, 0x22, 0x5f, 0x72, 0x69, 0x64, 0x22,
+        0x3a, 0x22, 0x4a, 0x48, 0x77, 0x58, 0x41, 0x41, 0x3d, 0x3d, 0x22, 0x7d,
+    ];
+
+    fn many_documents() -> Vec<u8> {
+        (0..40)
+            .map(|i| format!(r#"{{"id":"{}","name":"document {}"}},"#, i, i * 7))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    /// Decompresses `data`, received in chunks of `chunk_size` bytes.
+    async fn decompress(
+        data: &[u8],
+        chunk_size: usize,
+        encoding: Encoding,
+        max_size: usize,
+    ) -> Result<Bytes, StreamError> {
+        let chunks: Vec<Result<Bytes, StreamError>> = data
+            .chunks(chunk_size)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+        let body: Body = Box::pin(futures::stream::iter(chunks));
+        collect_pinned_stream(Box::pin(DecompressingStream::new(body, encoding, max_size))).await
+    }
+
+    #[tokio::test]
+    async fn decodes_every_encoding_in_chunks() {
+        for chunk_size in &[1, 7, 4096] {
+            let decoded = decompress(GZIP_FIXED, *chunk_size, Encoding::Gzip, usize::MAX);
+            assert_eq!(decoded.await.unwrap(), JSON);
+            let decoded = decompress(ZLIB_DYNAMIC, *chunk_size, Encoding::Deflate, usize::MAX);
+            assert_eq!(decoded.await.unwrap(), many_documents());
+            let decoded = decompress(RAW_STORED, *chunk_size, Encoding::Deflate, usize::MAX);
+            assert_eq!(decoded.await.unwrap(), JSON);
+        }
+    }
+
+    #[tokio::test]
+    async fn corrupted_bodies_are_errors() {
+        let mut corrupted = GZIP_FIXED.to_vec();
+        let crc = corrupted.len() - 8;
+        corrupted[crc] ^= 1;
+
+        assert!(decompress(&corrupted, 4096, Encoding::Gzip, usize::MAX)
+            .await
+            .is_err());
+        assert!(decompress(JSON, 4096, Encoding::Gzip, usize::MAX)
+            .await
+            .is_err());
+        assert!(decompress(
+            &ZLIB_DYNAMIC[..ZLIB_DYNAMIC.len() / 2],
+            4096,
+            Encoding::Deflate,
+            usize::MAX
+        )
+        .await
+        .is_err());
+        assert!(decompress(
+            &GZIP_FIXED[..GZIP_FIXED.len() - 4],
+            4096,
+            Encoding::Gzip,
+            usize::MAX
+        )
+        .await
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn empty_bodies_are_left_empty() {
+        for encoding in &[Encoding::Gzip, Encoding::Deflate] {
+            let decoded = decompress(&[], 4096, *encoding, usize::MAX);
+            assert!(decoded.await.unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn bodies_larger_than_the_limit_are_errors() {
+        let size = many_documents().len();
+        let decoded = decompress(ZLIB_DYNAMIC, 16, Encoding::Deflate, size);
+        assert_eq!(decoded.await.unwrap().len(), size);
+        let decoded = decompress(ZLIB_DYNAMIC, 16, Encoding::Deflate, size - 1);
+        assert!(decoded.await.is_err());
+    }
+}
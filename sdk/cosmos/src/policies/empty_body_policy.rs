@@ -0,0 +1,49 @@
+use crate::EmptyBodyContentLength;
+
+use azure_core::{Body, Context, Policy, PolicyResult, Request, Response};
+use http::header::CONTENT_LENGTH;
+use http::HeaderValue;
+use std::sync::Arc;
+
+/// Applies the [`EmptyBodyContentLength`] of the context, or the default one, to the POST and
+/// PUT requests without body.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmptyBodyPolicy;
+
+impl EmptyBodyPolicy {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for EmptyBodyPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let is_write =
+            request.method() == http::Method::POST || request.method() == http::Method::PUT;
+        let is_empty = matches!(request.body(), Body::Bytes(body) if body.is_empty());
+        if is_write && is_empty {
+            match ctx
+                .get::<EmptyBodyContentLength>()
+                .copied()
+                .unwrap_or(EmptyBodyContentLength::Send)
+            {
+                EmptyBodyContentLength::Send => {
+                    request
+                        .headers_mut()
+                        .insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
+                }
+                EmptyBodyContentLength::Omit => {
+                    request.headers_mut().remove(CONTENT_LENGTH);
+                }
+            }
+        }
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -0,0 +1,53 @@
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+/// Explains the TLS certificate failures of requests sent to the emulator.
+///
+/// The emulator serves a self-signed certificate: until it is trusted, every request to it fails
+/// deep in the transport. Such failures are replaced by [`crate::Error::EmulatorCertificate`],
+/// which tells how to fix them. The policy is only added to the pipelines of the clients whose
+/// [`is_emulator`](crate::clients::CosmosClient::is_emulator) is true, the failures of other
+/// endpoints are left untouched.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EmulatorCertificatePolicy;
+
+impl EmulatorCertificatePolicy {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for EmulatorCertificatePolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        match result {
+            Err(error) if is_certificate_error(&*error) => {
+                Err(Box::new(crate::Error::EmulatorCertificate {
+                    uri: request.uri().to_string(),
+                    source: error,
+                }))
+            }
+            result => result,
+        }
+    }
+}
+
+/// Whether `error`, or one of its sources, is the rejection of the certificate of the server.
+///
+/// The TLS backends only tell it in their messages, such as `invalid peer certificate:
+/// UnknownIssuer` or `certificate verify failed`.
+fn is_certificate_error(error: &(dyn StdError + 'static)) -> bool {
+    std::iter::successors(Some(error), |error| (*error).source()).any(|error| {
+        error
+            .to_string()
+            .to_ascii_lowercase()
+            .contains("certificate")
+    })
+}
@@ -0,0 +1,31 @@
+use crate::drain::InFlight;
+
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use std::sync::Arc;
+
+/// Counts the operations between their dispatch and their response, retries included, for
+/// [`CosmosClient::drain`](crate::clients::CosmosClient::drain).
+#[derive(Debug, Clone)]
+pub(crate) struct InFlightPolicy {
+    in_flight: InFlight,
+}
+
+impl InFlightPolicy {
+    pub(crate) fn new(in_flight: InFlight) -> Self {
+        Self { in_flight }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for InFlightPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        // dropped on completion, and when the caller drops the future
+        let _guard = self.in_flight.start();
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -0,0 +1,285 @@
+use crate::headers::{HEADER_ACTIVITY_ID, HEADER_REQUEST_CHARGE};
+use crate::OperationName;
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::Instrument;
+
+/// Emits one `tracing` event per request sent to Cosmos DB.
+///
+/// The request runs in a `cosmos_request` span and, once it completes, an event records its
+/// `method`, `resource_type`, `status`, `duration_ms`, `ru_charge` and `activity_id`. Both also
+/// record the `operation` the request is sent for, when its [`OperationName`] is known. Successful
+/// requests are logged at the `INFO` level, the ones the service rejected at `WARN` and the ones
+/// that got no response at all at `ERROR`, with the `error` instead of the status.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LoggingPolicy;
+
+impl LoggingPolicy {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for LoggingPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let method = request.method();
+        let resource_type = resource_type(request.uri().path()).to_owned();
+        let operation = ctx.get::<OperationName>().cloned();
+        let operation = operation.as_ref().map(OperationName::as_str);
+        let span = tracing::info_span!(
+            "cosmos_request",
+            method = %method,
+            resource_type = %resource_type,
+            operation
+        );
+
+        let start = Instant::now();
+        let result = next[0]
+            .send(ctx, request, &next[1..])
+            .instrument(span.clone())
+            .await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        let _entered = span.enter();
+        match &result {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let headers = response.headers();
+                let ru_charge = headers
+                    .get(HEADER_REQUEST_CHARGE)
+                    .and_then(|charge| charge.to_str().ok())
+                    .and_then(|charge| charge.parse::<f64>().ok())
+                    .unwrap_or_default();
+                let activity_id = headers
+                    .get(HEADER_ACTIVITY_ID)
+                    .and_then(|activity_id| activity_id.to_str().ok())
+                    .unwrap_or_default();
+
+                if response.status().is_success() {
+                    tracing::info!(
+                        method = %method,
+                        resource_type = %resource_type,
+                        operation,
+                        status,
+                        duration_ms,
+                        ru_charge,
+                        activity_id,
+                        "cosmos request completed"
+                    );
+                } else {
+                    tracing::warn!(
+                        method = %method,
+                        resource_type = %resource_type,
+                        operation,
+                        status,
+                        duration_ms,
+                        ru_charge,
+                        activity_id,
+                        "cosmos request rejected"
+                    );
+                }
+            }
+            Err(error) => tracing::error!(
+                method = %method,
+                resource_type = %resource_type,
+                operation,
+                duration_ms,
+                error = %error,
+                "cosmos request failed"
+            ),
+        }
+
+        result
+    }
+}
+
+/// The kind of resource addressed by `path`, such as `docs` for `/dbs/db/colls/coll/docs/doc`.
+fn resource_type(path: &str) -> &str {
+    // the segments alternate between a resource type and the name of a resource of that type
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    segments[(segments.len() - 1) & !1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::{CosmosClient, CosmosOptions};
+    use crate::mock_transport::{MockResponse, MockTransport};
+    use crate::operations::CreateDatabaseOptions;
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata};
+
+    /// Collects the level and fields of every event, and the fields of every span.
+    #[derive(Default)]
+    struct EventRecorder {
+        events: Mutex<Vec<(Level, HashMap<String, String>)>>,
+        spans: Mutex<Vec<HashMap<String, String>>>,
+    }
+
+    struct FieldRecorder<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldRecorder<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_owned(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_owned(), value.to_owned());
+        }
+    }
+
+    impl tracing::Subscriber for EventRecorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = HashMap::new();
+            span.record(&mut FieldRecorder(&mut fields));
+            self.spans.lock().unwrap().push(fields);
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = HashMap::new();
+            event.record(&mut FieldRecorder(&mut fields));
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), fields));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    fn delete_document(response: MockResponse) -> Vec<(Level, HashMap<String, String>)> {
+        let client = CosmosClient::new_emulator(
+            "localhost",
+            CosmosOptions::with_client(Arc::new(MockTransport::new(vec![response]))),
+        )
+        .into_database_client("db")
+        .into_collection_client("coll");
+
+        let recorder = Arc::new(EventRecorder::default());
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let _ = futures::executor::block_on(client.delete_document(
+                Context::new(),
+                "doc",
+                &"doc",
+                None,
+            ));
+        });
+        let events = recorder.events.lock().unwrap().clone();
+        events
+    }
+
+    #[test]
+    fn completed_requests_are_logged_with_their_fields() {
+        let events = delete_document(
+            MockResponse::new(http::StatusCode::NO_CONTENT, "")
+                .header("x-ms-session-token", "0:-1#3")
+                .header("x-ms-request-charge", "6.29")
+                .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44"),
+        );
+
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, Level::INFO);
+        assert_eq!(fields["method"], "DELETE");
+        assert_eq!(fields["resource_type"], "docs");
+        assert_eq!(fields["status"], "204");
+        assert_eq!(fields["ru_charge"], "6.29");
+        assert_eq!(
+            fields["activity_id"],
+            "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44"
+        );
+        assert!(fields.contains_key("duration_ms"));
+    }
+
+    #[test]
+    fn rejected_requests_are_warnings() {
+        let events = delete_document(
+            MockResponse::new(http::StatusCode::NOT_FOUND, "").header("x-ms-request-charge", "1"),
+        );
+
+        assert_eq!(events.len(), 1);
+        let (level, fields) = &events[0];
+        assert_eq!(*level, Level::WARN);
+        assert_eq!(fields["status"], "404");
+        assert_eq!(fields["ru_charge"], "1.0");
+        assert_eq!(fields["activity_id"], "");
+    }
+
+    fn create_database(ctx: Context) -> Arc<EventRecorder> {
+        let client = CosmosClient::new_emulator(
+            "localhost",
+            CosmosOptions::with_client(Arc::new(MockTransport::new(vec![MockResponse::database(
+                http::StatusCode::CREATED,
+                "db",
+            )]))),
+        );
+
+        let recorder = Arc::new(EventRecorder::default());
+        tracing::subscriber::with_default(recorder.clone(), || {
+            futures::executor::block_on(client.create_database(
+                ctx,
+                "db",
+                CreateDatabaseOptions::new(),
+            ))
+            .unwrap();
+        });
+        recorder
+    }
+
+    #[test]
+    fn requests_are_logged_with_their_operation() {
+        let recorder = create_database(Context::new());
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0]["operation"], "create_database");
+        assert_eq!(spans[0]["resource_type"], "dbs");
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(events[0].1["operation"], "create_database");
+    }
+
+    #[test]
+    fn the_operation_named_by_the_caller_is_kept() {
+        let mut ctx = Context::new();
+        ctx.insert(OperationName::new("provision_tenant"));
+
+        let recorder = create_database(ctx);
+
+        assert_eq!(
+            recorder.spans.lock().unwrap()[0]["operation"],
+            "provision_tenant"
+        );
+    }
+
+    #[test]
+    fn resource_type_is_the_last_type_in_the_path() {
+        assert_eq!(resource_type("/dbs"), "dbs");
+        assert_eq!(resource_type("/dbs/db"), "dbs");
+        assert_eq!(resource_type("/dbs/db/colls"), "colls");
+        assert_eq!(resource_type("/dbs/db/colls/coll/docs/doc"), "docs");
+        assert_eq!(resource_type("/offers/"), "offers");
+    }
+}
@@ -0,0 +1,21 @@
+//! Pipeline policies specific to Cosmos DB.
+
+mod client_request_id_policy;
+mod consistency_level_policy;
+mod decompression_policy;
+mod empty_body_policy;
+mod emulator_certificate_policy;
+mod in_flight_policy;
+mod logging_policy;
+mod signing_time_policy;
+mod tentative_writes_policy;
+
+pub(crate) use client_request_id_policy::{ClientRequestIdPolicy, RequestIdGenerator};
+pub(crate) use consistency_level_policy::ConsistencyLevelPolicy;
+pub(crate) use decompression_policy::{DecompressionPolicy, DEFAULT_MAX_DECOMPRESSED_SIZE};
+pub(crate) use empty_body_policy::EmptyBodyPolicy;
+pub(crate) use emulator_certificate_policy::EmulatorCertificatePolicy;
+pub(crate) use in_flight_policy::InFlightPolicy;
+pub(crate) use logging_policy::LoggingPolicy;
+pub(crate) use signing_time_policy::SigningTimePolicy;
+pub(crate) use tentative_writes_policy::TentativeWritesPolicy;
@@ -0,0 +1,41 @@
+use crate::clients::sign_request_at;
+use crate::resources::permission::AuthorizationToken;
+use crate::{Clock, SigningTime};
+
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use std::sync::Arc;
+
+/// Signs the requests of an operation with its [`SigningTime`], or the time of the [`Clock`].
+///
+/// The requests are prepared, and signed, before the pipeline sees their context: this policy
+/// replaces their `x-ms-date` header and signature with the time the operation is sent at, or
+/// the one its context pins.
+#[derive(Debug, Clone)]
+pub(crate) struct SigningTimePolicy {
+    auth_token: AuthorizationToken,
+    clock: Arc<dyn Clock>,
+}
+
+impl SigningTimePolicy {
+    pub(crate) fn new(auth_token: AuthorizationToken, clock: Arc<dyn Clock>) -> Self {
+        Self { auth_token, clock }
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for SigningTimePolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        let time = match ctx.get::<SigningTime>() {
+            Some(SigningTime(time)) => *time,
+            None => self.clock.now(),
+        };
+        sign_request_at(&self.auth_token, request, time)?;
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
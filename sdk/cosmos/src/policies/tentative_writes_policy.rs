@@ -0,0 +1,37 @@
+use crate::headers::HEADER_ALLOW_MULTIPLE_WRITES;
+
+use azure_core::{Context, Policy, PolicyResult, Request, Response};
+use http::HeaderValue;
+use std::sync::Arc;
+
+/// Allows tentative writes on every request that is not a read.
+///
+/// Multi-region write accounts reject writes sent to a non-primary region unless the
+/// `x-ms-cosmos-allow-tentative-writes` header is set. GET requests are left untouched.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TentativeWritesPolicy;
+
+impl TentativeWritesPolicy {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl Policy for TentativeWritesPolicy {
+    async fn send(
+        &self,
+        ctx: &mut Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult<Response> {
+        if request.method() != http::Method::GET {
+            request.headers_mut().insert(
+                HEADER_ALLOW_MULTIPLE_WRITES,
+                HeaderValue::from_static("true"),
+            );
+        }
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
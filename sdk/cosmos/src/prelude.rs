@@ -18,7 +18,7 @@ pub use crate::{ConsistencyLevel, CosmosEntity};
 pub use crate::clients::*;
 
 // Resources
-pub use crate::resources::collection::Offer;
+pub use crate::resources::collection::{Offer, PartitionKeyRange};
 #[doc(inline)]
 pub use crate::resources::document::*;
 #[doc(inline)]
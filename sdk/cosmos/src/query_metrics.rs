@@ -0,0 +1,144 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The server-side metrics of one page of a query, as reported when querying with
+/// `populate_query_metrics(true)`
+///
+/// They come from the `x-ms-documentdb-query-metrics` header. A metric missing from the header
+/// is zero, and the ones this type does not know are ignored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryMetrics {
+    /// The time spent running the query, the sum of the other times
+    pub total_execution_time: Duration,
+    /// The time spent compiling the query
+    pub query_compile_time: Duration,
+    /// The time spent building the logical plan of the query
+    pub logical_plan_build_time: Duration,
+    /// The time spent building the physical plan of the query
+    pub physical_plan_build_time: Duration,
+    /// The time spent optimizing the query
+    pub query_optimization_time: Duration,
+    /// The time spent in the query runtime
+    pub vm_execution_time: Duration,
+    /// The time spent looking up the documents in the index
+    pub index_lookup_time: Duration,
+    /// The time spent loading the documents
+    pub document_load_time: Duration,
+    /// The time spent in system functions
+    pub system_function_execution_time: Duration,
+    /// The time spent in user defined functions
+    pub user_function_execution_time: Duration,
+    /// The time spent writing the results
+    pub document_write_time: Duration,
+    /// The number of documents read from the index
+    pub retrieved_document_count: u64,
+    /// The size of the documents read from the index, in bytes
+    pub retrieved_document_size: u64,
+    /// The number of documents returned
+    pub output_document_count: u64,
+    /// The size of the documents returned, in bytes
+    pub output_document_size: u64,
+    /// The ratio of the documents matched by the index to the documents loaded
+    pub index_utilization_ratio: f64,
+}
+
+impl FromStr for QueryMetrics {
+    type Err = QueryMetricsParsingError;
+
+    /// Parses the `;`-delimited `name=value` metrics of the `x-ms-documentdb-query-metrics`
+    /// header, such as `totalExecutionTimeInMs=33.67;retrievedDocumentCount=2000`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut metrics = Self::default();
+
+        for part in s.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) =
+                part.split_once('=')
+                    .ok_or_else(|| QueryMetricsParsingError::UnrecognizedPart {
+                        part: part.to_owned(),
+                        full_string: s.to_owned(),
+                    })?;
+            let number = || {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|number| number.is_finite() && *number >= 0.0)
+                    .ok_or_else(|| QueryMetricsParsingError::InvalidNumber {
+                        name: name.to_owned(),
+                        value: value.to_owned(),
+                    })
+            };
+            let time = || number().map(|ms| Duration::from_secs_f64(ms / 1000.0));
+            let count = || number().map(|count| count as u64);
+
+            match name {
+                "totalExecutionTimeInMs" => metrics.total_execution_time = time()?,
+                "queryCompileTimeInMs" => metrics.query_compile_time = time()?,
+                "queryLogicalPlanBuildTimeInMs" => metrics.logical_plan_build_time = time()?,
+                "queryPhysicalPlanBuildTimeInMs" => metrics.physical_plan_build_time = time()?,
+                "queryOptimizationTimeInMs" => metrics.query_optimization_time = time()?,
+                "VMExecutionTimeInMs" => metrics.vm_execution_time = time()?,
+                "indexLookupTimeInMs" => metrics.index_lookup_time = time()?,
+                "documentLoadTimeInMs" => metrics.document_load_time = time()?,
+                "systemFunctionExecuteTimeInMs" => metrics.system_function_execution_time = time()?,
+                "userFunctionExecuteTimeInMs" => metrics.user_function_execution_time = time()?,
+                "writeOutputTimeInMs" => metrics.document_write_time = time()?,
+                "retrievedDocumentCount" => metrics.retrieved_document_count = count()?,
+                "retrievedDocumentSize" => metrics.retrieved_document_size = count()?,
+                "outputDocumentCount" => metrics.output_document_count = count()?,
+                "outputDocumentSize" => metrics.output_document_size = count()?,
+                "indexUtilizationRatio" => metrics.index_utilization_ratio = number()?,
+                _ => {}
+            }
+        }
+
+        Ok(metrics)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum QueryMetricsParsingError {
+    #[error(
+        "query metrics have an unrecognized part - part: \"{}\" full string: \"{}\"",
+        part,
+        full_string
+    )]
+    UnrecognizedPart { part: String, full_string: String },
+    #[error("the query metric {name} is not a positive number: \"{value}\"")]
+    InvalidNumber { name: String, value: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_query_metrics() {
+        let metrics: QueryMetrics = "totalExecutionTimeInMs=33.67;queryCompileTimeInMs=0.06;queryLogicalPlanBuildTimeInMs=0.02;queryPhysicalPlanBuildTimeInMs=0.10;queryOptimizationTimeInMs=0.00;VMExecutionTimeInMs=32.56;indexLookupTimeInMs=0.99;documentLoadTimeInMs=9.58;systemFunctionExecuteTimeInMs=0.00;userFunctionExecuteTimeInMs=0.00;retrievedDocumentCount=2000;retrievedDocumentSize=1125600;outputDocumentCount=2000;outputDocumentSize=1125600;writeOutputTimeInMs=18.10;indexUtilizationRatio=1.00"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            metrics.total_execution_time,
+            Duration::from_secs_f64(0.03367)
+        );
+        assert_eq!(metrics.index_lookup_time, Duration::from_secs_f64(0.00099));
+        assert_eq!(metrics.document_load_time, Duration::from_secs_f64(0.00958));
+        assert_eq!(metrics.document_write_time, Duration::from_secs_f64(0.0181));
+        assert_eq!(metrics.query_optimization_time, Duration::ZERO);
+        assert_eq!(metrics.retrieved_document_count, 2000);
+        assert_eq!(metrics.output_document_size, 1125600);
+        assert_eq!(metrics.index_utilization_ratio, 1.0);
+    }
+
+    #[test]
+    fn unknown_metrics_are_ignored_and_invalid_ones_rejected() {
+        let metrics: QueryMetrics = "totalExecutionTimeInMs=1.00;someNewMetricInMs=2.00;"
+            .parse()
+            .unwrap();
+        assert_eq!(metrics.total_execution_time, Duration::from_millis(1));
+        assert_eq!(metrics.document_load_time, Duration::ZERO);
+
+        assert!("totalExecutionTimeInMs".parse::<QueryMetrics>().is_err());
+        assert!("totalExecutionTimeInMs=-1".parse::<QueryMetrics>().is_err());
+    }
+}
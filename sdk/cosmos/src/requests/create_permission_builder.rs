@@ -10,7 +10,6 @@ use std::convert::TryInto;
 #[derive(Debug, Clone)]
 pub struct CreatePermissionBuilder<'a, 'b> {
     permission_client: &'a PermissionClient,
-    // TODO: use this field
     expiry_seconds: ExpirySeconds,
     user_agent: Option<UserAgent<'b>>,
     activity_id: Option<ActivityId<'b>>,
@@ -58,6 +57,7 @@ impl<'a, 'b> CreatePermissionBuilder<'a, 'b> {
         let request = azure_core::headers::add_optional_header(&self.user_agent, request);
         let request = azure_core::headers::add_optional_header(&self.activity_id, request);
         let request = azure_core::headers::add_optional_header(&self.consistency_level, request);
+        let request = azure_core::headers::add_mandatory_header(&self.expiry_seconds, request);
 
         let request = request.header(http::header::CONTENT_TYPE, "application/json");
 
@@ -79,11 +79,12 @@ impl<'a, 'b> CreatePermissionBuilder<'a, 'b> {
         let request = request.body(request_body)?;
         debug!("\nrequest == {:#?}", request);
 
-        Ok(self
+        let response = self
             .permission_client
             .http_client()
             .execute_request_check_status(request, StatusCode::CREATED)
-            .await?
-            .try_into()?)
+            .await?;
+
+        (response, self.expiry_seconds).try_into()
     }
 }
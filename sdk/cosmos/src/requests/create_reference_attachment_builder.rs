@@ -46,12 +46,10 @@ impl<'a, 'b> CreateReferenceAttachmentBuilder<'a, 'b> {
         req = azure_core::headers::add_optional_header(&self.activity_id, req);
         req = azure_core::headers::add_optional_header(&self.consistency_level, req);
 
-        req = crate::cosmos_entity::add_as_partition_key_header_serialized(
-            self.attachment_client
-                .document_client()
-                .partition_key_serialized(),
-            req,
-        );
+        req = self
+            .attachment_client
+            .document_client()
+            .add_partition_key_header(req);
 
         #[derive(Debug, Serialize)]
         struct Request<'r> {
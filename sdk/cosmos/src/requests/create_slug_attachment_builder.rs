@@ -52,12 +52,10 @@ impl<'a, 'b> CreateSlugAttachmentBuilder<'a, 'b> {
         req = azure_core::headers::add_optional_header(&self.consistency_level, req);
         req = azure_core::headers::add_optional_header(&self.content_type, req);
 
-        req = crate::cosmos_entity::add_as_partition_key_header_serialized(
-            self.attachment_client
-                .document_client()
-                .partition_key_serialized(),
-            req,
-        );
+        req = self
+            .attachment_client
+            .document_client()
+            .add_partition_key_header(req);
 
         req = req.header("Slug", self.attachment_client.attachment_name());
         req = req.header(http::header::CONTENT_LENGTH, body.len());
@@ -1,61 +0,0 @@
-use crate::prelude::*;
-use crate::responses::CreateUserResponse;
-use azure_core::prelude::*;
-use http::StatusCode;
-use std::convert::TryInto;
-
-#[derive(Debug, Clone)]
-pub struct CreateUserBuilder<'a, 'b> {
-    user_client: &'a UserClient,
-    user_agent: Option<UserAgent<'b>>,
-    activity_id: Option<ActivityId<'b>>,
-    consistency_level: Option<ConsistencyLevel>,
-}
-
-impl<'a, 'b> CreateUserBuilder<'a, 'b> {
-    pub(crate) fn new(user_client: &'a UserClient) -> Self {
-        Self {
-            user_client,
-            user_agent: None,
-            activity_id: None,
-            consistency_level: None,
-        }
-    }
-
-    setters! {
-        user_agent: &'b str => Some(UserAgent::new(user_agent)),
-        activity_id: &'b str => Some(ActivityId::new(activity_id)),
-        consistency_level: ConsistencyLevel => Some(consistency_level),
-    }
-
-    pub async fn execute(&self) -> Result<CreateUserResponse, crate::Error> {
-        trace!("CreateUserBuilder::execute called");
-
-        let req = self.user_client.prepare_request(http::Method::POST);
-
-        let req = azure_core::headers::add_optional_header(&self.user_agent, req);
-        let req = azure_core::headers::add_optional_header(&self.activity_id, req);
-        let req = azure_core::headers::add_optional_header(&self.consistency_level, req);
-
-        let req = req.header(http::header::CONTENT_TYPE, "application/json");
-
-        #[derive(Serialize, Deserialize)]
-        struct RequestBody<'x> {
-            id: &'x str,
-        }
-        let request_body = RequestBody {
-            id: self.user_client.user_name(),
-        };
-        let request_body = azure_core::to_json(&request_body)?;
-
-        let req = req.body(request_body)?;
-        debug!("\nreq == {:?}", req);
-
-        Ok(self
-            .user_client
-            .http_client()
-            .execute_request_check_status(req, StatusCode::CREATED)
-            .await?
-            .try_into()?)
-    }
-}
@@ -1,53 +0,0 @@
-use crate::prelude::*;
-use crate::responses::DeleteTriggerResponse;
-use azure_core::prelude::*;
-
-use http::StatusCode;
-use std::convert::TryInto;
-
-#[derive(Debug, Clone)]
-pub struct DeleteTriggerBuilder<'a, 'b> {
-    trigger_client: &'a TriggerClient,
-    user_agent: Option<UserAgent<'b>>,
-    activity_id: Option<ActivityId<'b>>,
-    consistency_level: Option<ConsistencyLevel>,
-}
-
-impl<'a, 'b> DeleteTriggerBuilder<'a, 'b> {
-    pub(crate) fn new(trigger_client: &'a TriggerClient) -> Self {
-        Self {
-            trigger_client,
-            user_agent: None,
-            activity_id: None,
-            consistency_level: None,
-        }
-    }
-
-    setters! {
-        user_agent: &'b str => Some(UserAgent::new(user_agent)),
-        activity_id: &'b str => Some(ActivityId::new(activity_id)),
-        consistency_level: ConsistencyLevel => Some(consistency_level),
-    }
-
-    pub async fn execute(&self) -> Result<DeleteTriggerResponse, crate::Error> {
-        trace!("DeleteTriggerBuilder::execute called");
-
-        let req = self
-            .trigger_client
-            .prepare_request_with_trigger_name(http::Method::DELETE);
-
-        // add trait headers
-        let req = azure_core::headers::add_optional_header(&self.user_agent, req);
-        let req = azure_core::headers::add_optional_header(&self.activity_id, req);
-        let req = azure_core::headers::add_optional_header(&self.consistency_level, req);
-
-        let request = req.body(bytes::Bytes::from_static(EMPTY_BODY))?;
-
-        Ok(self
-            .trigger_client
-            .http_client()
-            .execute_request_check_status(request, StatusCode::NO_CONTENT)
-            .await?
-            .try_into()?)
-    }
-}
@@ -10,6 +10,7 @@ pub struct GetCollectionBuilder<'a> {
     user_agent: Option<UserAgent<'a>>,
     activity_id: Option<ActivityId<'a>>,
     consistency_level: Option<ConsistencyLevel>,
+    populate_quota_info: bool,
 }
 
 impl<'a> GetCollectionBuilder<'a> {
@@ -19,6 +20,7 @@ impl<'a> GetCollectionBuilder<'a> {
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            populate_quota_info: false,
         }
     }
 
@@ -26,6 +28,7 @@ impl<'a> GetCollectionBuilder<'a> {
         user_agent: &'a str => Some(UserAgent::new(user_agent)),
         activity_id: &'a str => Some(ActivityId::new(activity_id)),
         consistency_level: ConsistencyLevel => Some(consistency_level),
+        populate_quota_info: bool,
     }
 
     pub async fn execute(&self) -> Result<GetCollectionResponse, crate::Error> {
@@ -38,6 +41,14 @@ impl<'a> GetCollectionBuilder<'a> {
         let request = azure_core::headers::add_optional_header(&self.user_agent, request);
         let request = azure_core::headers::add_optional_header(&self.activity_id, request);
         let request = azure_core::headers::add_optional_header(&self.consistency_level, request);
+        let request = if self.populate_quota_info {
+            request.header(
+                crate::headers::HEADER_DOCUMENTDB_POPULATE_QUOTA_INFO,
+                "true",
+            )
+        } else {
+            request
+        };
 
         let request = request.body(bytes::Bytes::from_static(EMPTY_BODY))?;
 
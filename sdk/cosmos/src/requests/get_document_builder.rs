@@ -51,10 +51,7 @@ impl<'a, 'b> GetDocumentBuilder<'a, 'b> {
         req = azure_core::headers::add_optional_header(&self.activity_id, req);
         req = azure_core::headers::add_optional_header(&self.consistency_level, req);
 
-        req = crate::cosmos_entity::add_as_partition_key_header_serialized(
-            self.document_client.partition_key_serialized(),
-            req,
-        );
+        req = self.document_client.add_partition_key_header(req);
 
         let req = req.body(bytes::Bytes::from_static(EMPTY_BODY))?;
 
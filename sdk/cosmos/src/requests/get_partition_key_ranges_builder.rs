@@ -40,11 +40,7 @@ impl<'a, 'b> GetPartitionKeyRangesBuilder<'a, 'b> {
         trace!("GetPartitionKeyRangesBuilder::execute called");
 
         let request = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/pkranges",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
+            &format!("{}/pkranges", self.collection_client.path()),
             http::Method::GET,
             ResourceType::PartitionKeyRanges,
         );
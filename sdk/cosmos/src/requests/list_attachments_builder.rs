@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::resources::ResourceType;
 use crate::responses::ListAttachmentsResponse;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use futures::stream::{unfold, Stream, StreamExt};
 use http::StatusCode;
 use std::convert::TryInto;
 
@@ -15,6 +15,7 @@ pub struct ListAttachmentsBuilder<'a, 'b> {
     consistency_level: Option<ConsistencyLevel>,
     continuation: Option<Continuation<'b>>,
     max_item_count: MaxItemCount,
+    max_pages: Option<usize>,
     a_im: ChangeFeed,
 }
 
@@ -28,6 +29,7 @@ impl<'a, 'b> ListAttachmentsBuilder<'a, 'b> {
             consistency_level: None,
             continuation: None,
             max_item_count: MaxItemCount::new(-1),
+            max_pages: None,
             a_im: ChangeFeed::None,
         }
     }
@@ -42,12 +44,18 @@ impl<'a, 'b> ListAttachmentsBuilder<'a, 'b> {
         a_im: ChangeFeed,
     }
 
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
     pub async fn execute(&self) -> Result<ListAttachmentsResponse, crate::Error> {
         let mut req = self.document_client.cosmos_client().prepare_request(
             &format!(
-                "dbs/{}/colls/{}/docs/{}/attachments",
-                self.document_client.database_client().database_name(),
-                self.document_client.collection_client().collection_name(),
+                "{}/docs/{}/attachments",
+                self.document_client.collection_client().path(),
                 self.document_client.document_name()
             ),
             http::Method::GET,
@@ -63,10 +71,7 @@ impl<'a, 'b> ListAttachmentsBuilder<'a, 'b> {
         req = azure_core::headers::add_mandatory_header(&self.max_item_count, req);
         req = azure_core::headers::add_mandatory_header(&self.a_im, req);
 
-        req = crate::cosmos_entity::add_as_partition_key_header_serialized(
-            self.document_client.partition_key_serialized(),
-            req,
-        );
+        req = self.document_client.add_partition_key_header(req);
 
         let req = req.body(bytes::Bytes::from_static(EMPTY_BODY))?;
 
@@ -117,5 +122,6 @@ impl<'a, 'b> ListAttachmentsBuilder<'a, 'b> {
                 }
             },
         )
+        .take(self.max_pages.unwrap_or(usize::MAX))
     }
 }
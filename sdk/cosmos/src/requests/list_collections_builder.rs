@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::resources::ResourceType;
 use crate::responses::ListCollectionsResponse;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use futures::stream::{unfold, Stream, StreamExt};
 use http::StatusCode;
 use std::convert::TryInto;
 
@@ -14,6 +14,7 @@ pub struct ListCollectionsBuilder<'a> {
     consistency_level: Option<ConsistencyLevel>,
     continuation: Option<Continuation<'a>>,
     max_item_count: MaxItemCount,
+    max_pages: Option<usize>,
 }
 
 impl<'a> ListCollectionsBuilder<'a> {
@@ -21,6 +22,7 @@ impl<'a> ListCollectionsBuilder<'a> {
         ListCollectionsBuilder {
             database_client,
             max_item_count: MaxItemCount::new(-1),
+            max_pages: None,
             user_agent: None,
             activity_id: None,
             consistency_level: None,
@@ -36,6 +38,13 @@ impl<'a> ListCollectionsBuilder<'a> {
         max_item_count: i32 => MaxItemCount::new(max_item_count),
     }
 
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
     pub async fn execute(&self) -> Result<ListCollectionsResponse, crate::Error> {
         trace!("ListCollectionsBuilder::execute called");
         let request = self.database_client.cosmos_client().prepare_request(
@@ -101,5 +110,6 @@ impl<'a> ListCollectionsBuilder<'a> {
                 }
             },
         )
+        .take(self.max_pages.unwrap_or(usize::MAX))
     }
 }
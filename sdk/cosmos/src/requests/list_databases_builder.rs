@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::resources::ResourceType;
 use crate::responses::ListDatabasesResponse;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use futures::stream::{unfold, Stream, StreamExt};
 use http::StatusCode;
 use std::convert::TryInto;
 
@@ -14,6 +14,7 @@ pub struct ListDatabasesBuilder<'a> {
     consistency_level: Option<ConsistencyLevel>,
     continuation: Option<Continuation<'a>>,
     max_item_count: MaxItemCount,
+    max_pages: Option<usize>,
 }
 
 impl<'a> ListDatabasesBuilder<'a> {
@@ -25,6 +26,7 @@ impl<'a> ListDatabasesBuilder<'a> {
             consistency_level: None,
             continuation: None,
             max_item_count: MaxItemCount::new(-1),
+            max_pages: None,
         }
     }
 
@@ -36,6 +38,13 @@ impl<'a> ListDatabasesBuilder<'a> {
         max_item_count: i32 => MaxItemCount::new(max_item_count),
     }
 
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
     pub async fn execute(&self) -> Result<ListDatabasesResponse, crate::Error> {
         trace!("ListDatabasesBuilder::execute called");
 
@@ -98,5 +107,6 @@ impl<'a> ListDatabasesBuilder<'a> {
                 }
             },
         )
+        .take(self.max_pages.unwrap_or(usize::MAX))
     }
 }
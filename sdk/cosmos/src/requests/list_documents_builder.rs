@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::resources::ResourceType;
 use crate::responses::ListDocumentsResponse;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use futures::stream::{unfold, Stream, StreamExt};
 use http::StatusCode;
 use serde::de::DeserializeOwned;
 use std::convert::TryInto;
@@ -16,6 +16,7 @@ pub struct ListDocumentsBuilder<'a, 'b> {
     consistency_level: Option<ConsistencyLevel>,
     continuation: Option<Continuation<'b>>,
     max_item_count: MaxItemCount,
+    max_pages: Option<usize>,
     a_im: ChangeFeed,
     partition_range_id: Option<PartitionRangeId<'b>>,
 }
@@ -30,6 +31,7 @@ impl<'a, 'b> ListDocumentsBuilder<'a, 'b> {
             consistency_level: None,
             continuation: None,
             max_item_count: MaxItemCount::new(-1),
+            max_pages: None,
             a_im: ChangeFeed::None,
             partition_range_id: None,
         }
@@ -46,16 +48,19 @@ impl<'a, 'b> ListDocumentsBuilder<'a, 'b> {
         partition_range_id: &'b str => Some(PartitionRangeId::new(partition_range_id)),
     }
 
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
     pub async fn execute<T>(&self) -> Result<ListDocumentsResponse<T>, crate::Error>
     where
         T: DeserializeOwned,
     {
         let req = self.collection_client.cosmos_client().prepare_request(
-            &format!(
-                "dbs/{}/colls/{}/docs",
-                self.collection_client.database_client().database_name(),
-                self.collection_client.collection_name()
-            ),
+            &format!("{}/docs", self.collection_client.path()),
             http::Method::GET,
             ResourceType::Documents,
         );
@@ -124,5 +129,6 @@ impl<'a, 'b> ListDocumentsBuilder<'a, 'b> {
                 }
             },
         )
+        .take(self.max_pages.unwrap_or(usize::MAX))
     }
 }
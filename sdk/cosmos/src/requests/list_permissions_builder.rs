@@ -2,7 +2,7 @@ use crate::prelude::*;
 use crate::resources::ResourceType;
 use crate::responses::ListPermissionsResponse;
 use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
+use futures::stream::{unfold, Stream, StreamExt};
 use http::StatusCode;
 use std::convert::TryInto;
 
@@ -14,6 +14,7 @@ pub struct ListPermissionsBuilder<'a, 'b> {
     consistency_level: Option<ConsistencyLevel>,
     continuation: Option<Continuation<'b>>,
     max_item_count: MaxItemCount,
+    max_pages: Option<usize>,
 }
 
 impl<'a, 'b> ListPermissionsBuilder<'a, 'b> {
@@ -25,6 +26,7 @@ impl<'a, 'b> ListPermissionsBuilder<'a, 'b> {
             consistency_level: None,
             continuation: None,
             max_item_count: MaxItemCount::new(-1),
+            max_pages: None,
         }
     }
 
@@ -36,6 +38,13 @@ impl<'a, 'b> ListPermissionsBuilder<'a, 'b> {
         max_item_count: i32 => MaxItemCount::new(max_item_count),
     }
 
+    pub fn max_pages(self, max_pages: usize) -> Self {
+        Self {
+            max_pages: Some(max_pages),
+            ..self
+        }
+    }
+
     pub async fn execute(&self) -> Result<ListPermissionsResponse<'a>, crate::Error> {
         trace!("ListPermissionsBuilder::execute called");
 
@@ -107,5 +116,6 @@ impl<'a, 'b> ListPermissionsBuilder<'a, 'b> {
                 }
             },
         )
+        .take(self.max_pages.unwrap_or(usize::MAX))
     }
 }
@@ -1,101 +0,0 @@
-use crate::prelude::*;
-use crate::resources::ResourceType;
-use crate::responses::ListUsersResponse;
-use azure_core::prelude::*;
-use futures::stream::{unfold, Stream};
-use http::StatusCode;
-use std::convert::TryInto;
-
-#[derive(Debug, Clone)]
-pub struct ListUsersBuilder<'a, 'b> {
-    database_client: &'a DatabaseClient,
-    user_agent: Option<UserAgent<'b>>,
-    activity_id: Option<ActivityId<'b>>,
-    consistency_level: Option<ConsistencyLevel>,
-    // TODO: use in request
-    continuation: Option<Continuation<'b>>,
-    // TODO: use in request
-    max_item_count: MaxItemCount,
-}
-
-impl<'a, 'b> ListUsersBuilder<'a, 'b> {
-    pub(crate) fn new(database_client: &'a DatabaseClient) -> Self {
-        Self {
-            database_client,
-            user_agent: None,
-            activity_id: None,
-            consistency_level: None,
-            continuation: None,
-            max_item_count: MaxItemCount::new(-1),
-        }
-    }
-
-    setters! {
-        user_agent: &'b str => Some(UserAgent::new(user_agent)),
-        activity_id: &'b str => Some(ActivityId::new(activity_id)),
-        consistency_level: ConsistencyLevel => Some(consistency_level),
-        continuation: &'b str => Some(Continuation::new(continuation)),
-        max_item_count: i32 => MaxItemCount::new(max_item_count),
-    }
-
-    pub async fn execute(&self) -> Result<ListUsersResponse, crate::Error> {
-        trace!("ListUsersBuilder::execute called");
-
-        let req = self.database_client.cosmos_client().prepare_request(
-            &format!("dbs/{}/users", self.database_client.database_name()),
-            http::Method::GET,
-            ResourceType::Users,
-        );
-
-        let req = req.body(bytes::Bytes::from_static(EMPTY_BODY))?;
-        debug!("\nreq == {:?}", req);
-
-        Ok(self
-            .database_client
-            .http_client()
-            .execute_request_check_status(req, StatusCode::OK)
-            .await?
-            .try_into()?)
-    }
-
-    pub fn stream(&self) -> impl Stream<Item = Result<ListUsersResponse, crate::Error>> + '_ {
-        #[derive(Debug, Clone, PartialEq)]
-        enum States {
-            Init,
-            Continuation(String),
-        }
-
-        unfold(
-            Some(States::Init),
-            move |continuation_token: Option<States>| {
-                async move {
-                    debug!("continuation_token == {:?}", &continuation_token);
-                    let response = match continuation_token {
-                        Some(States::Init) => self.execute().await,
-                        Some(States::Continuation(continuation_token)) => {
-                            self.clone()
-                                .continuation(continuation_token.as_str())
-                                .execute()
-                                .await
-                        }
-                        None => return None,
-                    };
-
-                    // the ? operator does not work in async move (yet?)
-                    // so we have to resort to this boilerplate
-                    let response = match response {
-                        Ok(response) => response,
-                        Err(err) => return Some((Err(err), None)),
-                    };
-
-                    let continuation_token = response
-                        .continuation_token
-                        .as_ref()
-                        .map(|ct| States::Continuation(ct.to_owned()));
-
-                    Some((Ok(response), continuation_token))
-                }
-            },
-        )
-    }
-}
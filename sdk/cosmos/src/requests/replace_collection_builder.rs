@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::resources::collection::{IndexingPolicy, PartitionKey};
+use crate::resources::collection::{IndexingPolicy, PartitionKeyDefinition};
 use crate::responses::CreateCollectionResponse;
 use azure_core::prelude::*;
 use http::StatusCode;
@@ -8,7 +8,7 @@ use std::convert::TryInto;
 #[derive(Debug, Clone)]
 pub struct ReplaceCollectionBuilder<'a, 'b> {
     collection_client: &'a CollectionClient,
-    partition_key: Option<PartitionKey>,
+    partition_key: Option<PartitionKeyDefinition>,
     indexing_policy: Option<&'a IndexingPolicy>,
     user_agent: Option<UserAgent<'b>>,
     activity_id: Option<ActivityId<'b>>,
@@ -38,7 +38,7 @@ impl<'a, 'b> ReplaceCollectionBuilder<'a, 'b> {
 }
 
 impl<'a, 'b> ReplaceCollectionBuilder<'a, 'b> {
-    pub async fn execute<P: Into<PartitionKey>>(
+    pub async fn execute<P: Into<PartitionKeyDefinition>>(
         &self,
         partition_key: P,
     ) -> Result<CreateCollectionResponse, crate::Error> {
@@ -60,7 +60,7 @@ impl<'a, 'b> ReplaceCollectionBuilder<'a, 'b> {
             id: &'k str,
             #[serde(skip_serializing_if = "Option::is_none")]
             indexing_policy: Option<&'k IndexingPolicy>,
-            partition_key: PartitionKey,
+            partition_key: PartitionKeyDefinition,
         }
 
         let request = Request {
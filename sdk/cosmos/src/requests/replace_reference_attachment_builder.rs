@@ -54,12 +54,10 @@ impl<'a, 'b> ReplaceReferenceAttachmentBuilder<'a, 'b> {
         req = azure_core::headers::add_optional_header(&self.activity_id, req);
         req = azure_core::headers::add_optional_header(&self.consistency_level, req);
 
-        req = crate::cosmos_entity::add_as_partition_key_header_serialized(
-            self.attachment_client
-                .document_client()
-                .partition_key_serialized(),
-            req,
-        );
+        req = self
+            .attachment_client
+            .document_client()
+            .add_partition_key_header(req);
 
         // create serialized request
         #[derive(Debug, Clone, Serialize)]
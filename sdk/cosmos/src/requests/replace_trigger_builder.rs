@@ -1,24 +1,22 @@
+use crate::operations::CreateTriggerResponse;
 use crate::prelude::*;
 use crate::resources::trigger::*;
-use crate::responses::CreateTriggerResponse;
 use azure_core::prelude::*;
 use http::StatusCode;
 use std::convert::TryInto;
 
 #[derive(Debug, Clone)]
-pub struct CreateOrReplaceTriggerBuilder<'a> {
+pub struct ReplaceTriggerBuilder<'a> {
     trigger_client: &'a TriggerClient,
-    is_create: bool,
     user_agent: Option<UserAgent<'a>>,
     activity_id: Option<ActivityId<'a>>,
     consistency_level: Option<ConsistencyLevel>,
 }
 
-impl<'a> CreateOrReplaceTriggerBuilder<'a> {
-    pub(crate) fn new(trigger_client: &'a TriggerClient, is_create: bool) -> Self {
+impl<'a> ReplaceTriggerBuilder<'a> {
+    pub(crate) fn new(trigger_client: &'a TriggerClient) -> Self {
         Self {
             trigger_client,
-            is_create,
             user_agent: None,
             activity_id: None,
             consistency_level: None,
@@ -26,7 +24,7 @@ impl<'a> CreateOrReplaceTriggerBuilder<'a> {
     }
 }
 
-impl<'a> CreateOrReplaceTriggerBuilder<'a> {
+impl<'a> ReplaceTriggerBuilder<'a> {
     setters! {
         user_agent: &'a str => Some(UserAgent::new(user_agent)),
         activity_id: &'a str => Some(ActivityId::new(activity_id)),
@@ -34,7 +32,7 @@ impl<'a> CreateOrReplaceTriggerBuilder<'a> {
     }
 }
 
-impl<'a> CreateOrReplaceTriggerBuilder<'a> {
+impl<'a> ReplaceTriggerBuilder<'a> {
     pub async fn execute<B, T, O>(
         &self,
         body: B,
@@ -46,14 +44,11 @@ impl<'a> CreateOrReplaceTriggerBuilder<'a> {
         T: Into<TriggerType>,
         O: Into<TriggerOperation>,
     {
-        trace!("CreateOrReplaceTriggerBuilder::execute called");
+        trace!("ReplaceTriggerBuilder::execute called");
 
-        let req = self.trigger_client;
-        let req = if self.is_create {
-            req.prepare_request(http::Method::POST)
-        } else {
-            req.prepare_request_with_trigger_name(http::Method::PUT)
-        };
+        let req = self
+            .trigger_client
+            .prepare_request_with_trigger_name(http::Method::PUT);
 
         let req = azure_core::headers::add_optional_header(&self.user_agent, req);
         let req = azure_core::headers::add_optional_header(&self.activity_id, req);
@@ -81,17 +76,10 @@ impl<'a> CreateOrReplaceTriggerBuilder<'a> {
         let request = azure_core::to_json(&request)?;
         let request = req.body(request)?;
 
-        let expected_status = if self.is_create {
-            StatusCode::CREATED
-        } else {
-            StatusCode::OK
-        };
-
-        Ok(self
-            .trigger_client
+        self.trigger_client
             .http_client()
-            .execute_request_check_status(request, expected_status)
+            .execute_request_check_status(request, StatusCode::OK)
             .await?
-            .try_into()?)
+            .try_into()
     }
 }
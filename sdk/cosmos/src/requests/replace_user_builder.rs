@@ -1,5 +1,5 @@
+use crate::operations::CreateUserResponse;
 use crate::prelude::*;
-use crate::responses::CreateUserResponse;
 use azure_core::prelude::*;
 use http::StatusCode;
 use std::convert::TryInto;
@@ -1,21 +1,21 @@
+use crate::operations::CreateUserDefinedFunctionResponse;
 use crate::prelude::*;
-use crate::responses::CreateStoredProcedureResponse;
 use azure_core::prelude::*;
 use http::StatusCode;
 use std::convert::TryInto;
 
 #[derive(Debug, Clone)]
-pub struct CreateStoredProcedureBuilder<'a, 'b> {
-    stored_procedure_client: &'a StoredProcedureClient,
+pub struct ReplaceUserDefinedFunctionBuilder<'a, 'b> {
+    user_defined_function_client: &'a UserDefinedFunctionClient,
     user_agent: Option<UserAgent<'b>>,
     activity_id: Option<ActivityId<'b>>,
     consistency_level: Option<ConsistencyLevel>,
 }
 
-impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
-    pub(crate) fn new(stored_procedure_client: &'a StoredProcedureClient) -> Self {
+impl<'a, 'b> ReplaceUserDefinedFunctionBuilder<'a, 'b> {
+    pub(crate) fn new(user_defined_function_client: &'a UserDefinedFunctionClient) -> Self {
         Self {
-            stored_procedure_client,
+            user_defined_function_client,
             user_agent: None,
             activity_id: None,
             consistency_level: None,
@@ -23,7 +23,7 @@ impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
     }
 }
 
-impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
+impl<'a, 'b> ReplaceUserDefinedFunctionBuilder<'a, 'b> {
     setters! {
         user_agent: &'b str => Some(UserAgent::new(user_agent)),
         activity_id: &'b str => Some(ActivityId::new(activity_id)),
@@ -31,17 +31,19 @@ impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
     }
 }
 
-impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
+impl<'a, 'b> ReplaceUserDefinedFunctionBuilder<'a, 'b> {
     pub async fn execute<B: AsRef<str>>(
         &self,
         body: B,
-    ) -> Result<CreateStoredProcedureResponse, crate::Error> {
-        trace!("CreateStoredProcedureBuilder::execute called");
+    ) -> Result<CreateUserDefinedFunctionResponse, crate::Error> {
+        trace!("ReplaceUserDefinedFunctionBuilder::execute called");
 
+        // See: https://docs.microsoft.com/rest/api/cosmos-db/replace-a-user-defined-function
         let req = self
-            .stored_procedure_client
-            .prepare_request(http::Method::POST);
+            .user_defined_function_client
+            .prepare_request_with_user_defined_function_name(http::Method::PUT);
 
+        // add trait headers
         let req = azure_core::headers::add_optional_header(&self.user_agent, req);
         let req = azure_core::headers::add_optional_header(&self.activity_id, req);
         let req = azure_core::headers::add_optional_header(&self.consistency_level, req);
@@ -55,17 +57,19 @@ impl<'a, 'b> CreateStoredProcedureBuilder<'a, 'b> {
         }
         let request = Request {
             body: body.as_ref(),
-            id: self.stored_procedure_client.stored_procedure_name(),
+            id: self
+                .user_defined_function_client
+                .user_defined_function_name(),
         };
 
         let request = azure_core::to_json(&request)?;
         let request = req.body(request)?;
 
-        Ok(self
-            .stored_procedure_client
+        let result = self
+            .user_defined_function_client
             .http_client()
-            .execute_request_check_status(request, StatusCode::CREATED)
-            .await?
-            .try_into()?)
+            .execute_request_check_status(request, StatusCode::OK)
+            .await?;
+        result.try_into()
     }
 }
@@ -101,6 +101,49 @@ pub(crate) fn resource_quotas_from_str(
     Ok(v)
 }
 
+/// The resources of a collection, as reported when reading it with
+/// `populate_quota_info(true)`
+///
+/// The same type describes both the limits (`x-ms-resource-quota`) and the current usage
+/// (`x-ms-resource-usage`); a resource missing from the header is `None`. Sizes are in KB and a
+/// `documents_count` of `-1` means there is no limit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CollectionQuota {
+    /// Stored procedures
+    pub stored_procedures: Option<u64>,
+    /// Triggers
+    pub triggers: Option<u64>,
+    /// User defined functions
+    pub functions: Option<u64>,
+    /// Size of a single document
+    pub document_size_kb: Option<u64>,
+    /// Size of all the documents
+    pub documents_size_kb: Option<u64>,
+    /// Number of documents
+    pub documents_count: Option<i64>,
+    /// Size of the collection, documents and indexes
+    pub collection_size_kb: Option<u64>,
+}
+
+impl From<&[ResourceQuota]> for CollectionQuota {
+    fn from(resource_quotas: &[ResourceQuota]) -> Self {
+        let mut collection_quota = Self::default();
+        for resource_quota in resource_quotas {
+            match *resource_quota {
+                ResourceQuota::StoredProcedures(n) => collection_quota.stored_procedures = Some(n),
+                ResourceQuota::Triggers(n) => collection_quota.triggers = Some(n),
+                ResourceQuota::Functions(n) => collection_quota.functions = Some(n),
+                ResourceQuota::DocumentSize(n) => collection_quota.document_size_kb = Some(n),
+                ResourceQuota::DocumentsSize(n) => collection_quota.documents_size_kb = Some(n),
+                ResourceQuota::DocumentsCount(n) => collection_quota.documents_count = Some(n),
+                ResourceQuota::CollectionSize(n) => collection_quota.collection_size_kb = Some(n),
+                _ => {}
+            }
+        }
+        collection_quota
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ResourceQuotaParsingError {
     #[error(
@@ -179,4 +222,33 @@ mod tests {
             vec![ResourceQuota::ClientEncryptionKeys(13)]
         );
     }
+
+    #[test]
+    fn collection_quota_from_quota_info_headers() {
+        let quota = resource_quotas_from_str(
+            "functions=25;storedProcedures=100;triggers=25;documentSize=10240;documentsSize=10485760;documentsCount=-1;collectionSize=10485760;",
+        )
+        .unwrap();
+        assert_eq!(
+            CollectionQuota::from(quota.as_slice()),
+            CollectionQuota {
+                stored_procedures: Some(100),
+                triggers: Some(25),
+                functions: Some(25),
+                document_size_kb: Some(10240),
+                documents_size_kb: Some(10485760),
+                documents_count: Some(-1),
+                collection_size_kb: Some(10485760),
+            }
+        );
+
+        let usage = resource_quotas_from_str(
+            "functions=0;storedProcedures=2;triggers=0;documentSize=1;documentsSize=187;documentsCount=42;",
+        )
+        .unwrap();
+        let usage = CollectionQuota::from(usage.as_slice());
+        assert_eq!(usage.documents_count, Some(42));
+        assert_eq!(usage.documents_size_kb, Some(187));
+        assert_eq!(usage.collection_size_kb, None);
+    }
 }
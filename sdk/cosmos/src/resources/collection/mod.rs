@@ -1,9 +1,11 @@
 //! Utilities for interacting with [`Collection`]s.
 
 mod offer;
+mod partition_key_range;
 
 use super::Resource;
 pub use offer::Offer;
+pub use partition_key_range::PartitionKeyRange;
 
 /// A container of JSON documents and associated JavaScript application logic.
 ///
@@ -17,7 +19,15 @@ pub struct Collection {
     pub indexing_policy: IndexingPolicy,
     /// The partition key
     #[serde(rename = "partitionKey")]
-    pub parition_key: PartitionKey,
+    pub parition_key: PartitionKeyDefinition,
+    /// The time to live of the documents in seconds, `-1` if they only expire with a `ttl` of
+    /// their own, or `None` if they never expire
+    #[serde(
+        rename = "defaultTtl",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_ttl: Option<i32>,
     /// The resource id
     #[serde(rename = "_rid")]
     pub rid: String,
@@ -127,36 +137,142 @@ impl From<String> for ExcludedPath {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
 /// The partitioning configuration settings for collection
-pub struct PartitionKey {
+///
+/// A single path such as `"/id"` converts into a [`PartitionKind::Hash`] definition, while
+/// [`PartitionKeyDefinition::hierarchical`] partitions on up to three paths.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct PartitionKeyDefinition {
     /// An array of paths using which data within the collection can be partitioned
-    pub paths: Vec<String>,
+    pub paths: Vec<PartitionKeyPath>,
     /// The algorithm used for partitioning
-    pub kind: KeyKind,
+    pub kind: PartitionKind,
+    /// The version of the hash function: 2 supports partition key values longer than 100 bytes
+    #[serde(default = "PartitionKeyDefinition::default_version")]
+    pub version: u8,
 }
 
-impl std::default::Default for PartitionKey {
-    fn default() -> Self {
+impl PartitionKeyDefinition {
+    /// A hierarchical partition key, whose levels are the `paths` from the top one down
+    pub fn hierarchical<I, S>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
         Self {
-            paths: vec![],
-            kind: KeyKind::Hash,
+            paths: paths
+                .into_iter()
+                .map(|path| PartitionKeyPath(path.into()))
+                .collect(),
+            kind: PartitionKind::MultiHash,
+            version: 2,
+        }
+    }
+
+    fn default_version() -> u8 {
+        1
+    }
+
+    /// Checks that there is at least one path and that every path is a valid
+    /// [`PartitionKeyPath`], as `/address/zipCode`
+    pub(crate) fn validate(&self) -> Result<(), crate::Error> {
+        if self.paths.is_empty() {
+            return Err(crate::Error::InvalidPartitionKeyDefinition {
+                reason: "no path is given".to_owned(),
+            });
         }
+        for path in &self.paths {
+            path.as_str().parse::<PartitionKeyPath>()?;
+        }
+        Ok(())
+    }
+}
+
+/// A valid partition key path, such as `/tenantId` or `/address/zipCode`
+///
+/// The path starts with `/` and its segments are not empty. A segment is either a property name
+/// without whitespace nor any of `"'\?#*[]`, or any name in double quotes, as `/"tenant id"`. A
+/// `PartitionKeyPath` converts into a [`PartitionKind::Hash`] definition.
+///
+/// Only parsing a path checks it. The paths deserialized from Cosmos DB are kept as they are, so
+/// that a collection is never rejected for a path this crate does not know, and the paths of a
+/// definition built from strings are checked when the collection is created.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PartitionKeyPath(String);
+
+impl PartitionKeyPath {
+    /// The path, as `/tenantId`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for PartitionKeyPath {
+    type Err = crate::Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| crate::Error::InvalidPartitionKeyDefinition {
+            reason: format!("the path {:?} {}", path, reason),
+        };
+        let segments = path
+            .strip_prefix('/')
+            .ok_or_else(|| invalid("does not start with '/'"))?;
+        for segment in segments.split('/') {
+            let is_quoted = segment.len() >= 2
+                && segment.starts_with('"')
+                && segment.ends_with('"')
+                && !segment[1..segment.len() - 1].contains('"');
+            if segment.is_empty() {
+                return Err(invalid("has an empty segment"));
+            } else if !is_quoted
+                && segment
+                    .chars()
+                    .any(|c| c.is_whitespace() || "\"'\\?#*[]".contains(c))
+            {
+                return Err(invalid("has a segment with an invalid character"));
+            }
+        }
+        Ok(Self(path.to_owned()))
+    }
+}
+
+impl AsRef<str> for PartitionKeyPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PartitionKeyPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
-impl<T> From<T> for PartitionKey
+impl<T> From<T> for PartitionKeyDefinition
 where
     T: AsRef<str>,
 {
     fn from(t: T) -> Self {
         Self {
-            paths: vec![t.as_ref().to_owned()],
-            kind: KeyKind::Hash,
+            paths: vec![PartitionKeyPath(t.as_ref().to_owned())],
+            kind: PartitionKind::Hash,
+            version: Self::default_version(),
         }
     }
 }
 
+/// The algorithm used to spread the documents of a collection across its partitions
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, PartialEq)]
+pub enum PartitionKind {
+    /// The documents are spread by the hash of their partition key value
+    Hash,
+    /// The documents are spread by ranges of their partition key value
+    Range,
+    /// The documents are spread by the hash of each level of a hierarchical partition key
+    MultiHash,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
 /// The indexing policy for a collection
 #[serde(rename_all = "camelCase")]
@@ -169,4 +285,64 @@ pub struct IndexingPolicy {
     pub included_paths: Vec<IncludedPath>,
     /// Array containing document paths to be excluded from indexing
     pub excluded_paths: Vec<ExcludedPath>,
+    /// Indexes on several paths at once, each being the paths in the order they are sorted by
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub composite_indexes: Vec<Vec<CompositePath>>,
+}
+
+/// A path of a composite index
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct CompositePath {
+    /// The indexed path, such as `/name`
+    pub path: String,
+    /// The order the path is sorted in
+    pub order: CompositePathOrder,
+}
+
+/// The order a path of a composite index is sorted in
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CompositePathOrder {
+    /// Ascending order, used for `ORDER BY ... ASC`
+    Ascending,
+    /// Descending order, used for `ORDER BY ... DESC`
+    Descending,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_key_paths_are_validated() {
+        let path = "/tenantId".parse::<PartitionKeyPath>().unwrap();
+        assert_eq!(path.as_str(), "/tenantId");
+        assert_eq!(PartitionKeyDefinition::from(path.clone()).paths, vec![path]);
+        assert!("/a/b".parse::<PartitionKeyPath>().is_ok());
+        assert!("/\"tenant id\"".parse::<PartitionKeyPath>().is_ok());
+
+        for invalid in &["tenantId", "", "/", "/a//b", "/a/", "/tenant id", "/a?b"] {
+            assert!(
+                matches!(
+                    invalid.parse::<PartitionKeyPath>(),
+                    Err(crate::Error::InvalidPartitionKeyDefinition { .. })
+                ),
+                "{:?} is not a valid path",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn partition_key_paths_are_read_as_they_are() {
+        let json = serde_json::json!({ "paths": ["/tenantId", "tenant id"], "kind": "Hash" });
+        let definition: PartitionKeyDefinition = serde_json::from_value(json).unwrap();
+        assert_eq!(definition.paths[0], "/tenantId".parse().unwrap());
+        assert_eq!(definition.paths[1].as_str(), "tenant id");
+        assert_eq!(
+            serde_json::to_value(&definition).unwrap()["paths"],
+            serde_json::json!(["/tenantId", "tenant id"])
+        );
+        assert!(definition.validate().is_err());
+    }
 }
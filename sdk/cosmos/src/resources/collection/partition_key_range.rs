@@ -0,0 +1,41 @@
+/// A range of the hashes of partition keys, served by one physical partition of a collection.
+///
+/// The ranges of a collection are contiguous, from the empty string to `FF`. A range is split in
+/// two when its partition grows, so a change-feed or parallel-query consumer should list them
+/// again when one of them disappears.
+///
+/// You can learn more about partition key ranges [here](https://docs.microsoft.com/rest/api/cosmos-db/get-partition-key-ranges).
+#[derive(Debug, PartialEq, PartialOrd, Clone, Deserialize)]
+pub struct PartitionKeyRange {
+    /// The range id, used to address the range in change-feed and query requests
+    pub id: String,
+    /// The smallest hash in the range
+    #[serde(rename = "minInclusive")]
+    pub min_inclusive: String,
+    /// The first hash after the range
+    #[serde(rename = "maxExclusive")]
+    pub max_exclusive: String,
+    /// The resource id
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    /// The resource's etag used for concurrency control
+    #[serde(rename = "_etag")]
+    pub etag: String,
+    /// The prefix of the resource ids of the documents in the range
+    #[serde(rename = "ridPrefix")]
+    pub rid_prefix: u64,
+    /// The resource's uri
+    #[serde(rename = "_self")]
+    pub _self: String,
+    /// The share of the collection throughput the range gets
+    #[serde(rename = "throughputFraction")]
+    pub throughput_fraction: f64,
+    /// The status of the range, such as `online`
+    pub status: String,
+    /// The ids of the ranges this one was split from
+    #[serde(default)]
+    pub parents: Vec<String>,
+    /// The last updated timestamp
+    #[serde(rename = "_ts")]
+    pub ts: u64,
+}
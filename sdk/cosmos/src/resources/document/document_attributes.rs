@@ -47,7 +47,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for DocumentAttributes {
     type Error = crate::Error;
 
     fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
-        Ok(serde_json::from_slice(response.body())?)
+        Ok(crate::json::from_json(response.body())?)
     }
 }
 
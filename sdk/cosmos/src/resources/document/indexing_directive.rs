@@ -1,6 +1,7 @@
 use crate::headers;
 use azure_core::ParsingError;
 use http::request::Builder;
+use http::HeaderValue;
 use std::fmt;
 
 /// Whether the resource should be included in the index.
@@ -58,4 +59,14 @@ impl azure_core::AddAsHeader for IndexingDirective {
             }
         }
     }
+
+    fn add_as_header2(&self, request: &mut azure_core::Request) -> Result<(), http::Error> {
+        if *self != IndexingDirective::Default {
+            request.headers_mut().insert(
+                headers::HEADER_INDEXING_DIRECTIVE,
+                HeaderValue::from_static(self.into()),
+            );
+        }
+        Ok(())
+    }
 }
@@ -19,6 +19,9 @@ use serde::de::DeserializeOwned;
 /// User-defined content in JSON format.
 ///
 /// You can learn more about Documents [here](https://docs.microsoft.com/rest/api/cosmos-db/documents).
+///
+/// In a collection with a default time to live, a document can override it with a `ttl` field of
+/// `T`: a number of seconds after its last write, or `-1` to never expire.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct Document<T> {
@@ -49,7 +52,7 @@ where
         let _headers = value.0;
         let body = value.1;
 
-        Ok(serde_json::from_slice(body)?)
+        Ok(crate::json::from_json(body)?)
     }
 }
 
@@ -126,7 +129,7 @@ pub enum IsUpsert {
 }
 
 impl IsUpsert {
-    fn as_bool_str(&self) -> &str {
+    fn as_bool_str(&self) -> &'static str {
         match self {
             Self::Yes => "true",
             Self::No => "false",
@@ -138,6 +141,14 @@ impl AddAsHeader for IsUpsert {
     fn add_as_header(&self, builder: Builder) -> Builder {
         builder.header(headers::HEADER_DOCUMENTDB_IS_UPSERT, self.as_bool_str())
     }
+
+    fn add_as_header2(&self, request: &mut azure_core::Request) -> Result<(), http::Error> {
+        request.headers_mut().insert(
+            headers::HEADER_DOCUMENTDB_IS_UPSERT,
+            http::HeaderValue::from_static(self.as_bool_str()),
+        );
+        Ok(())
+    }
 }
 
 /// Whether to use an incremental change feed
@@ -4,12 +4,14 @@
 
 pub mod collection;
 pub mod document;
+pub mod offer;
 pub mod permission;
 pub mod stored_procedure;
 pub mod trigger;
 
 mod attachment;
 mod database;
+mod system_properties;
 mod user;
 mod user_defined_function;
 
@@ -26,6 +28,8 @@ pub use permission::Permission;
 #[doc(inline)]
 pub use stored_procedure::StoredProcedure;
 #[doc(inline)]
+pub use system_properties::CosmosSystemProperties;
+#[doc(inline)]
 pub use trigger::Trigger;
 #[doc(inline)]
 pub use user::User;
@@ -50,16 +54,96 @@ pub trait Resource {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) enum ResourceType {
+/// The maximum length, in characters, of a resource name.
+pub const MAX_RESOURCE_NAME_LENGTH: usize = 255;
+
+/// Checks a database, collection or other resource name against the Cosmos DB naming rules.
+///
+/// A name must not be empty, must not contain `/`, `\`, `#` or `?`, must not end with a space
+/// and must be at most [`MAX_RESOURCE_NAME_LENGTH`] characters long. Names breaking these rules
+/// would otherwise be rejected by the service with a `400 Bad Request` or, as they end up in
+/// the request URI, produce a signature the service cannot verify.
+pub fn validate_resource_name(name: &str) -> Result<(), crate::Error> {
+    let reason = if name.is_empty() {
+        "the name is empty".to_owned()
+    } else if let Some(c) = name.chars().find(|c| matches!(c, '/' | '\\' | '#' | '?')) {
+        format!("the name contains the forbidden character {:?}", c)
+    } else if name.ends_with(' ') {
+        "the name ends with a space".to_owned()
+    } else if name.chars().count() > MAX_RESOURCE_NAME_LENGTH {
+        format!(
+            "the name is longer than {} characters",
+            MAX_RESOURCE_NAME_LENGTH
+        )
+    } else {
+        return Ok(());
+    };
+    Err(crate::Error::InvalidResourceName { reason })
+}
+
+/// The kind of resource a request is about, as signed in its `Authorization` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceType {
+    /// Databases (`dbs`)
     Databases,
+    /// Collections (`colls`)
     Collections,
+    /// Documents (`docs`)
     Documents,
+    /// Stored procedures (`sprocs`)
     StoredProcedures,
+    /// Users (`users`)
     Users,
+    /// Permissions (`permissions`)
     Permissions,
+    /// Attachments (`attachments`)
     Attachments,
+    /// Partition key ranges (`pkranges`)
     PartitionKeyRanges,
+    /// User defined functions (`udfs`)
     UserDefinedFunctions,
+    /// Triggers (`triggers`)
     Triggers,
+    /// Offers (`offers`)
+    Offers,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(name: &str) -> String {
+        match validate_resource_name(name) {
+            Err(crate::Error::InvalidResourceName { reason }) => reason,
+            other => panic!("{:?} should be invalid, got {:?}", name, other),
+        }
+    }
+
+    #[test]
+    fn valid_resource_names() {
+        for name in &["ToDoList", "my db", "αβγ-1_2.3", &"a".repeat(255)] {
+            assert!(
+                validate_resource_name(name).is_ok(),
+                "{:?} should be valid",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_resource_names() {
+        assert_eq!(
+            reason("dbs/other"),
+            "the name contains the forbidden character '/'"
+        );
+        assert_eq!(reason("trailing "), "the name ends with a space");
+        assert_eq!(
+            reason(&"a".repeat(256)),
+            "the name is longer than 255 characters"
+        );
+        assert_eq!(reason(""), "the name is empty");
+        for name in &["back\\slash", "hash#tag", "what?"] {
+            reason(name);
+        }
+    }
 }
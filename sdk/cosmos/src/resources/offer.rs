@@ -0,0 +1,68 @@
+//! Utilities for interacting with [`Offer`]s.
+//!
+//! Not to be confused with [`crate::resources::collection::Offer`], the throughput requested
+//! when creating a collection.
+
+/// The throughput provisioned for a collection, or shared by the collections of a database.
+///
+/// Offers are account-level resources: the resource they provision is the one whose `_self`
+/// link is their `resource`.
+///
+/// You can learn more about Offers [here](https://docs.microsoft.com/rest/api/cosmos-db/offers).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Offer {
+    /// The offer id
+    pub id: String,
+    /// The resource id
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    /// The resource's uri
+    #[serde(rename = "_self")]
+    pub _self: String,
+    /// The resource's etag used for concurrency control
+    #[serde(rename = "_etag")]
+    pub etag: String,
+    /// The last updated timestamp
+    #[serde(rename = "_ts")]
+    pub ts: u64,
+    /// The `_self` link of the collection or database the throughput is provisioned for
+    pub resource: String,
+    /// The `_rid` of the collection or database the throughput is provisioned for
+    #[serde(rename = "offerResourceId")]
+    pub offer_resource_id: String,
+    /// The offer version, `V2` for throughput in request units
+    #[serde(rename = "offerVersion")]
+    pub offer_version: String,
+    /// The performance level of `V1` offers, such as `S1`, `Invalid` for `V2` offers
+    #[serde(rename = "offerType")]
+    pub offer_type: String,
+    /// The provisioned throughput
+    pub content: OfferContent,
+}
+
+impl Offer {
+    /// Whether the throughput scales on its own, up to
+    /// [`AutoscaleSettings::max_throughput`]
+    pub fn is_autoscale(&self) -> bool {
+        self.content.autoscale_settings.is_some()
+    }
+}
+
+/// The throughput of an [`Offer`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OfferContent {
+    /// The request units per second currently provisioned
+    #[serde(rename = "offerThroughput", default)]
+    pub offer_throughput: Option<u64>,
+    /// The settings of autoscale throughput, `None` for manual throughput
+    #[serde(rename = "offerAutopilotSettings", default)]
+    pub autoscale_settings: Option<AutoscaleSettings>,
+}
+
+/// How far autoscale throughput may scale.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AutoscaleSettings {
+    /// The request units per second the throughput scales up to, from a tenth of it
+    #[serde(rename = "maxThroughput")]
+    pub max_throughput: u64,
+}
@@ -27,6 +27,51 @@ impl AuthorizationToken {
     pub fn new_resource(resource: String) -> AuthorizationToken {
         AuthorizationToken::Resource(resource)
     }
+
+    /// The kind of the token, which unlike the token itself is safe to log
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            AuthorizationToken::Primary(_) => TokenKind::Primary,
+            AuthorizationToken::Resource(_) => TokenKind::Resource,
+        }
+    }
+
+    /// Whether this is a primary (master key) token
+    pub fn is_primary(&self) -> bool {
+        self.kind() == TokenKind::Primary
+    }
+
+    /// Whether this is a resource token
+    pub fn is_resource(&self) -> bool {
+        self.kind() == TokenKind::Resource
+    }
+
+    /// An identifier of the token which is safe to log, such as `Primary(sha256=3f9a0c1b)`
+    ///
+    /// It is the kind of the token and the first bytes of the SHA-256 of its secret: the same
+    /// secret always gives the same identifier, from which the secret cannot be recovered.
+    pub fn redacted(&self) -> String {
+        let secret = match self {
+            AuthorizationToken::Primary(key) => key.as_slice(),
+            AuthorizationToken::Resource(resource) => resource.as_bytes(),
+        };
+        let digest = ring::digest::digest(&ring::digest::SHA256, secret);
+        let fingerprint: String = digest.as_ref()[..4]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("{:?}(sha256={})", self.kind(), fingerprint)
+    }
+}
+
+/// The kind of an [`AuthorizationToken`], without its secret
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A master key of the account
+    Primary,
+    /// A resource token, usually obtained from a permission
+    Resource,
 }
 
 #[allow(missing_docs)]
@@ -51,6 +96,23 @@ impl fmt::Debug for AuthorizationToken {
     }
 }
 
+/// A primary token from the raw bytes of a master key
+impl std::convert::From<Vec<u8>> for AuthorizationToken {
+    fn from(key: Vec<u8>) -> Self {
+        AuthorizationToken::Primary(key)
+    }
+}
+
+/// A primary token from a base64 encoded master key, as shown in the Azure portal: see
+/// [`primary_from_base64`](AuthorizationToken::primary_from_base64)
+impl std::convert::TryFrom<&str> for AuthorizationToken {
+    type Error = AuthorizationTokenParsingError;
+
+    fn try_from(base64_encoded: &str) -> Result<Self, Self::Error> {
+        AuthorizationToken::primary_from_base64(base64_encoded)
+    }
+}
+
 impl std::convert::From<PermissionToken> for AuthorizationToken {
     fn from(permission_token: PermissionToken) -> Self {
         trace!(
@@ -60,3 +122,52 @@ impl std::convert::From<PermissionToken> for AuthorizationToken {
         permission_token.token
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn conversions_make_primary_tokens() {
+        let token = AuthorizationToken::try_from("bWFzdGVyIGtleQ==").unwrap();
+        assert_eq!(token, AuthorizationToken::Primary(b"master key".to_vec()));
+        assert_eq!(
+            AuthorizationToken::from(b"master key".to_vec()),
+            AuthorizationToken::primary_from_base64("bWFzdGVyIGtleQ==").unwrap()
+        );
+
+        assert!(matches!(
+            AuthorizationToken::try_from("not base64!"),
+            Err(AuthorizationTokenParsingError::InvalidBase64Encoding(_))
+        ));
+    }
+
+    #[test]
+    fn kind_of_each_token() {
+        let primary = AuthorizationToken::primary_from_base64("bWFzdGVyIGtleQ==").unwrap();
+        assert_eq!(primary.kind(), TokenKind::Primary);
+        assert!(primary.is_primary());
+        assert!(!primary.is_resource());
+
+        let resource = AuthorizationToken::new_resource("type=resource&ver=1&sig=c2ln".to_owned());
+        assert_eq!(resource.kind(), TokenKind::Resource);
+        assert!(resource.is_resource());
+        assert!(!resource.is_primary());
+    }
+
+    #[test]
+    fn redacted_ids_depend_on_the_secret_only() {
+        let primary = AuthorizationToken::primary_from_base64("bWFzdGVyIGtleQ==").unwrap();
+        let same = AuthorizationToken::primary_from_base64("bWFzdGVyIGtleQ==").unwrap();
+        let other = AuthorizationToken::primary_from_base64("b3RoZXIga2V5").unwrap();
+
+        assert_eq!(primary.redacted(), same.redacted());
+        assert_ne!(primary.redacted(), other.redacted());
+        assert!(primary.redacted().starts_with("Primary(sha256="));
+        assert!(!primary.redacted().contains("bWFzdGVyIGtleQ"));
+
+        let resource = AuthorizationToken::new_resource("type=resource&ver=1&sig=c2ln".to_owned());
+        assert!(resource.redacted().starts_with("Resource(sha256="));
+    }
+}
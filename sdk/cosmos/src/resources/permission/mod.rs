@@ -7,12 +7,14 @@ mod permission_token;
 
 pub use authorization_token::AuthorizationToken;
 pub use authorization_token::AuthorizationTokenParsingError;
+pub use authorization_token::TokenKind;
 pub use permission::{Permission, PermissionMode};
 pub use permission_token::PermissionToken;
 pub use permission_token::PermissionTokenParsingError;
 
 use crate::headers;
 use azure_core::AddAsHeader;
+use chrono::{DateTime, Utc};
 use http::request::Builder;
 
 /// The amount of time before authorization expires
@@ -24,6 +26,11 @@ impl ExpirySeconds {
     pub fn new(secs: u64) -> Self {
         Self(secs)
     }
+
+    /// The time a token issued at `issued_on` expires
+    pub(crate) fn expires_on(&self, issued_on: DateTime<Utc>) -> DateTime<Utc> {
+        issued_on + chrono::Duration::seconds(self.0 as i64)
+    }
 }
 
 impl AddAsHeader for ExpirySeconds {
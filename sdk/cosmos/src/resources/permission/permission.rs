@@ -123,4 +123,23 @@ mod tests {
             PermissionMode::Read(r#"dbs/volcanodb/colls/volcano1"#.into())
         );
     }
+
+    #[test]
+    fn serialize_permission_mode() {
+        let permission_mode = PermissionMode::Read("dbs/volcanodb/colls/volcano1".into());
+
+        assert_eq!(permission_mode.kind(), "Read");
+        assert_eq!(
+            serde_json::to_value(&permission_mode).unwrap(),
+            serde_json::json!({
+                "permissionMode": "Read",
+                "resource": "dbs/volcanodb/colls/volcano1",
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(PermissionMode::All("dbs/volcanodb".into())).unwrap()
+                ["permissionMode"],
+            "All"
+        );
+    }
 }
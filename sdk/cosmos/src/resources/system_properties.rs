@@ -0,0 +1,96 @@
+//! The system properties shared by every Cosmos resource.
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::de::Error as _;
+use serde::{Deserializer, Serializer};
+
+/// The `_rid`, `_self`, `_ts` and `_etag` properties the service adds to every resource.
+///
+/// Resource types can embed it with `#[serde(flatten)]`; it can also be deserialized on its own
+/// from the body of any resource, ignoring its other properties.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Debug)]
+pub struct CosmosSystemProperties {
+    #[serde(rename = "_rid")]
+    rid: String,
+    #[serde(rename = "_self")]
+    self_link: String,
+    #[serde(
+        rename = "_ts",
+        serialize_with = "serialize_ts",
+        deserialize_with = "deserialize_ts"
+    )]
+    timestamp: DateTime<Utc>,
+    #[serde(rename = "_etag")]
+    etag: String,
+}
+
+impl CosmosSystemProperties {
+    /// The resource id, unique and stable across renames.
+    pub fn rid(&self) -> &str {
+        &self.rid
+    }
+
+    /// The addressable link of the resource, such as `dbs/JHwXAA==/`.
+    ///
+    /// The links of the sub-resources (collections, users, ...) are relative to it.
+    pub fn self_link(&self) -> &str {
+        &self.self_link
+    }
+
+    /// When the resource was last updated, with a one second precision.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// The entity tag of the resource, used for optimistic concurrency control.
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+}
+
+fn serialize_ts<S: Serializer>(
+    timestamp: &DateTime<Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(timestamp.timestamp())
+}
+
+fn deserialize_ts<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    let ts: i64 = serde::Deserialize::deserialize(deserializer)?;
+    Utc.timestamp_opt(ts, 0)
+        .single()
+        .ok_or_else(|| D::Error::custom(format!("invalid _ts {}", ts)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_properties_of_a_database() {
+        let database = r#"{
+            "id": "ToDoList",
+            "_rid": "JHwXAA==",
+            "_self": "dbs/JHwXAA==/",
+            "_etag": "\"00000d01-0000-0d00-0000-5e1f9d200000\"",
+            "_colls": "colls/",
+            "_users": "users/",
+            "_ts": 1579130144
+        }"#;
+
+        let properties: CosmosSystemProperties = serde_json::from_str(database).unwrap();
+        assert_eq!(properties.rid(), "JHwXAA==");
+        assert_eq!(properties.self_link(), "dbs/JHwXAA==/");
+        assert_eq!(
+            properties.timestamp(),
+            DateTime::parse_from_rfc3339("2020-01-15T23:15:44Z").unwrap()
+        );
+        assert_eq!(
+            properties.etag(),
+            "\"00000d01-0000-0d00-0000-5e1f9d200000\""
+        );
+
+        let serialized = serde_json::to_value(&properties).unwrap();
+        assert_eq!(serialized["_ts"], 1579130144);
+    }
+}
@@ -34,7 +34,7 @@ pub struct User {
 impl std::convert::TryFrom<&[u8]> for User {
     type Error = serde_json::Error;
     fn try_from(body: &[u8]) -> Result<Self, Self::Error> {
-        serde_json::from_slice(body)
+        crate::json::from_json(body)
     }
 }
 
@@ -29,7 +29,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for CreateCollectionResponse
         let body = response.body();
 
         Ok(Self {
-            collection: serde_json::from_slice(body)?,
+            collection: crate::json::from_json(body)?,
             charge: request_charge_from_headers(headers)?,
             activity_id: activity_id_from_headers(headers)?,
             etag: etag_from_headers(headers)?,
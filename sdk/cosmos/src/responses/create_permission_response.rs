@@ -1,6 +1,8 @@
 use crate::headers::from_headers::*;
+use crate::resources::permission::ExpirySeconds;
 use crate::resources::Permission;
-use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use azure_core::headers::{date_from_headers, etag_from_headers, session_token_from_headers};
+use chrono::{DateTime, Utc};
 use http::response::Response;
 use std::convert::TryInto;
 
@@ -13,14 +15,22 @@ pub struct CreatePermissionResponse<'a> {
     pub session_token: String,
     pub content_path: String,
     pub alt_content_path: String,
+    /// The time the token of the permission expires: its expiry seconds after the `Date` of the
+    /// response, or after it is received without one.
+    pub expires_on: DateTime<Utc>,
 }
 
-impl<'a> std::convert::TryFrom<Response<bytes::Bytes>> for CreatePermissionResponse<'a> {
+impl<'a> std::convert::TryFrom<(Response<bytes::Bytes>, ExpirySeconds)>
+    for CreatePermissionResponse<'a>
+{
     type Error = crate::Error;
 
-    fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
+    fn try_from(
+        (response, expiry_seconds): (Response<bytes::Bytes>, ExpirySeconds),
+    ) -> Result<Self, Self::Error> {
         let headers = response.headers();
         let body: &[u8] = response.body();
+        let issued_on = date_from_headers(headers).unwrap_or_else(|_| Utc::now());
 
         Ok(Self {
             permission: body.try_into()?,
@@ -30,6 +40,56 @@ impl<'a> std::convert::TryFrom<Response<bytes::Bytes>> for CreatePermissionRespo
             session_token: session_token_from_headers(headers)?,
             content_path: content_path_from_headers(headers)?.to_owned(),
             alt_content_path: alt_content_path_from_headers(headers)?.to_owned(),
+            expires_on: expiry_seconds.expires_on(issued_on),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::permission::{AuthorizationToken, PermissionMode};
+    use std::convert::TryFrom;
+
+    const PERMISSION_JSON: &str = r#"{
+        "id": "a_permission",
+        "permissionMode": "All",
+        "resource": "dbs/volcanodb/colls/volcano1",
+        "_rid": "Sl8fAG8cXgBn6Ju2GqNsAA==",
+        "_ts": 1449604760,
+        "_self": "dbs/Sl8fAA==/users/Sl8fAG8cXgA=/permissions/Sl8fAG8cXgBn6Ju2GqNsAA==/",
+        "_etag": "\"00000e00-0000-0000-0000-566736980000\"",
+        "_token": "type=resource&ver=1.0&sig=ocPyc9QQFybITu1EqzX0kg==;w+WR1aWafB3+yZq5JSoBwgz78XDlU+k9Xiqvc+Q7TlAl1P4h4t721Cn5cjhZ9h3TSd2/MJLy+wG+YkhDL9UlGkVv05RZGy2fMaLGdeQkWc7TShkc/M2boPc3GXq2yiERKl5CN4AZWSOcrFhOFuuTOqF4ZdBlflmNudaakodr/8qTip0i+a7moz1Jkc5+9iLAsDFyqTR1sirp7kAVNFbiqPdYTjNkvZUHF3nYYmRskOg=;"
+    }"#;
+
+    #[test]
+    fn parse_create_permission_response() {
+        let response = Response::builder()
+            .status(http::StatusCode::CREATED)
+            .header("date", "Tue, 08 Dec 2015 19:59:20 GMT")
+            .header("x-ms-request-charge", "1.23")
+            .header("x-ms-activity-id", "00000000-0000-0000-0000-000000000000")
+            .header("etag", "\"00000e00-0000-0000-0000-566736980000\"")
+            .header("x-ms-session-token", "0:1")
+            .header("x-ms-content-path", "Sl8fAA==")
+            .header("x-ms-alt-content-path", "dbs/volcanodb/users/a_user")
+            .body(bytes::Bytes::from_static(PERMISSION_JSON.as_bytes()))
+            .unwrap();
+
+        let response =
+            CreatePermissionResponse::try_from((response, ExpirySeconds::new(3600))).unwrap();
+
+        assert_eq!(
+            response.permission.permission_mode,
+            PermissionMode::All("dbs/volcanodb/colls/volcano1".into())
+        );
+        assert!(matches!(
+            response.permission.permission_token.token,
+            AuthorizationToken::Resource(_)
+        ));
+        assert_eq!(
+            response.expires_on,
+            DateTime::parse_from_rfc3339("2015-12-08T20:59:20Z").unwrap()
+        );
+    }
+}
@@ -44,7 +44,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for CreateReferenceAttachment
         debug!("headers == {:#?}", headers);
         debug!("body == {:#?}", body);
 
-        let attachment: Attachment = serde_json::from_slice(body)?;
+        let attachment: Attachment = crate::json::from_json(body)?;
 
         Ok(Self {
             attachment,
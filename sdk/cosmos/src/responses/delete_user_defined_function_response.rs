@@ -1,72 +0,0 @@
-use crate::headers::from_headers::*;
-use crate::ResourceQuota;
-use azure_core::headers::session_token_from_headers;
-use chrono::{DateTime, Utc};
-use http::response::Response;
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct DeleteUserDefinedFunctionResponse {
-    pub content_location: String,
-    pub server: String,
-    pub last_state_change: DateTime<Utc>,
-    pub resource_quota: Vec<ResourceQuota>,
-    pub resource_usage: Vec<ResourceQuota>,
-    pub lsn: u64,
-    pub schema_version: String,
-    pub alt_content_path: String,
-    pub content_path: String,
-    pub quorum_acked_lsn: u64,
-    pub current_write_quorum: u64,
-    pub current_replica_set_size: u64,
-    pub role: u32,
-    pub global_committed_lsn: u64,
-    pub number_of_read_regions: u32,
-    pub transport_request_id: u64,
-    pub cosmos_llsn: u64,
-    pub cosmos_quorum_acked_llsn: u64,
-    pub session_token: String,
-    pub charge: f64,
-    pub service_version: String,
-    pub activity_id: uuid::Uuid,
-    pub gateway_version: String,
-    pub date: DateTime<Utc>,
-}
-
-impl std::convert::TryFrom<Response<bytes::Bytes>> for DeleteUserDefinedFunctionResponse {
-    type Error = crate::Error;
-
-    fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
-        let headers = response.headers();
-        let body = response.body();
-
-        debug!("{:#?}", headers);
-        debug!("{:#?}", std::str::from_utf8(body));
-
-        Ok(Self {
-            content_location: content_location_from_headers(headers)?.to_owned(),
-            server: server_from_headers(headers)?.to_owned(),
-            last_state_change: last_state_change_from_headers(headers)?,
-            resource_quota: resource_quota_from_headers(headers)?,
-            resource_usage: resource_usage_from_headers(headers)?,
-            lsn: lsn_from_headers(headers)?,
-            schema_version: schema_version_from_headers(headers)?.to_owned(),
-            alt_content_path: alt_content_path_from_headers(headers)?.to_owned(),
-            content_path: content_path_from_headers(headers)?.to_owned(),
-            quorum_acked_lsn: quorum_acked_lsn_from_headers(headers)?,
-            current_write_quorum: current_write_quorum_from_headers(headers)?,
-            current_replica_set_size: current_replica_set_size_from_headers(headers)?,
-            role: role_from_headers(headers)?,
-            global_committed_lsn: global_committed_lsn_from_headers(headers)?,
-            number_of_read_regions: number_of_read_regions_from_headers(headers)?,
-            transport_request_id: transport_request_id_from_headers(headers)?,
-            cosmos_llsn: cosmos_llsn_from_headers(headers)?,
-            cosmos_quorum_acked_llsn: cosmos_quorum_acked_llsn_from_headers(headers)?,
-            session_token: session_token_from_headers(headers)?,
-            charge: request_charge_from_headers(headers)?,
-            service_version: service_version_from_headers(headers)?.to_owned(),
-            activity_id: activity_id_from_headers(headers)?,
-            gateway_version: gateway_version_from_headers(headers)?.to_owned(),
-            date: date_from_headers(headers)?,
-        })
-    }
-}
@@ -1,21 +0,0 @@
-use crate::headers::from_headers::*;
-use http::response::Response;
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct DeleteUserResponse {
-    pub charge: f64,
-    pub activity_id: uuid::Uuid,
-}
-
-impl std::convert::TryFrom<Response<bytes::Bytes>> for DeleteUserResponse {
-    type Error = crate::Error;
-
-    fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
-        let headers = response.headers();
-
-        Ok(Self {
-            charge: request_charge_from_headers(headers)?,
-            activity_id: activity_id_from_headers(headers)?,
-        })
-    }
-}
@@ -47,7 +47,7 @@ where
         debug!("body == {:#?}", body);
 
         Ok(Self {
-            payload: serde_json::from_slice(body)?,
+            payload: crate::json::from_json(body)?,
 
             last_state_change: last_state_change_from_headers(headers)?,
             schema_version: schema_version_from_headers(headers)?.to_owned(),
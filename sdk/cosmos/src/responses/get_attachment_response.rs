@@ -48,7 +48,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for GetAttachmentResponse {
         debug!("body == {:?}", std::str::from_utf8(body));
 
         Ok(Self {
-            attachment: serde_json::from_slice(body)?,
+            attachment: crate::json::from_json(body)?,
             content_type: content_type_from_headers(headers)?.to_owned(),
             content_location: content_location_from_headers(headers)?.to_owned(),
             last_change: last_state_change_from_headers(headers)?,
@@ -1,5 +1,6 @@
 use crate::headers::from_headers::*;
 use crate::resources::Collection;
+use crate::CollectionQuota;
 use azure_core::headers::{etag_from_headers, session_token_from_headers};
 use chrono::{DateTime, Utc};
 use http::response::Response;
@@ -26,6 +27,13 @@ pub struct GetCollectionResponse {
     pub activity_id: uuid::Uuid,
     pub session_token: String,
     pub gateway_version: String,
+    /// The limits of the collection, if they were asked for with `populate_quota_info`
+    pub resource_quota: Option<CollectionQuota>,
+    /// The current usage of the collection, if it was asked for with `populate_quota_info`
+    pub resource_usage: Option<CollectionQuota>,
+    /// The percentage of the documents reindexed since the last change of the indexing policy,
+    /// if the quota info was asked for with `populate_quota_info`
+    pub index_transformation_progress: Option<u8>,
 }
 
 impl std::convert::TryFrom<Response<bytes::Bytes>> for GetCollectionResponse {
@@ -36,7 +44,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for GetCollectionResponse {
         let body = response.body();
 
         Ok(Self {
-            collection: serde_json::from_slice(body)?,
+            collection: crate::json::from_json(body)?,
             last_state_change: last_state_change_from_headers(headers)?,
             etag: etag_from_headers(headers)?,
             collection_partition_index: collection_partition_index_from_headers(headers)?,
@@ -56,6 +64,13 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for GetCollectionResponse {
             activity_id: activity_id_from_headers(headers)?,
             session_token: session_token_from_headers(headers)?,
             gateway_version: gateway_version_from_headers(headers)?.to_owned(),
+            resource_quota: resource_quota_from_headers_optional(headers)?
+                .map(|quota| CollectionQuota::from(quota.as_slice())),
+            resource_usage: resource_usage_from_headers_optional(headers)?
+                .map(|usage| CollectionQuota::from(usage.as_slice())),
+            index_transformation_progress: index_transformation_progress_from_headers_optional(
+                headers,
+            )?,
         })
     }
 }
@@ -11,6 +11,8 @@ use serde::de::DeserializeOwned;
 #[derive(Debug, Clone)]
 pub enum GetDocumentResponse<T> {
     Found(Box<FoundDocumentResponse<T>>),
+    /// The document did not change since the `if_modified_since` time or `if_match_condition` etag
+    NotModified(Box<NotModifiedDocumentResponse>),
     NotFound(Box<NotFoundDocumentResponse>),
 }
 
@@ -21,19 +23,17 @@ where
     type Error = crate::Error;
 
     fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
-        let status_code = response.status();
-
-        let has_been_found =
-            status_code == StatusCode::OK || status_code == StatusCode::NOT_MODIFIED;
-
-        if has_been_found {
-            Ok(GetDocumentResponse::Found(Box::new(
+        match response.status() {
+            StatusCode::OK => Ok(GetDocumentResponse::Found(Box::new(
                 FoundDocumentResponse::try_from(response)?,
-            )))
-        } else {
-            Ok(GetDocumentResponse::NotFound(Box::new(
+            ))),
+            // a 304 has no body to read the document from
+            StatusCode::NOT_MODIFIED => Ok(GetDocumentResponse::NotModified(Box::new(
+                NotModifiedDocumentResponse::try_from(response)?,
+            ))),
+            _ => Ok(GetDocumentResponse::NotFound(Box::new(
                 NotFoundDocumentResponse::try_from(response)?,
-            )))
+            ))),
         }
     }
 }
@@ -104,6 +104,29 @@ where
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct NotModifiedDocumentResponse {
+    pub etag: String,
+    pub session_token: SessionToken,
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+}
+
+impl std::convert::TryFrom<Response<bytes::Bytes>> for NotModifiedDocumentResponse {
+    type Error = crate::Error;
+
+    fn try_from(response: Response<bytes::Bytes>) -> Result<Self, Self::Error> {
+        let headers = response.headers();
+
+        Ok(Self {
+            etag: etag_from_headers(headers)?,
+            session_token: session_token_from_headers(headers)?,
+            charge: request_charge_from_headers(headers)?,
+            activity_id: activity_id_from_headers(headers)?,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NotFoundDocumentResponse {
     pub content_location: String,
@@ -154,3 +177,30 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for NotFoundDocumentResponse
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn not_modified_documents_are_read_from_the_headers() {
+        let response = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header("etag", "\"00000000-0000-0000-4a78-a3a0e3ab01d7\"")
+            .header("x-ms-session-token", "0:-1#3")
+            .header("x-ms-request-charge", "1")
+            .header("x-ms-activity-id", "0bc6df5e-d0ca-4ec4-ba72-f16ec5ecef44")
+            .body(bytes::Bytes::new())
+            .unwrap();
+
+        match GetDocumentResponse::<serde_json::Value>::try_from(response).unwrap() {
+            GetDocumentResponse::NotModified(response) => {
+                assert_eq!(response.etag, "\"00000000-0000-0000-4a78-a3a0e3ab01d7\"");
+                assert_eq!(response.session_token, "0:-1#3");
+                assert_eq!(response.charge, 1.0);
+            }
+            other => panic!("expected a not modified document, got {:?}", other),
+        }
+    }
+}
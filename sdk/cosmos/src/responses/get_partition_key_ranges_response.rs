@@ -1,4 +1,5 @@
 use crate::headers::from_headers::*;
+use crate::resources::collection::PartitionKeyRange;
 use azure_core::headers::{item_count_from_headers, session_token_from_headers};
 use chrono::{DateTime, Utc};
 use http::response::Response;
@@ -42,7 +43,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for GetPartitionKeyRangesResp
             pub partition_key_ranges: Vec<PartitionKeyRange>,
         }
 
-        let r: Response = serde_json::from_slice(body)?;
+        let r: Response = crate::json::from_json(body)?;
 
         Ok(Self {
             rid: r.rid,
@@ -68,25 +69,3 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for GetPartitionKeyRangesResp
         })
     }
 }
-
-#[derive(Debug, PartialEq, PartialOrd, Clone, Deserialize)]
-pub struct PartitionKeyRange {
-    #[serde(rename = "_rid")]
-    pub rid: String,
-    pub id: String,
-    #[serde(rename = "_etag")]
-    pub etag: String,
-    #[serde(rename = "minInclusive")]
-    pub min_exclusive: String,
-    #[serde(rename = "maxExclusive")]
-    pub max_exclusive: String,
-    #[serde(rename = "ridPrefix")]
-    pub rid_prefix: u64,
-    pub _self: String,
-    #[serde(rename = "throughputFraction")]
-    pub throughput_fraction: u64,
-    pub status: String,
-    // TODO: parents
-    #[serde(rename = "_ts")]
-    pub ts: u64,
-}
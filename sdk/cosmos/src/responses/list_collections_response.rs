@@ -40,7 +40,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for ListCollectionsResponse {
             pub count: u32,
         }
 
-        let response: Response = serde_json::from_slice(body)?;
+        let response: Response = crate::json::from_json(body)?;
 
         Ok(Self {
             rid: response._rid,
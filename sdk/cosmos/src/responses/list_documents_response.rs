@@ -60,7 +60,7 @@ pub struct ListDocumentsResponseEntities<T> {
 impl std::convert::TryFrom<&[u8]> for ListDocumentsResponseAttributes {
     type Error = crate::Error;
     fn try_from(body: &[u8]) -> Result<Self, Self::Error> {
-        Ok(serde_json::from_slice(body)?)
+        Ok(crate::json::from_json(body)?)
     }
 }
 
@@ -71,7 +71,7 @@ where
     type Error = crate::Error;
 
     fn try_from(body: &[u8]) -> Result<Self, Self::Error> {
-        Ok(serde_json::from_slice(body)?)
+        Ok(crate::json::from_json(body)?)
     }
 }
 
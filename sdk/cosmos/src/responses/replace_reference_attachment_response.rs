@@ -42,7 +42,7 @@ impl std::convert::TryFrom<Response<bytes::Bytes>> for ReplaceReferenceAttachmen
         debug!("headers == {:#?}", headers);
         debug!("body == {:#?}", std::str::from_utf8(body));
 
-        let attachment: Attachment = serde_json::from_slice(body)?;
+        let attachment: Attachment = crate::json::from_json(body)?;
 
         Ok(Self {
             attachment,
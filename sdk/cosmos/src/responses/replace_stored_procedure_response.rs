@@ -1 +1 @@
-pub type ReplaceStoredProcedureResponse = crate::responses::CreateStoredProcedureResponse;
+pub type ReplaceStoredProcedureResponse = crate::operations::CreateStoredProcedureResponse;
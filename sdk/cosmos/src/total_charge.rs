@@ -0,0 +1,96 @@
+use crate::headers::from_headers::request_charge_from_headers;
+use crate::headers::HEADER_REQUEST_CHARGE;
+use crate::QueryMetrics;
+use futures::stream::{Stream, StreamExt};
+use http::HeaderMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+/// The request units consumed so far by the pages of a stream.
+///
+/// Clones share the same total, so a clone kept aside keeps counting while the stream is
+/// consumed.
+#[derive(Debug, Clone, Default)]
+pub struct TotalCharge(Arc<Mutex<f64>>);
+
+impl TotalCharge {
+    /// The sum of the `x-ms-request-charge` of the pages received so far
+    pub fn get(&self) -> f64 {
+        *self.0.lock().unwrap()
+    }
+
+    /// Adds the request units of one more response
+    pub(crate) fn add(&self, charge: f64) {
+        *self.0.lock().unwrap() += charge;
+    }
+
+    /// Adds the charge of the page whose headers are `headers`, if it has one
+    pub(crate) fn add_page(&self, headers: &HeaderMap) -> Result<(), crate::Error> {
+        if headers.contains_key(HEADER_REQUEST_CHARGE) {
+            self.add(request_charge_from_headers(headers)?);
+        }
+        Ok(())
+    }
+}
+
+/// The query metrics of the pages received so far, shared with the stream receiving them
+pub(crate) type PageQueryMetrics = Arc<Mutex<Vec<QueryMetrics>>>;
+
+/// A stream of the items of paginated responses, which counts the request units of the pages.
+///
+/// Its items are the ones of the inner stream: call [`total_charge`](Self::total_charge) once
+/// it is consumed, or during, for the cost of the pages received so far.
+#[derive(Debug)]
+pub struct ChargedStream<S> {
+    stream: S,
+    total_charge: TotalCharge,
+    query_metrics: PageQueryMetrics,
+}
+
+impl<S> ChargedStream<S> {
+    pub(crate) fn new(stream: S, total_charge: TotalCharge) -> Self {
+        Self {
+            stream,
+            total_charge,
+            query_metrics: PageQueryMetrics::default(),
+        }
+    }
+
+    /// Reports the query metrics the stream collects in `query_metrics`
+    pub(crate) fn with_query_metrics(self, query_metrics: PageQueryMetrics) -> Self {
+        Self {
+            query_metrics,
+            ..self
+        }
+    }
+
+    /// The server-side metrics of the pages received so far, one per page, when the query
+    /// asked for them with
+    /// [`populate_query_metrics`](crate::operations::QueryDocumentsOptions::populate_query_metrics)
+    pub fn query_metrics(&self) -> Vec<QueryMetrics> {
+        self.query_metrics.lock().unwrap().clone()
+    }
+
+    /// The request units consumed by the pages received so far
+    pub fn total_charge(&self) -> f64 {
+        self.total_charge.get()
+    }
+
+    /// A handle on the total, which keeps counting after the stream is moved
+    pub fn total_charge_handle(&self) -> TotalCharge {
+        self.total_charge.clone()
+    }
+}
+
+impl<S: Stream + Unpin> Stream for ChargedStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
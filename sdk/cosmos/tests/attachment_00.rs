@@ -65,13 +65,14 @@ async fn attachment() -> Result<(), azure_cosmos::Error> {
             indexing_mode: collection::IndexingMode::Consistent,
             included_paths: vec![ip],
             excluded_paths: vec![],
+            composite_indexes: vec![],
         };
 
-        let options = CreateCollectionOptions::new("/id")
+        let options = CreateCollectionOptions::new()
             .offer(Offer::Throughput(400))
             .indexing_policy(ip);
         database_client
-            .create_collection(Context::new(), COLLECTION_NAME, options)
+            .create_collection(Context::new(), COLLECTION_NAME, "/id", options)
             .await
             .unwrap()
     };
@@ -91,8 +92,7 @@ async fn attachment() -> Result<(), azure_cosmos::Error> {
 
     // let's add an entity.
     let session_token: ConsistencyLevel = collection_client
-        .create_document()
-        .execute(&doc)
+        .create_document(Context::new(), &doc, CreateDocumentOptions::new())
         .await?
         .into();
 
@@ -28,7 +28,8 @@ async fn create_and_delete_collection() {
         .create_collection(
             Context::new(),
             COLLECTION_NAME,
-            CreateCollectionOptions::new("/id"),
+            "/id",
+            CreateCollectionOptions::new(),
         )
         .await
         .unwrap();
@@ -85,12 +86,13 @@ async fn replace_collection() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
-    let options = CreateCollectionOptions::new("/id")
+    let options = CreateCollectionOptions::new()
         .offer(Offer::S2)
         .indexing_policy(indexing_policy);
     let collection = database_client
-        .create_collection(Context::new(), COLLECTION_NAME, options)
+        .create_collection(Context::new(), COLLECTION_NAME, "/id", options)
         .await
         .unwrap();
 
@@ -118,6 +120,7 @@ async fn replace_collection() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![ip],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
 
     new_ip
@@ -34,7 +34,7 @@ async fn create_and_delete_database() {
         .get_database(Context::new(), GetDatabaseOptions::new())
         .await
         .unwrap();
-    assert!(database.database.rid == database_after_get.database.rid);
+    assert!(database.database.unwrap().rid == database_after_get.database.rid);
 
     // delete the database
     client
@@ -7,6 +7,7 @@ use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
 use azure_cosmos::responses::GetDocumentResponse;
 use collection::*;
+use futures::stream::TryStreamExt;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 struct MyDocument {
@@ -47,13 +48,14 @@ async fn create_and_delete_document() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
 
-    let options = CreateCollectionOptions::new("/id")
+    let options = CreateCollectionOptions::new()
         .offer(Offer::Throughput(400))
         .indexing_policy(indexing_policy);
     database_client
-        .create_collection(Context::new(), COLLECTION_NAME, options)
+        .create_collection(Context::new(), COLLECTION_NAME, "/id", options)
         .await
         .unwrap();
 
@@ -67,8 +69,7 @@ async fn create_and_delete_document() {
         hello: 42,
     };
     collection_client
-        .create_document()
-        .execute(&document_data)
+        .create_document(Context::new(), &document_data, CreateDocumentOptions::new())
         .await
         .unwrap();
 
@@ -99,7 +100,10 @@ async fn create_and_delete_document() {
     }
 
     // delete document
-    document_client.delete_document().execute().await.unwrap();
+    collection_client
+        .delete_document(Context::new(), DOCUMENT_NAME, &DOCUMENT_NAME, None)
+        .await
+        .unwrap();
 
     let documents = collection_client
         .list_documents()
@@ -136,13 +140,14 @@ async fn query_documents() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
 
-    let options = CreateCollectionOptions::new("/id")
+    let options = CreateCollectionOptions::new()
         .indexing_policy(indexing_policy)
         .offer(Offer::S2);
     database_client
-        .create_collection(Context::new(), COLLECTION_NAME, options)
+        .create_collection(Context::new(), COLLECTION_NAME, "/id", options)
         .await
         .unwrap();
 
@@ -156,8 +161,7 @@ async fn query_documents() {
         hello: 42,
     };
     collection_client
-        .create_document()
-        .execute(&document_data)
+        .create_document(Context::new(), &document_data, CreateDocumentOptions::new())
         .await
         .unwrap();
 
@@ -170,19 +174,20 @@ async fn query_documents() {
     assert!(documents.len() == 1);
 
     // now query all documents and see if we get the correct result
-    let query_result = collection_client
-        .query_documents()
-        .query_cross_partition(true)
-        .execute::<MyDocument, _>("SELECT * FROM c")
+    let query_result: Vec<Document<MyDocument>> = collection_client
+        .query_documents(
+            Context::new(),
+            "SELECT * FROM c",
+            vec![],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect()
         .await
-        .unwrap()
-        .into_documents()
-        .unwrap()
-        .results;
+        .unwrap();
 
     assert!(query_result.len() == 1);
     assert!(query_result[0].document_attributes.rid() == documents[0].document_attributes.rid());
-    assert_eq!(query_result[0].result, document_data);
+    assert_eq!(query_result[0].document, document_data);
 
     database_client.delete_database().execute().await.unwrap();
 }
@@ -211,13 +216,14 @@ async fn replace_document() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
 
-    let options = CreateCollectionOptions::new("/id")
+    let options = CreateCollectionOptions::new()
         .indexing_policy(indexing_policy)
         .offer(Offer::S2);
     database_client
-        .create_collection(Context::new(), COLLECTION_NAME, options)
+        .create_collection(Context::new(), COLLECTION_NAME, "/id", options)
         .await
         .unwrap();
 
@@ -231,8 +237,7 @@ async fn replace_document() {
         hello: 42,
     };
     collection_client
-        .create_document()
-        .execute(&document_data)
+        .create_document(Context::new(), &document_data, CreateDocumentOptions::new())
         .await
         .unwrap();
 
@@ -246,15 +251,16 @@ async fn replace_document() {
     // replace document with optimistic concurrency and session token
     document_data.hello = 190;
     collection_client
-        .clone()
-        .into_document_client(document_data.id.clone(), &document_data.id)
-        .unwrap()
-        .replace_document()
-        .consistency_level(ConsistencyLevel::from(&documents))
-        .if_match_condition(IfMatchCondition::Match(
-            &documents.documents[0].document_attributes.etag(),
-        ))
-        .execute(&document_data)
+        .replace_document(
+            Context::new(),
+            &document_data.id,
+            &document_data,
+            ReplaceDocumentOptions::new()
+                .consistency_level(ConsistencyLevel::from(&documents))
+                .if_match_condition(IfMatchCondition::Match(
+                    &documents.documents[0].document_attributes.etag(),
+                )),
+        )
         .await
         .unwrap();
 
@@ -29,16 +29,23 @@ async fn permissions() {
 
     // create two users
     let user1_client = database_client.clone().into_user_client(USER_NAME1);
-    let _create_user_response = user1_client.create_user().execute().await.unwrap();
+    let _create_user_response = user1_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await
+        .unwrap();
     let user2_client = database_client.clone().into_user_client(USER_NAME2);
-    let _create_user_response = user2_client.create_user().execute().await.unwrap();
+    let _create_user_response = user2_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await
+        .unwrap();
 
     // create a temp collection
     let create_collection_response = database_client
         .create_collection(
             Context::new(),
             COLLECTION_NAME,
-            CreateCollectionOptions::new("/id"),
+            "/id",
+            CreateCollectionOptions::new(),
         )
         .await
         .unwrap();
@@ -49,18 +49,27 @@ async fn permission_token_usage() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
     };
 
-    let create_collection_options = CreateCollectionOptions::new("/id")
+    let create_collection_options = CreateCollectionOptions::new()
         .offer(Offer::Throughput(400))
         .indexing_policy(indexing_policy);
     let create_collection_response = database_client
-        .create_collection(Context::new(), COLLECTION_NAME, create_collection_options)
+        .create_collection(
+            Context::new(),
+            COLLECTION_NAME,
+            "/id",
+            create_collection_options,
+        )
         .await
         .unwrap();
 
     let user_client = database_client.clone().into_user_client(USER_NAME);
-    user_client.create_user().execute().await.unwrap();
+    user_client
+        .create_user(Context::new(), CreateUserOptions::new())
+        .await
+        .unwrap();
 
     // create the RO permission
     let permission_client = user_client.into_permission_client(PERMISSION);
@@ -103,9 +112,7 @@ async fn permission_token_usage() {
     };
 
     new_collection_client
-        .create_document()
-        .is_upsert(true)
-        .execute(&document)
+        .upsert_document(Context::new(), &document, CreateDocumentOptions::new())
         .await
         .unwrap_err();
 
@@ -135,9 +142,7 @@ async fn permission_token_usage() {
     // now we have an "All" authorization_token
     // so the create_document should succeed!
     let create_document_response = new_collection_client
-        .create_document()
-        .is_upsert(true)
-        .execute(&document)
+        .upsert_document(Context::new(), &document, CreateDocumentOptions::new())
         .await
         .unwrap();
     println!(
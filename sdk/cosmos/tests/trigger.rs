@@ -61,7 +61,8 @@ async fn trigger() -> Result<(), azure_cosmos::Error> {
             .create_collection(
                 Context::new(),
                 COLLECTION_NAME,
-                CreateCollectionOptions::new("/id"),
+                "/id",
+                CreateCollectionOptions::new(),
             )
             .await
             .unwrap()
@@ -72,12 +73,14 @@ async fn trigger() -> Result<(), azure_cosmos::Error> {
         .into_collection_client(COLLECTION_NAME);
     let trigger_client = collection_client.clone().into_trigger_client(TRIGGER_NAME);
 
-    let ret = trigger_client
-        .create_trigger()
-        .execute(
-            "something",
+    let ret = collection_client
+        .create_trigger(
+            Context::new(),
+            TRIGGER_NAME,
             trigger::TriggerType::Post,
             trigger::TriggerOperation::All,
+            "something",
+            CreateTriggerOptions::new(),
         )
         .await?;
 
@@ -93,20 +96,22 @@ async fn trigger() -> Result<(), azure_cosmos::Error> {
 
     let mut last_session_token: Option<ConsistencyLevel> = None;
 
-    let stream = collection_client
-        .list_triggers()
-        .max_item_count(3)
-        .consistency_level(&ret);
-    let mut stream = Box::pin(stream.stream());
+    let mut stream = collection_client.list_triggers(
+        Context::new(),
+        ListTriggersOptions::new()
+            .max_item_count(3)
+            .consistency_level(&ret),
+    );
     while let Some(ret) = stream.next().await {
         let ret = ret.unwrap();
         last_session_token = Some(ConsistencyLevel::Session(ret.session_token));
     }
 
     let _ret = trigger_client
-        .delete_trigger()
-        .consistency_level(last_session_token.unwrap())
-        .execute()
+        .delete_trigger(
+            Context::new(),
+            DeleteTriggerOptions::new().consistency_level(last_session_token.unwrap()),
+        )
         .await?;
 
     // delete the database
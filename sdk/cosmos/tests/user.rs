@@ -1,6 +1,7 @@
 #![cfg(all(test, feature = "test_e2e"))]
 
 use azure_cosmos::prelude::*;
+use futures::StreamExt;
 
 mod setup;
 
@@ -25,9 +26,17 @@ async fn users() {
     let database_client = client.clone().into_database_client(DATABASE_NAME);
     let user_client = database_client.clone().into_user_client(USER_NAME);
 
-    let _create_user_response = user_client.create_user().execute().await.unwrap();
+    let _create_user_response = user_client
+        .create_user(azure_core::Context::new(), CreateUserOptions::new())
+        .await
+        .unwrap();
 
-    let list_users_response = database_client.list_users().execute().await.unwrap();
+    let list_users_response = database_client
+        .list_users(azure_core::Context::new(), ListUsersOptions::new())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(list_users_response.users.len(), 1);
 
     let get_user_response = user_client.get_user().execute().await.unwrap();
@@ -41,14 +50,27 @@ async fn users() {
         .await
         .unwrap();
 
-    let list_users_response = database_client.list_users().execute().await.unwrap();
+    let list_users_response = database_client
+        .list_users(azure_core::Context::new(), ListUsersOptions::new())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(list_users_response.users.len(), 1);
 
     let user_client = database_client.clone().into_user_client(USER_NAME_REPLACED);
 
-    let _delete_user_response = user_client.delete_user().execute().await.unwrap();
+    let _delete_user_response = user_client
+        .delete_user(azure_core::Context::new(), DeleteUserOptions::new())
+        .await
+        .unwrap();
 
-    let list_users_response = database_client.list_users().execute().await.unwrap();
+    let list_users_response = database_client
+        .list_users(azure_core::Context::new(), ListUsersOptions::new())
+        .next()
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(list_users_response.users.len(), 0);
 
     // delete the database
@@ -1,8 +1,7 @@
 #![cfg(all(test, feature = "test_e2e"))]
 use azure_core::prelude::*;
 use azure_cosmos::prelude::*;
-use azure_cosmos::responses::QueryDocumentsResponseRaw;
-use futures::stream::StreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
 
 mod setup;
 
@@ -43,7 +42,8 @@ async fn user_defined_function00() -> Result<(), azure_cosmos::Error> {
         .create_collection(
             Context::new(),
             COLLECTION_NAME,
-            CreateCollectionOptions::new("/id"),
+            "/id",
+            CreateCollectionOptions::new(),
         )
         .await
         .unwrap();
@@ -56,18 +56,22 @@ async fn user_defined_function00() -> Result<(), azure_cosmos::Error> {
         .into_user_defined_function_client(USER_DEFINED_FUNCTION_NAME);
 
     let ret = user_defined_function_client
-        .create_user_defined_function()
-        .execute("body")
+        .create_user_defined_function(
+            Context::new(),
+            "body",
+            CreateUserDefinedFunctionOptions::new(),
+        )
         .await?;
 
-    let stream = collection_client
-        .list_user_defined_functions()
-        .max_item_count(3)
-        .consistency_level(&ret);
-    let mut stream = Box::pin(stream.stream());
+    let mut stream = collection_client.list_user_defined_functions(
+        Context::new(),
+        ListUserDefinedFunctionsOptions::new()
+            .max_item_count(3)
+            .consistency_level(&ret),
+    );
     while let Some(ret) = stream.next().await {
         let ret = ret.unwrap();
-        assert_eq!(ret.item_count, 1);
+        assert_eq!(ret.count, 1);
     }
 
     let ret = user_defined_function_client
@@ -77,32 +81,36 @@ async fn user_defined_function00() -> Result<(), azure_cosmos::Error> {
         .await?;
 
     let query_stmt = format!("SELECT udf.{}(100)", USER_DEFINED_FUNCTION_NAME);
-    let ret: QueryDocumentsResponseRaw<serde_json::Value> = collection_client
-        .query_documents()
-        .consistency_level(&ret)
-        .max_item_count(2i32)
-        .execute(&query_stmt)
-        .await?
-        .into_raw();
+    let results: Vec<serde_json::Value> = collection_client
+        .query_documents(
+            Context::new(),
+            &query_stmt,
+            vec![],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect()
+        .await?;
 
-    assert_eq!(ret.item_count, 1);
+    assert_eq!(results.len(), 1);
 
-    let fn_return = ret.results[0].as_object().unwrap();
+    let fn_return = results[0].as_object().unwrap();
     let value = fn_return.iter().take(1).next().unwrap().1.as_f64().unwrap();
     assert_eq!(value, 10.0);
 
     let query_stmt = format!("SELECT udf.{}(10000)", USER_DEFINED_FUNCTION_NAME);
-    let ret: QueryDocumentsResponseRaw<serde_json::Value> = collection_client
-        .query_documents()
-        .consistency_level(&ret)
-        .max_item_count(2i32)
-        .execute(&query_stmt)
-        .await?
-        .into_raw();
+    let results: Vec<serde_json::Value> = collection_client
+        .query_documents(
+            Context::new(),
+            &query_stmt,
+            vec![],
+            QueryDocumentsOptions::new(),
+        )
+        .try_collect()
+        .await?;
 
-    assert_eq!(ret.item_count, 1);
+    assert_eq!(results.len(), 1);
 
-    let fn_return = ret.results[0].as_object().unwrap();
+    let fn_return = results[0].as_object().unwrap();
     let value = fn_return
         .into_iter()
         .take(1)
@@ -114,9 +122,10 @@ async fn user_defined_function00() -> Result<(), azure_cosmos::Error> {
     assert_eq!(value, 4000.0);
 
     let _ret = user_defined_function_client
-        .delete_user_defined_function()
-        .consistency_level(&ret)
-        .execute()
+        .delete_user_defined_function(
+            Context::new(),
+            DeleteUserDefinedFunctionOptions::new().consistency_level(&ret),
+        )
         .await?;
 
     // delete the database
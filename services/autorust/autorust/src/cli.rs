@@ -42,6 +42,12 @@ fn config_try_new_from_matches(arg_matches: &ArgMatches) -> Result<Config> {
         output_folder,
         api_version,
         box_properties,
+        newtypes: Vec::new(),
+        additional_properties: HashSet::new(),
+        numbers_as_strings: HashSet::new(),
+        non_exhaustive_enums: false,
+        hashable_models: false,
+        validate_bodies: false,
     })
 }
 
@@ -13,6 +13,12 @@ fn main() -> Result<()> {
         output_folder: output_folder.into(),
         input_files: input_files.iter().map(Into::into).collect(),
         box_properties: HashSet::new(),
+        newtypes: Vec::new(),
+        additional_properties: HashSet::new(),
+        numbers_as_strings: HashSet::new(),
+        non_exhaustive_enums: false,
+        hashable_models: false,
+        validate_bodies: false,
     })?;
 
     Ok(())
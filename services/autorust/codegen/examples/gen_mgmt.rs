@@ -3,7 +3,7 @@
 use autorust_codegen::{
     self, cargo_toml,
     config_parser::{to_api_version, to_mod_name},
-    get_mgmt_configs, lib_rs, path, Config, PropertyName, SpecConfigs,
+    get_mgmt_configs, lib_rs, path, Config, Newtype, PropertyName, SpecConfigs,
 };
 use heck::SnakeCase;
 
@@ -100,6 +100,59 @@ const BOX_PROPERTIES: &[(&str, &str, &str)] = &[
     // ("../../../azure-rest-api-specs/specification/network/resource-manager/Microsoft.Network/stable/2021-02-01/publicIpAddress.json", "PublicIPAddressPropertiesFormat", "ipConfiguration"),
 ];
 
+// some properties are typed inconsistently across schemas and api versions, so a common newtype is used instead
+// (service, newtype name, wrapped primitive, property names)
+const NEWTYPES: &[(&str, &str, &str, &[&str])] = &[
+    // reservations
+    ("reservations", "ReservationQuantity", "u32", &["quantity", "originalQuantity", "quantities"]),
+];
+
+// schemas whose fields missing from the spec, such as the ones of newer api versions, are kept in a map
+// (service, schema name)
+const ADDITIONAL_PROPERTIES: &[(&str, &str)] = &[
+    // appconfiguration
+    ("appconfiguration", "ConfigurationStoreProperties"),
+];
+
+// integer properties, in any schema, that some api versions send as numeric strings
+// (service, property names)
+const NUMBERS_AS_STRINGS: &[(&str, &[&str])] = &[
+    // reservations
+    ("reservations", &["etag"]),
+];
+
+// services whose enums are marked `#[non_exhaustive]`, so that variants added to the spec are not breaking changes
+const NON_EXHAUSTIVE_ENUMS: &[&str] = &["reservations"];
+
+// services whose enums, and structs without floating point or untyped fields, derive `Eq` and `Hash`
+const HASHABLE_MODELS: &[&str] = &["reservations"];
+
+// services whose operations check the required fields of the request bodies before sending them
+const VALIDATED_BODIES: &[&str] = &["appconfiguration"];
+
+// hand-written modules of a crate, kept when it is regenerated and exported from its lib.rs
+// (service, module name)
+const HAND_WRITTEN_MODULES: &[(&str, &str)] = &[
+    // appconfiguration
+    ("appconfiguration", "update_parameters"),
+    ("appconfiguration", "key_value"),
+    ("appconfiguration", "key_vault_reference"),
+    ("appconfiguration", "list_key_values"),
+    // deviceupdate
+    ("deviceupdate", "error_detail"),
+    ("deviceupdate", "name_availability"),
+    // reservations
+    ("reservations", "applied_scope"),
+    ("reservations", "merge_request"),
+    ("reservations", "sku_restriction"),
+    ("reservations", "reservation_orders"),
+    ("reservations", "paging"),
+    ("reservations", "applied_reservations"),
+    ("reservations", "patch"),
+    ("reservations", "purchase"),
+    ("reservations", "calculate_price"),
+];
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
@@ -147,6 +200,12 @@ fn gen_crate(spec: &SpecConfigs) -> Result<()> {
     let output_folder = &path::join(OUTPUT_FOLDER, service_name).map_err(|source| Error::PathError { source })?;
 
     let src_folder = path::join(output_folder, "src").map_err(|source| Error::PathError { source })?;
+    let mut hand_written_mods = Vec::new();
+    for (_, mod_name) in HAND_WRITTEN_MODULES.iter().filter(|(service, _)| *service == spec.spec()) {
+        let file = path::join(&src_folder, &format!("{}.rs", mod_name)).map_err(|source| Error::PathError { source })?;
+        let contents = fs::read(&file).map_err(|source| Error::IoError { source })?;
+        hand_written_mods.push((mod_name.to_string(), file, contents));
+    }
     if src_folder.exists() {
         fs::remove_dir_all(&src_folder).map_err(|source| Error::IoError { source })?;
     }
@@ -162,6 +221,28 @@ fn gen_crate(spec: &SpecConfigs) -> Result<()> {
             property_name: property_name.to_string(),
         });
     }
+    let newtypes: Vec<_> = NEWTYPES
+        .iter()
+        .filter(|(service, ..)| *service == spec.spec())
+        .map(|(_, name, inner, property_names)| Newtype {
+            name: name.to_string(),
+            inner: inner.to_string(),
+            property_names: property_names.iter().map(|property_name| property_name.to_string()).collect(),
+        })
+        .collect();
+    let additional_properties: HashSet<String> = ADDITIONAL_PROPERTIES
+        .iter()
+        .filter(|(service, _)| *service == spec.spec())
+        .map(|(_, schema_name)| schema_name.to_string())
+        .collect();
+    let numbers_as_strings: HashSet<String> = NUMBERS_AS_STRINGS
+        .iter()
+        .filter(|(service, _)| *service == spec.spec())
+        .flat_map(|(_, property_names)| property_names.iter().map(|property_name| property_name.to_string()))
+        .collect();
+    let non_exhaustive_enums = NON_EXHAUSTIVE_ENUMS.contains(&spec.spec());
+    let hashable_models = HASHABLE_MODELS.contains(&spec.spec());
+    let validate_bodies = VALIDATED_BODIES.contains(&spec.spec());
 
     for config in spec.configs() {
         let tag = config.tag.as_str();
@@ -194,6 +275,12 @@ fn gen_crate(spec: &SpecConfigs) -> Result<()> {
                 output_folder: mod_output_folder.into(),
                 input_files,
                 box_properties: box_properties.clone(),
+                newtypes: newtypes.clone(),
+                additional_properties: additional_properties.clone(),
+                numbers_as_strings: numbers_as_strings.clone(),
+                non_exhaustive_enums,
+                hashable_models,
+                validate_bodies,
             })
             .map_err(|source| Error::CodegenError { source })?;
         }
@@ -207,8 +294,13 @@ fn gen_crate(spec: &SpecConfigs) -> Result<()> {
         &path::join(output_folder, "Cargo.toml").map_err(|source| Error::PathError { source })?,
     )
     .map_err(|source| Error::CargoTomlError { source })?;
+    for (_, file, contents) in &hand_written_mods {
+        fs::write(file, contents).map_err(|source| Error::IoError { source })?;
+    }
+    let hand_written_mod_names: Vec<_> = hand_written_mods.into_iter().map(|(mod_name, ..)| mod_name).collect();
     lib_rs::create(
         &feature_mod_names,
+        &hand_written_mod_names,
         &path::join(src_folder, "lib.rs").map_err(|source| Error::PathError { source })?,
     )
     .map_err(|source| Error::LibRsError { source })?;
@@ -29,12 +29,16 @@ serde = {{ version = "1.0", features = ["derive"] }}
 serde_json = "1.0"
 reqwest = {{ version = "0.11", features = ["json"] }}
 bytes = "1.0"
+futures = "0.3"
 thiserror = "1.0"
 http = "0.2"
 url = "2.2"
 
 [dev-dependencies]
+async-trait = "0.1"
 azure_identity = {{ path = "../../../sdk/identity", version = "0.1.0" }}
+chrono = "0.4"
+oauth2 = "4.0"
 tokio = {{ version = "1.0", features = ["macros"] }}
 
 [features]
@@ -2,7 +2,7 @@ use crate::{
     identifier::{self, ident, CamelCaseIdent},
     spec,
     status_codes::{get_error_responses, get_response_type_name, get_status_code_name, get_success_responses, has_default_response},
-    Config, OperationVerb, PropertyName, ResolvedSchema, Spec,
+    Config, Newtype, OperationVerb, PropertyName, ResolvedSchema, Spec,
 };
 use autorust_openapi::{
     CollectionFormat, DataType, Parameter, ParameterType, PathItem, Reference, ReferenceOr, Response, Schema, SchemaCommon,
@@ -15,7 +15,8 @@ use regex::Regex;
 use serde_json::Value;
 use spec::{get_schema_schema_references, openapi, RefKey};
 use std::{
-    collections::HashSet,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
 
@@ -23,12 +24,18 @@ use std::{
 pub struct CodeGen {
     config: Config,
     pub spec: Spec,
+    /// whether the types generated for named schemas can derive `Eq` and `Hash`
+    hashable: RefCell<HashMap<RefKey, bool>>,
 }
 
 impl CodeGen {
     pub fn new(config: Config) -> Result<Self> {
         let spec = Spec::read_files(&config.input_files).map_err(Error::SpecError)?;
-        Ok(Self { config, spec })
+        Ok(Self {
+            config,
+            spec,
+            hashable: RefCell::new(HashMap::new()),
+        })
     }
 
     pub fn input_files(&self) -> &[PathBuf] {
@@ -43,6 +50,14 @@ impl CodeGen {
         self.config.api_version.as_deref()
     }
 
+    /// The newtype used for a property, if any.
+    fn property_newtype(&self, property_name: &str) -> Option<&Newtype> {
+        self.config
+            .newtypes
+            .iter()
+            .find(|newtype| newtype.property_names.iter().any(|name| name == property_name))
+    }
+
     pub fn create_models(&self) -> Result<TokenStream> {
         let mut file = TokenStream::new();
         file.extend(create_generated_by_header());
@@ -74,7 +89,13 @@ impl CodeGen {
             }
         }
 
+        for newtype in &self.config.newtypes {
+            file.extend(create_newtype(newtype)?);
+        }
+
+        let validated_body_types = self.validated_body_types()?;
         let mut schema_names = IndexMap::new();
+        let mut provisioning_states = Vec::new();
         for (ref_key, schema) in &all_schemas {
             let doc_file = &ref_key.file_path;
             let schema_name = &ref_key.name;
@@ -84,22 +105,123 @@ impl CodeGen {
                 //     schema_name, first_doc_file, doc_file
                 // );
             } else {
-                if is_array(&schema.schema.common) {
+                if self.config.newtypes.iter().any(|newtype| newtype.name == schema_name.to_camel_case()) {
+                    // replaced by the newtype
+                } else if is_array(&schema.schema.common) {
                     file.extend(self.create_vec_alias(doc_file, schema_name, schema)?);
                 } else if is_local_enum(schema) {
                     let no_namespace = TokenStream::new();
-                    let (_tp_name, tp) = create_enum(&no_namespace, schema_name, schema)?;
+                    let (_tp_name, tp) = create_enum(
+                        &no_namespace,
+                        schema_name,
+                        schema,
+                        self.config.non_exhaustive_enums,
+                        self.config.hashable_models,
+                    )?;
                     file.extend(tp);
+                    if is_provisioning_state(schema_name) {
+                        let nm = ident(&schema_name.to_camel_case()).map_err(|source| Error::IdentError {
+                            source,
+                            file: file!(),
+                            line: line!(),
+                        })?;
+                        provisioning_states.push((quote! { #nm }, enum_values(schema)));
+                    }
                 } else {
                     for stream in self.create_struct(doc_file, schema_name, schema)? {
                         file.extend(stream);
                     }
+                    if validated_body_types.contains(&schema_name.to_camel_case()) {
+                        file.extend(self.create_validate(doc_file, schema_name, schema)?);
+                    }
+                    provisioning_states.extend(self.local_provisioning_states(doc_file, schema_name, schema)?);
                 }
             }
         }
+        file.extend(create_provisioning_state_conversions(&provisioning_states)?);
         Ok(file)
     }
 
+    /// The names of the structs sent as the body of an operation: they get a `validate` method,
+    /// called by the operation before the body is serialized.
+    fn validated_body_types(&self) -> Result<HashSet<String>> {
+        let mut names = HashSet::new();
+        if !self.config.validate_bodies {
+            return Ok(names);
+        }
+        for (doc_file, doc) in self.spec.input_docs() {
+            let paths = self.spec.resolve_path_map(doc_file, &doc.paths).map_err(Error::SpecError)?;
+            for (_path, item) in &paths {
+                for op in spec::path_item_operations(item) {
+                    let parameters = self
+                        .spec
+                        .resolve_parameters(doc_file, &op.operation().parameters)
+                        .map_err(Error::SpecError)?;
+                    for parameter in &parameters {
+                        if let (ParameterType::Body, Some(ReferenceOr::Reference { reference, .. })) = (&parameter.in_, &parameter.schema) {
+                            let name = match &reference.name {
+                                Some(name) => name.to_camel_case(),
+                                None => continue,
+                            };
+                            let schema = self
+                                .spec
+                                .resolve_schema_ref(doc_file, reference.clone())
+                                .map_err(Error::SpecError)?;
+                            let is_newtype = self.config.newtypes.iter().any(|newtype| newtype.name == name);
+                            if !is_newtype && !is_array(&schema.schema.common) && !is_local_enum(&schema) {
+                                names.insert(name);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// The `validate` method of a request body: the service answers `400 Bad Request` to the
+    /// bodies whose required strings are empty. The required nested objects are not `Option`s,
+    /// they are always there.
+    fn create_validate(&self, doc_file: &Path, struct_name: &str, schema: &ResolvedSchema) -> Result<TokenStream> {
+        let nm = ident(&struct_name.to_camel_case()).map_err(|source| Error::IdentError {
+            source,
+            file: file!(),
+            line: line!(),
+        })?;
+        let required: HashSet<&str> = schema.schema.required.iter().map(String::as_str).collect();
+        let properties = self
+            .spec
+            .resolve_schema_map(doc_file, &schema.schema.properties)
+            .map_err(Error::SpecError)?;
+        let mut checks = TokenStream::new();
+        for (property_name, property) in &properties {
+            let is_plain_string = property.ref_key.is_none()
+                && is_string(&property.schema.common)
+                && !is_local_enum(property)
+                && self.property_newtype(property_name).is_none();
+            if required.contains(property_name.as_str()) && is_plain_string {
+                let field_name = ident(&property_name.to_snake_case()).map_err(|source| Error::IdentError {
+                    source,
+                    file: file!(),
+                    line: line!(),
+                })?;
+                checks.extend(quote! {
+                    if self.#field_name.is_empty() {
+                        return Err(azure_core::ValidationError::EmptyRequiredField(#property_name));
+                    }
+                });
+            }
+        }
+        Ok(quote! {
+            impl #nm {
+                pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+                    #checks
+                    Ok(())
+                }
+            }
+        })
+    }
+
     pub fn create_operations(&self) -> Result<TokenStream> {
         let mut file = TokenStream::new();
         file.extend(create_generated_by_header());
@@ -111,6 +233,7 @@ impl CodeGen {
 
         });
         let param_re = Regex::new(r"\{(\w+)\}").unwrap();
+        let validated_body_types = self.validated_body_types()?;
         let mut modules: IndexMap<Option<String>, TokenStream> = IndexMap::new();
         // println!("input_files {:?}", self.input_files());
         for (doc_file, doc) in self.spec.docs() {
@@ -121,7 +244,7 @@ impl CodeGen {
                 for (path, item) in &paths {
                     for op in spec::path_item_operations(item) {
                         let (module_name, function_name) = op.function_name(path);
-                        let function = create_function(self, doc_file, path, item, &op, &param_re, &function_name)?;
+                        let function = create_function(self, doc_file, path, item, &op, &param_re, &function_name, &validated_body_types)?;
                         if modules.contains_key(&module_name) {}
                         match modules.get_mut(&module_name) {
                             Some(module) => {
@@ -189,6 +312,36 @@ impl CodeGen {
         Ok(quote! { pub type #typ = Vec<#items_typ>; })
     }
 
+    /// The provisioning state enums generated in the module of a struct, with their values.
+    fn local_provisioning_states(
+        &self,
+        doc_file: &Path,
+        struct_name: &str,
+        schema: &ResolvedSchema,
+    ) -> Result<Vec<(TokenStream, Vec<String>)>> {
+        let ns = ident(&struct_name.to_snake_case()).map_err(|source| Error::IdentError {
+            source,
+            file: file!(),
+            line: line!(),
+        })?;
+        let properties = self
+            .spec
+            .resolve_schema_map(doc_file, &schema.schema.properties)
+            .map_err(Error::SpecError)?;
+        let mut provisioning_states = Vec::new();
+        for (property_name, property) in &properties {
+            if is_provisioning_state(property_name) && property.ref_key.is_none() && is_local_enum(property) {
+                let nm = ident(&property_name.to_camel_case()).map_err(|source| Error::IdentError {
+                    source,
+                    file: file!(),
+                    line: line!(),
+                })?;
+                provisioning_states.push((quote! { #ns::#nm }, enum_values(property)));
+            }
+        }
+        Ok(provisioning_states)
+    }
+
     fn create_struct(&self, doc_file: &Path, struct_name: &str, schema: &ResolvedSchema) -> Result<Vec<TokenStream>> {
         // println!("create_struct {} {}", doc_file.to_str().unwrap(), struct_name);
         let mut streams = Vec::new();
@@ -229,7 +382,10 @@ impl CodeGen {
                 file: file!(),
                 line: line!(),
             })?;
-            let (mut field_tp_name, field_tp) = self.create_struct_field_type(doc_file, &ns, property_name, property)?;
+            let (mut field_tp_name, field_tp) = match self.property_newtype(property_name) {
+                Some(newtype) => (create_newtype_field_type(newtype, property)?, Vec::new()),
+                None => self.create_struct_field_type(doc_file, &ns, property_name, property)?,
+            };
             let is_required = required.contains(property_name.as_str());
             let is_vec = is_vec(&field_tp_name);
             if !is_vec {
@@ -240,12 +396,23 @@ impl CodeGen {
             if &nm.to_string() != property_name {
                 serde_attrs.push(quote! { rename = #property_name });
             }
-            if !is_required {
-                if is_vec {
-                    serde_attrs.push(quote! { default, skip_serializing_if = "Vec::is_empty"});
+            // some services quote these integers in some api versions, such as `"etag": "123"`
+            if self.property_newtype(property_name).is_none()
+                && is_integer(&property.schema.common)
+                && self.config.numbers_as_strings.contains(property_name)
+            {
+                let with = if is_required {
+                    "azure_core::parsing::number_or_string"
                 } else {
-                    serde_attrs.push(quote! { default, skip_serializing_if = "Option::is_none"});
-                }
+                    "azure_core::parsing::number_or_string_optional"
+                };
+                serde_attrs.push(quote! { with = #with });
+            }
+            if !is_required {
+                // a field that is not serialized when empty may as well be missing from a response:
+                // it is always paired with `default` so that sparse payloads deserialize
+                let skip_serializing_if = if is_vec { "Vec::is_empty" } else { "Option::is_none" };
+                serde_attrs.push(quote! { default, skip_serializing_if = #skip_serializing_if });
             }
             let serde = if serde_attrs.len() > 0 {
                 quote! { #[serde(#(#serde_attrs),*)] }
@@ -268,8 +435,26 @@ impl CodeGen {
             });
         }
 
+        // the map only gets the fields that did not deserialize into another one, and serializes
+        // them back as they were
+        let has_additional_properties = self.config.additional_properties.contains(&struct_name.to_camel_case());
+        if has_additional_properties {
+            props.extend(quote! {
+                #[serde(flatten)]
+                pub additional: std::collections::HashMap<String, serde_json::Value>,
+            });
+        }
+
+        let derive = if self.config.hashable_models
+            && !has_additional_properties
+            && self.is_struct_hashable(doc_file, &schema.schema, &mut HashSet::new())?
+        {
+            quote! { #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)] }
+        } else {
+            quote! { #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)] }
+        };
         let st = quote! {
-            #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+            #derive
             pub struct #nm {
                 #props
             }
@@ -290,6 +475,96 @@ impl CodeGen {
         Ok(streams)
     }
 
+    /// Whether the struct generated for a schema can derive `Eq` and `Hash`.
+    ///
+    /// That is the case when none of its fields, including flattened `allOf` ones, is or
+    /// contains a floating point number or a `serde_json::Value`.
+    fn is_struct_hashable(&self, doc_file: &Path, schema: &Schema, visiting: &mut HashSet<RefKey>) -> Result<bool> {
+        for all_of in self.spec.resolve_schemas(doc_file, &schema.all_of).map_err(Error::SpecError)? {
+            if !self.is_field_hashable(doc_file, &all_of, visiting)? {
+                return Ok(false);
+            }
+        }
+        for (property_name, property) in &self
+            .spec
+            .resolve_schema_map(doc_file, &schema.properties)
+            .map_err(Error::SpecError)?
+        {
+            // newtypes always derive `Eq` and `Hash`
+            if self.property_newtype(property_name).is_none() && !self.is_field_hashable(doc_file, property, visiting)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether the type of a struct field can derive `Eq` and `Hash`.
+    /// Mirrors the types chosen by `create_struct_field_type`.
+    fn is_field_hashable(&self, doc_file: &Path, property: &ResolvedSchema, visiting: &mut HashSet<RefKey>) -> Result<bool> {
+        match &property.ref_key {
+            Some(ref_key) => self.is_named_type_hashable(ref_key, &property.schema, visiting),
+            None => {
+                if is_local_enum(property) {
+                    Ok(true)
+                } else if is_local_struct(property) {
+                    self.is_struct_hashable(doc_file, &property.schema, visiting)
+                } else {
+                    self.is_schema_type_hashable(doc_file, &property.schema.common, visiting)
+                }
+            }
+        }
+    }
+
+    /// Whether the type generated for a named schema, as done by `create_models`, can derive `Eq` and `Hash`.
+    fn is_named_type_hashable(&self, ref_key: &RefKey, schema: &Schema, visiting: &mut HashSet<RefKey>) -> Result<bool> {
+        if let Some(hashable) = self.hashable.borrow().get(ref_key) {
+            return Ok(*hashable);
+        }
+        // a recursive type is hashable if the rest of it is
+        if !visiting.insert(ref_key.clone()) {
+            return Ok(true);
+        }
+        let doc_file = &ref_key.file_path;
+        let hashable = if is_array(&schema.common) {
+            self.is_schema_type_hashable(doc_file, &schema.common, visiting)?
+        } else if !schema.common.enum_.is_empty() {
+            true
+        } else if self.config.additional_properties.contains(&ref_key.name.to_camel_case()) {
+            // the `additional` map is not hashable
+            false
+        } else {
+            self.is_struct_hashable(doc_file, schema, visiting)?
+        };
+        visiting.remove(ref_key);
+        // a positive answer may rely on an assumption made for a type still being visited
+        if !hashable || visiting.is_empty() {
+            self.hashable.borrow_mut().insert(ref_key.clone(), hashable);
+        }
+        Ok(hashable)
+    }
+
+    /// Whether the type chosen by `get_type_name_for_schema` can derive `Eq` and `Hash`.
+    fn is_schema_type_hashable(&self, doc_file: &Path, schema: &SchemaCommon, visiting: &mut HashSet<RefKey>) -> Result<bool> {
+        match &schema.type_ {
+            Some(DataType::Array) => match get_schema_array_items(schema)? {
+                ReferenceOr::Reference { reference, .. } => {
+                    let items = self
+                        .spec
+                        .resolve_schema_ref(doc_file, reference.clone())
+                        .map_err(Error::SpecError)?;
+                    match &items.ref_key {
+                        Some(ref_key) => self.is_named_type_hashable(ref_key, &items.schema, visiting),
+                        None => self.is_schema_type_hashable(doc_file, &items.schema.common, visiting),
+                    }
+                }
+                ReferenceOr::Item(items) => self.is_schema_type_hashable(doc_file, &items.common, visiting),
+            },
+            Some(DataType::Integer) | Some(DataType::String) | Some(DataType::Boolean) => Ok(true),
+            // f32, f64 and serde_json::Value
+            Some(DataType::Number) | Some(DataType::Object) | Some(DataType::File) | None => Ok(false),
+        }
+    }
+
     /// Creates the type reference for a struct field from a struct property.
     /// Optionally, creates a type for a local schema.
     fn create_struct_field_type(
@@ -310,7 +585,13 @@ impl CodeGen {
             }
             None => {
                 if is_local_enum(property) {
-                    let (tp_name, tp) = create_enum(namespace, property_name, property)?;
+                    let (tp_name, tp) = create_enum(
+                        namespace,
+                        property_name,
+                        property,
+                        self.config.non_exhaustive_enums,
+                        self.config.hashable_models,
+                    )?;
                     Ok((tp_name, vec![tp]))
                 } else if is_local_struct(property) {
                     let id = ident(&property_name.to_camel_case()).map_err(|source| Error::IdentError {
@@ -360,6 +641,84 @@ pub enum AsReference {
     False,
 }
 
+/// Creates a newtype wrapping an unsigned integer primitive, with checked conversions from the signed
+/// integers a spec may use for it and a `Sum` panicking on overflow.
+fn create_newtype(newtype: &Newtype) -> Result<TokenStream> {
+    let nm = ident(&newtype.name).map_err(|source| Error::IdentError {
+        source,
+        file: file!(),
+        line: line!(),
+    })?;
+    let inner = ident(&newtype.inner).map_err(|source| Error::IdentError {
+        source,
+        file: file!(),
+        line: line!(),
+    })?;
+    let overflow = format!("{} overflow", newtype.name);
+    Ok(quote! {
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct #nm(#inner);
+        impl #nm {
+            pub fn new(value: #inner) -> Self {
+                Self(value)
+            }
+            pub fn get(self) -> #inner {
+                self.0
+            }
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+        }
+        impl From<#inner> for #nm {
+            fn from(value: #inner) -> Self {
+                Self(value)
+            }
+        }
+        impl From<#nm> for #inner {
+            fn from(value: #nm) -> Self {
+                value.0
+            }
+        }
+        impl std::convert::TryFrom<i32> for #nm {
+            type Error = std::num::TryFromIntError;
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                <#inner as std::convert::TryFrom<i32>>::try_from(value).map(Self)
+            }
+        }
+        impl std::convert::TryFrom<i64> for #nm {
+            type Error = std::num::TryFromIntError;
+            fn try_from(value: i64) -> Result<Self, Self::Error> {
+                <#inner as std::convert::TryFrom<i64>>::try_from(value).map(Self)
+            }
+        }
+        impl std::iter::Sum for #nm {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |sum, value| sum.checked_add(value).expect(#overflow))
+            }
+        }
+        impl<'a> std::iter::Sum<&'a #nm> for #nm {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    })
+}
+
+/// The type of a field using a newtype, a `Vec` of it for arrays.
+fn create_newtype_field_type(newtype: &Newtype, property: &ResolvedSchema) -> Result<TokenStream> {
+    let nm = ident(&newtype.name).map_err(|source| Error::IdentError {
+        source,
+        file: file!(),
+        line: line!(),
+    })?;
+    if is_array(&property.schema.common) {
+        Ok(quote! { Vec<#nm> })
+    } else {
+        Ok(quote! { #nm })
+    }
+}
+
 fn is_vec(ts: &TokenStream) -> bool {
     ts.to_string().starts_with("Vec <")
 }
@@ -368,6 +727,10 @@ fn is_array(schema: &SchemaCommon) -> bool {
     matches!(schema.type_, Some(DataType::Array))
 }
 
+fn is_integer(schema: &SchemaCommon) -> bool {
+    matches!(schema.type_, Some(DataType::Integer))
+}
+
 fn is_string(schema: &SchemaCommon) -> bool {
     matches!(schema.type_, Some(DataType::String))
 }
@@ -390,7 +753,13 @@ fn is_local_struct(property: &ResolvedSchema) -> bool {
     property.schema.properties.len() > 0
 }
 
-fn create_enum(namespace: &TokenStream, property_name: &str, property: &ResolvedSchema) -> Result<(TokenStream, TokenStream)> {
+fn create_enum(
+    namespace: &TokenStream,
+    property_name: &str,
+    property: &ResolvedSchema,
+    non_exhaustive: bool,
+    hashable: bool,
+) -> Result<(TokenStream, TokenStream)> {
     let enum_values = enum_values_as_strings(&property.schema.common.enum_);
     let id = ident(&property_name.to_camel_case()).map_err(|source| Error::IdentError {
         source,
@@ -398,7 +767,8 @@ fn create_enum(namespace: &TokenStream, property_name: &str, property: &Resolved
         line: line!(),
     })?;
     let mut values = TokenStream::new();
-    for name in enum_values {
+    let mut poll_results = TokenStream::new();
+    for name in enum_values.iter().copied() {
         let nm = name.to_camel_case_ident().map_err(|source| Error::CreateEnumIdentError {
             source,
             property_name: property_name.to_owned(),
@@ -414,22 +784,108 @@ fn create_enum(namespace: &TokenStream, property_name: &str, property: &Resolved
             #nm,
         };
         values.extend(value);
+        let poll_result = poll_result(name);
+        poll_results.extend(quote! {
+            Self::#nm => #poll_result,
+        });
     }
     let nm = ident(&property_name.to_camel_case()).map_err(|source| Error::IdentError {
         source,
         file: file!(),
         line: line!(),
     })?;
+    // non_exhaustive so that variants added to the specification are not breaking changes
+    let non_exhaustive = if non_exhaustive {
+        quote! { #[non_exhaustive] }
+    } else {
+        quote! {}
+    };
+    let derive = if hashable {
+        quote! { #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)] }
+    } else {
+        quote! { #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)] }
+    };
     let tp = quote! {
-        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        #derive
+        #non_exhaustive
         pub enum #nm {
             #values
         }
     };
+    // the provisioning states are what the long-running operations are polled with
+    let tp = if property_name.to_camel_case() == "ProvisioningState" {
+        quote! {
+            #tp
+            impl azure_core::IntoPollResult for #nm {
+                fn into_poll_result(&self) -> azure_core::PollResult {
+                    match self {
+                        #poll_results
+                    }
+                }
+            }
+        }
+    } else {
+        tp
+    };
     let tp_name = quote! {#namespace::#id};
     Ok((tp_name, tp))
 }
 
+fn is_provisioning_state(property_name: &str) -> bool {
+    property_name.to_camel_case() == "ProvisioningState"
+}
+
+/// `From` conversions between the provisioning states of a package that have the same values, such
+/// as the ones of a resource and of its private endpoint connections.
+fn create_provisioning_state_conversions(provisioning_states: &[(TokenStream, Vec<String>)]) -> Result<TokenStream> {
+    let mut conversions = TokenStream::new();
+    for (from, from_values) in provisioning_states {
+        for (into, into_values) in provisioning_states {
+            let mut sorted_from_values = from_values.clone();
+            sorted_from_values.sort_unstable();
+            let mut sorted_into_values = into_values.clone();
+            sorted_into_values.sort_unstable();
+            if from.to_string() == into.to_string() || sorted_from_values != sorted_into_values {
+                continue;
+            }
+            let mut arms = TokenStream::new();
+            for name in from_values {
+                let nm = name.to_camel_case_ident().map_err(|source| Error::CreateEnumIdentError {
+                    source,
+                    property_name: "provisioningState".to_owned(),
+                    enum_value: name.to_owned(),
+                })?;
+                arms.extend(quote! {
+                    #from::#nm => Self::#nm,
+                });
+            }
+            conversions.extend(quote! {
+                impl From<#from> for #into {
+                    fn from(state: #from) -> Self {
+                        match state {
+                            #arms
+                        }
+                    }
+                }
+            });
+        }
+    }
+    Ok(conversions)
+}
+
+/// What a provisioning state means for the operation that is polled: `Succeeded` and the other
+/// final states of a resource are done, `Failed`, `Canceled` and the service-specific failures
+/// (`BillingFailed`, `Expired`...) failed, and any other state is still in progress.
+fn poll_result(provisioning_state: &str) -> TokenStream {
+    let state = provisioning_state.to_camel_case();
+    match state.as_str() {
+        "Succeeded" | "Completed" | "Deleted" | "Split" | "Merged" => quote! { azure_core::PollResult::Done },
+        "Failed" | "Canceled" | "Cancelled" | "Expired" => quote! { azure_core::PollResult::Failed(#provisioning_state.to_owned()) },
+        _ if state.ends_with("Failed") => quote! { azure_core::PollResult::Failed(#provisioning_state.to_owned()) },
+        _ => quote! { azure_core::PollResult::InProgress },
+    }
+}
+
 /// Wraps a type in an Option if is not required.
 fn require(is_required: bool, tp: TokenStream) -> TokenStream {
     if is_required {
@@ -439,6 +895,13 @@ fn require(is_required: bool, tp: TokenStream) -> TokenStream {
     }
 }
 
+fn enum_values(schema: &ResolvedSchema) -> Vec<String> {
+    enum_values_as_strings(&schema.schema.common.enum_)
+        .into_iter()
+        .map(str::to_owned)
+        .collect()
+}
+
 fn enum_values_as_strings(values: &Vec<Value>) -> Vec<&str> {
     values
         .iter()
@@ -576,6 +1039,7 @@ fn create_function(
     operation_verb: &OperationVerb,
     param_re: &Regex,
     function_name: &str,
+    validated_body_types: &HashSet<String>,
 ) -> Result<TokenStream> {
     let fname = ident(function_name).map_err(|source| Error::IdentError {
         source,
@@ -655,6 +1119,7 @@ fn create_function(
 
     // params
     let mut has_body_parameter = false;
+    let mut is_body_validated = false;
     for param in &parameters {
         let param_name = &param.name;
         let param_name_var = get_param_name(&param)?;
@@ -724,14 +1189,28 @@ fn create_function(
             }
             ParameterType::Body => {
                 has_body_parameter = true;
+                let validate = match &param.schema {
+                    Some(ReferenceOr::Reference { reference, .. })
+                        if reference
+                            .name
+                            .as_ref()
+                            .map_or(false, |name| validated_body_types.contains(&name.to_camel_case())) =>
+                    {
+                        is_body_validated = true;
+                        quote! { #param_name_var.validate().map_err(#fname::Error::ValidationError)?; }
+                    }
+                    _ => quote! {},
+                };
                 if required {
                     ts_request_builder.extend(quote! {
+                        #validate
                         let req_body = azure_core::to_json(#param_name_var).map_err(#fname::Error::SerializeError)?;
                     });
                 } else {
                     ts_request_builder.extend(quote! {
                         let req_body =
                             if let Some(#param_name_var) = #param_name_var {
+                                #validate
                                 azure_core::to_json(#param_name_var).map_err(#fname::Error::SerializeError)?
                             } else {
                                 bytes::Bytes::from_static(azure_core::EMPTY_BODY)
@@ -968,6 +1447,15 @@ fn create_function(
         });
     }
 
+    let validation_error = if is_body_validated {
+        quote! {
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
+        }
+    } else {
+        quote! {}
+    };
+
     let func = quote! {
         pub async fn #fname(#fparams) -> #fresponse {
             let http_client = operation_config.http_client();
@@ -996,6 +1484,7 @@ fn create_function(
                 BuildRequestError(http::Error),
                 #[error("Failed to execute request: {0}")]
                 ExecuteRequestError(azure_core::HttpError),
+                #validation_error
                 #[error("Failed to serialize request body: {0}")]
                 SerializeError(serde_json::Error),
                 #[error("Failed to deserialize response: {0}, body: {1:?}")]
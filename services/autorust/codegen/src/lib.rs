@@ -53,12 +53,34 @@ pub struct PropertyName {
     pub property_name: String,
 }
 
+/// A tuple struct wrapping a primitive, used instead of the spec types for some properties.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Newtype {
+    /// name of the generated type, replacing any schema of the same name
+    pub name: String,
+    /// the wrapped primitive, such as `u32`
+    pub inner: String,
+    /// names of the properties, in any schema, using the newtype (or a `Vec` of it for arrays)
+    pub property_names: Vec<String>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Config {
     pub input_files: Vec<PathBuf>,
     pub output_folder: PathBuf,
     pub api_version: Option<String>,
     pub box_properties: HashSet<PropertyName>,
+    pub newtypes: Vec<Newtype>,
+    /// names of the schemas whose fields missing from the spec are kept in an `additional` map
+    pub additional_properties: HashSet<String>,
+    /// names of the integer properties, in any schema, also accepted as numeric strings
+    pub numbers_as_strings: HashSet<String>,
+    /// whether the generated enums are marked `#[non_exhaustive]`
+    pub non_exhaustive_enums: bool,
+    /// whether the generated enums, and the structs without floating point or untyped fields, derive `Eq` and `Hash`
+    pub hashable_models: bool,
+    /// whether the request bodies get a `validate` method, called by the operations before sending them
+    pub validate_bodies: bool,
 }
 
 pub fn run(config: Config) -> Result<()> {
@@ -18,12 +18,13 @@ pub enum Error {
     WriteFileError { source: crate::Error },
 }
 
-pub fn create(feature_mod_names: &Vec<(String, String)>, path: &Path) -> Result<()> {
-    write_file(path, &create_body(feature_mod_names)?).map_err(|source| Error::WriteFileError { source })?;
+/// Creates the `lib.rs` of a crate, also declaring and exporting its hand-written modules.
+pub fn create(feature_mod_names: &Vec<(String, String)>, hand_written_mod_names: &[String], path: &Path) -> Result<()> {
+    write_file(path, &create_body(feature_mod_names, hand_written_mod_names)?).map_err(|source| Error::WriteFileError { source })?;
     Ok(())
 }
 
-fn create_body(feature_mod_names: &Vec<(String, String)>) -> Result<TokenStream> {
+fn create_body(feature_mod_names: &Vec<(String, String)>, hand_written_mod_names: &[String]) -> Result<TokenStream> {
     let mut cfgs = TokenStream::new();
     for (feature_name, mod_name) in feature_mod_names {
         let mod_name = ident(mod_name).map_err(|source| Error::IdentModNameError {
@@ -38,11 +39,30 @@ fn create_body(feature_mod_names: &Vec<(String, String)>) -> Result<TokenStream>
             pub use #mod_name::{models, operations, API_VERSION};
         });
     }
+    // the hand-written modules follow the generated ones, built with any of the packages
+    let feature_names: Vec<_> = feature_mod_names.iter().map(|(feature_name, _)| feature_name).collect();
+    let mut hand_written_mods = TokenStream::new();
+    for mod_name in hand_written_mod_names {
+        let mod_name = ident(mod_name).map_err(|source| Error::IdentModNameError {
+            source,
+            feature_name: String::new(),
+            mod_name: mod_name.to_owned(),
+        })?;
+        // a module may only add methods to the models, with nothing to export
+        hand_written_mods.extend(quote! {
+            #[cfg(any(#(feature = #feature_names),*))]
+            mod #mod_name;
+            #[cfg(any(#(feature = #feature_names),*))]
+            #[allow(unused_imports)]
+            pub use #mod_name::*;
+        });
+    }
     let generated_by = create_generated_by_header();
     Ok(quote! {
         #generated_by
         #cfgs
         use azure_core::setters;
+        pub use azure_core::JsonValueExt;
 
         pub fn config(
             http_client: std::sync::Arc<dyn azure_core::HttpClient>,
@@ -75,19 +95,20 @@ fn create_body(feature_mod_names: &Vec<(String, String)>) -> Result<TokenStream>
             pub fn build(self) -> OperationConfig {
                 OperationConfig {
                     api_version: self.api_version.unwrap_or(API_VERSION.to_owned()),
-                    http_client: self.http_client,
+                    http_client: std::sync::Arc::new(azure_core::ArmThrottlingHttpClient::new(self.http_client)),
                     base_path: self.base_path.unwrap_or("https://management.azure.com".to_owned()),
-                    token_credential: Some(self.token_credential),
+                    token_credential: Some(self.token_credential.into()),
                     token_credential_resource: self.token_credential_resource.unwrap_or("https://management.azure.com/".to_owned()),
                 }
             }
         }
 
+        #[derive(Clone)]
         pub struct OperationConfig {
             api_version: String,
             http_client: std::sync::Arc<dyn azure_core::HttpClient>,
             base_path: String,
-            token_credential: Option<Box<dyn azure_core::TokenCredential>>,
+            token_credential: Option<std::sync::Arc<dyn azure_core::TokenCredential>>,
             token_credential_resource: String,
         }
 
@@ -95,6 +116,12 @@ fn create_body(feature_mod_names: &Vec<(String, String)>) -> Result<TokenStream>
             pub fn api_version(&self) -> &str {
                 self.api_version.as_str()
             }
+            pub fn with_api_version(&self, api_version: impl Into<String>) -> Self {
+                Self {
+                    api_version: api_version.into(),
+                    ..self.clone()
+                }
+            }
             pub fn http_client(&self) -> &dyn azure_core::HttpClient {
                 self.http_client.as_ref()
             }
@@ -108,5 +135,7 @@ fn create_body(feature_mod_names: &Vec<(String, String)>) -> Result<TokenStream>
                 self.token_credential_resource.as_str()
             }
         }
+
+        #hand_written_mods
     })
 }
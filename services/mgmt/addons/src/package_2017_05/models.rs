@@ -19,6 +19,19 @@ pub mod canonical_support_plan_properties {
         Cancelling,
         Upgrading,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Cancelled => azure_core::PollResult::Failed("Cancelled".to_owned()),
+                Self::Purchasing => azure_core::PollResult::InProgress,
+                Self::Downgrading => azure_core::PollResult::InProgress,
+                Self::Cancelling => azure_core::PollResult::InProgress,
+                Self::Upgrading => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CanonicalSupportPlanResponseEnvelope {
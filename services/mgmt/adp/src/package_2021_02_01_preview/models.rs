@@ -72,6 +72,18 @@ pub mod account_properties {
         Provisioning,
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AccountList {
@@ -116,6 +128,18 @@ pub mod data_pool_base_properties {
         Provisioning,
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DataPoolProperties {
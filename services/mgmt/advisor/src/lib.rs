@@ -18,6 +18,7 @@ pub use package_2017_03::{models, operations, API_VERSION};
 #[cfg(feature = "package-2016-07-preview")]
 mod package_2016_07_preview;
 use azure_core::setters;
+pub use azure_core::JsonValueExt;
 #[cfg(feature = "package-2016-07-preview")]
 pub use package_2016_07_preview::{models, operations, API_VERSION};
 pub fn config(
@@ -44,24 +45,31 @@ impl OperationConfigBuilder {
     pub fn build(self) -> OperationConfig {
         OperationConfig {
             api_version: self.api_version.unwrap_or(API_VERSION.to_owned()),
-            http_client: self.http_client,
+            http_client: std::sync::Arc::new(azure_core::ArmThrottlingHttpClient::new(self.http_client)),
             base_path: self.base_path.unwrap_or("https://management.azure.com".to_owned()),
-            token_credential: Some(self.token_credential),
+            token_credential: Some(self.token_credential.into()),
             token_credential_resource: self.token_credential_resource.unwrap_or("https://management.azure.com/".to_owned()),
         }
     }
 }
+#[derive(Clone)]
 pub struct OperationConfig {
     api_version: String,
     http_client: std::sync::Arc<dyn azure_core::HttpClient>,
     base_path: String,
-    token_credential: Option<Box<dyn azure_core::TokenCredential>>,
+    token_credential: Option<std::sync::Arc<dyn azure_core::TokenCredential>>,
     token_credential_resource: String,
 }
 impl OperationConfig {
     pub fn api_version(&self) -> &str {
         self.api_version.as_str()
     }
+    pub fn with_api_version(&self, api_version: impl Into<String>) -> Self {
+        Self {
+            api_version: api_version.into(),
+            ..self.clone()
+        }
+    }
     pub fn http_client(&self) -> &dyn azure_core::HttpClient {
         self.http_client.as_ref()
     }
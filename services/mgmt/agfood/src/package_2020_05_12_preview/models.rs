@@ -4,33 +4,33 @@
 use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExtensionProperties {
-    #[serde(rename = "extensionId", skip_serializing)]
+    #[serde(rename = "extensionId", default, skip_serializing)]
     pub extension_id: Option<String>,
-    #[serde(rename = "extensionCategory", skip_serializing)]
+    #[serde(rename = "extensionCategory", default, skip_serializing)]
     pub extension_category: Option<String>,
-    #[serde(rename = "installedExtensionVersion", skip_serializing)]
+    #[serde(rename = "installedExtensionVersion", default, skip_serializing)]
     pub installed_extension_version: Option<String>,
-    #[serde(rename = "extensionAuthLink", skip_serializing)]
+    #[serde(rename = "extensionAuthLink", default, skip_serializing)]
     pub extension_auth_link: Option<String>,
-    #[serde(rename = "extensionApiDocsLink", skip_serializing)]
+    #[serde(rename = "extensionApiDocsLink", default, skip_serializing)]
     pub extension_api_docs_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Extension {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
-    #[serde(rename = "systemData", skip_serializing)]
+    #[serde(rename = "systemData", default, skip_serializing)]
     pub system_data: Option<SystemData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<ExtensionProperties>,
-    #[serde(rename = "eTag", skip_serializing)]
+    #[serde(rename = "eTag", default, skip_serializing)]
     pub e_tag: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExtensionListResponse {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<Extension>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -53,32 +53,32 @@ pub struct DetailedInformation {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FarmBeatsExtensionProperties {
-    #[serde(rename = "targetResourceType", skip_serializing)]
+    #[serde(rename = "targetResourceType", default, skip_serializing)]
     pub target_resource_type: Option<String>,
-    #[serde(rename = "farmBeatsExtensionId", skip_serializing)]
+    #[serde(rename = "farmBeatsExtensionId", default, skip_serializing)]
     pub farm_beats_extension_id: Option<String>,
-    #[serde(rename = "farmBeatsExtensionName", skip_serializing)]
+    #[serde(rename = "farmBeatsExtensionName", default, skip_serializing)]
     pub farm_beats_extension_name: Option<String>,
-    #[serde(rename = "farmBeatsExtensionVersion", skip_serializing)]
+    #[serde(rename = "farmBeatsExtensionVersion", default, skip_serializing)]
     pub farm_beats_extension_version: Option<String>,
-    #[serde(rename = "publisherId", skip_serializing)]
+    #[serde(rename = "publisherId", default, skip_serializing)]
     pub publisher_id: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub description: Option<String>,
-    #[serde(rename = "extensionCategory", skip_serializing)]
+    #[serde(rename = "extensionCategory", default, skip_serializing)]
     pub extension_category: Option<String>,
-    #[serde(rename = "extensionAuthLink", skip_serializing)]
+    #[serde(rename = "extensionAuthLink", default, skip_serializing)]
     pub extension_auth_link: Option<String>,
-    #[serde(rename = "extensionApiDocsLink", skip_serializing)]
+    #[serde(rename = "extensionApiDocsLink", default, skip_serializing)]
     pub extension_api_docs_link: Option<String>,
-    #[serde(rename = "detailedInformation", skip_serializing)]
+    #[serde(rename = "detailedInformation", default, skip_serializing)]
     pub detailed_information: Vec<DetailedInformation>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FarmBeatsExtension {
     #[serde(flatten)]
     pub proxy_resource: ProxyResource,
-    #[serde(rename = "systemData", skip_serializing)]
+    #[serde(rename = "systemData", default, skip_serializing)]
     pub system_data: Option<SystemData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<FarmBeatsExtensionProperties>,
@@ -87,14 +87,14 @@ pub struct FarmBeatsExtension {
 pub struct FarmBeatsExtensionListResponse {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FarmBeatsExtension>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FarmBeatsProperties {
-    #[serde(rename = "instanceUri", skip_serializing)]
+    #[serde(rename = "instanceUri", default, skip_serializing)]
     pub instance_uri: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<farm_beats_properties::ProvisioningState>,
 }
 pub mod farm_beats_properties {
@@ -104,12 +104,20 @@ pub mod farm_beats_properties {
         Succeeded,
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FarmBeats {
     #[serde(flatten)]
     pub tracked_resource: TrackedResource,
-    #[serde(rename = "systemData", skip_serializing)]
+    #[serde(rename = "systemData", default, skip_serializing)]
     pub system_data: Option<SystemData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<FarmBeatsProperties>,
@@ -125,7 +133,7 @@ pub struct FarmBeatsUpdateRequestModel {
 pub struct FarmBeatsListResponse {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FarmBeats>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -135,22 +143,22 @@ pub struct ErrorResponse {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorDetail {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub code: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub message: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub target: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<ErrorDetail>,
-    #[serde(rename = "additionalInfo", skip_serializing)]
+    #[serde(rename = "additionalInfo", default, skip_serializing)]
     pub additional_info: Vec<ErrorAdditionalInfo>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ErrorAdditionalInfo {
-    #[serde(rename = "type", skip_serializing)]
+    #[serde(rename = "type", default, skip_serializing)]
     pub type_: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub info: Option<serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -179,35 +187,35 @@ pub mod check_name_availability_response {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Operation>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "isDataAction", skip_serializing)]
+    #[serde(rename = "isDataAction", default, skip_serializing)]
     pub is_data_action: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display: Option<operation::Display>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub origin: Option<operation::Origin>,
-    #[serde(rename = "actionType", skip_serializing)]
+    #[serde(rename = "actionType", default, skip_serializing)]
     pub action_type: Option<operation::ActionType>,
 }
 pub mod operation {
     use super::*;
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Display {
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub provider: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub resource: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub operation: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub description: Option<String>,
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -263,11 +271,11 @@ pub struct ProxyResource {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub id: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "type", skip_serializing)]
+    #[serde(rename = "type", default, skip_serializing)]
     pub type_: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -104,6 +104,14 @@ pub mod farm_beats_properties {
         Succeeded,
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FarmBeats {
@@ -110,6 +110,24 @@ pub mod analysis_services_server_properties {
         Preparing,
         Scaling,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Paused => azure_core::PollResult::InProgress,
+                Self::Suspended => azure_core::PollResult::InProgress,
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Suspending => azure_core::PollResult::InProgress,
+                Self::Pausing => azure_core::PollResult::InProgress,
+                Self::Resuming => azure_core::PollResult::InProgress,
+                Self::Preparing => azure_core::PollResult::InProgress,
+                Self::Scaling => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceSku {
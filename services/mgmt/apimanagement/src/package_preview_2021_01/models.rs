@@ -2790,6 +2790,13 @@ pub mod association_contract {
             #[serde(rename = "created")]
             Created,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Created => azure_core::PollResult::InProgress,
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
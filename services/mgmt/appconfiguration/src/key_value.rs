@@ -0,0 +1,97 @@
+//! Flattening key-values into a settings map, keyed the App Configuration way.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. The key-values of the
+//! api versions from 2020-07-01-preview are resources, with their key and value in their
+//! properties: it follows the ones of the earlier versions, such as the default
+//! `package-2020-06-01`.
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+use crate::models::KeyValue;
+
+/// The label of the key-values without label.
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+pub const NULL_LABEL: &str = "\0";
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+impl KeyValue {
+    /// `label/key`, or `key` for a key-value without label or with the [`NULL_LABEL`].
+    pub fn composite_key(&self) -> String {
+        let key = self.key.as_deref().unwrap_or_default();
+        match self.label.as_deref() {
+            None | Some(NULL_LABEL) => key.to_owned(),
+            Some(label) => format!("{}/{}", label, key),
+        }
+    }
+
+    /// The composite key and the value, `None` for a key-value without key or value.
+    pub fn into_pair(self) -> Option<(String, String)> {
+        self.key.as_ref()?;
+        let composite_key = self.composite_key();
+        self.value.map(|value| (composite_key, value))
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "package-2019-02-01-preview",
+        feature = "package-2019-10-01",
+        feature = "package-2019-11-01-preview",
+        feature = "package-2020-06-01"
+    )
+))]
+mod tests {
+    use super::*;
+
+    fn key_value(label: Option<&str>) -> KeyValue {
+        serde_json::from_value(serde_json::json!({
+            "key": "Settings:BackgroundColor",
+            "label": label,
+            "value": "blue",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn a_labeled_key_is_prefixed_with_its_label() {
+        let key_value = key_value(Some("production"));
+        assert_eq!(key_value.composite_key(), "production/Settings:BackgroundColor");
+        assert_eq!(
+            key_value.into_pair(),
+            Some(("production/Settings:BackgroundColor".to_owned(), "blue".to_owned()))
+        );
+    }
+
+    #[test]
+    fn an_unlabeled_key_is_the_key_alone() {
+        assert_eq!(key_value(Some(NULL_LABEL)).composite_key(), "Settings:BackgroundColor");
+        assert_eq!(
+            key_value(None).into_pair(),
+            Some(("Settings:BackgroundColor".to_owned(), "blue".to_owned()))
+        );
+    }
+
+    #[test]
+    fn a_key_value_without_value_has_no_pair() {
+        let key_value = KeyValue {
+            value: None,
+            ..key_value(None)
+        };
+        assert_eq!(key_value.into_pair(), None);
+    }
+}
@@ -0,0 +1,277 @@
+//! Key Vault references: key-values whose value is the uri of a Key Vault secret.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. It follows the
+//! key-values of the api versions before 2020-07-01-preview, such as the default
+//! `package-2020-06-01`. A reference is only resolved when asked to, with the credential it is
+//! given: listing the key-values never reads the secrets.
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+use crate::models::KeyValue;
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+use serde::{Deserialize, Serialize};
+
+/// The content type of the Key Vault references.
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+pub const KEY_VAULT_REFERENCE_CONTENT_TYPE: &str = "application/vnd.microsoft.appconfig.keyvaultref+json;charset=utf-8";
+
+/// The resource of the tokens of Key Vault.
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+const KEY_VAULT_RESOURCE: &str = "https://vault.azure.net";
+/// The api version of the secrets of Key Vault.
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+const KEY_VAULT_API_VERSION: &str = "7.1";
+
+/// The value of a Key Vault reference.
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyVaultRef {
+    /// The uri of the secret, such as `https://contoso.vault.azure.net/secrets/password`, with
+    /// or without its version.
+    pub uri: String,
+}
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+impl KeyValue {
+    /// The Key Vault reference of a key-value with the `.keyvaultref+json` content type, `None`
+    /// for the other key-values or a reference whose value is not a uri.
+    pub fn key_vault_reference(&self) -> Option<KeyVaultRef> {
+        let media_type = self.content_type.as_deref()?.split(';').next()?.trim();
+        if !media_type.to_ascii_lowercase().ends_with(".keyvaultref+json") {
+            return None;
+        }
+        serde_json::from_str(self.value.as_deref()?).ok()
+    }
+}
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+impl KeyVaultRef {
+    /// The value of the referenced secret, read with a token of `token_credential` for Key
+    /// Vault.
+    ///
+    /// Only `https` uris are resolved, so the token is never sent in clear.
+    pub async fn resolve(
+        &self,
+        http_client: &dyn azure_core::HttpClient,
+        token_credential: &dyn azure_core::TokenCredential,
+    ) -> Result<String, resolve::Error> {
+        let mut url = url::Url::parse(&self.uri).map_err(resolve::Error::ParseUrlError)?;
+        if url.scheme() != "https" {
+            return Err(resolve::Error::InsecureUri(self.uri.clone()));
+        }
+        url.query_pairs_mut().append_pair("api-version", KEY_VAULT_API_VERSION);
+        let token_response = token_credential
+            .get_token(KEY_VAULT_RESOURCE)
+            .await
+            .map_err(resolve::Error::GetTokenError)?;
+        let req = http::request::Builder::new()
+            .method(http::Method::GET)
+            .uri(url.as_str())
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()))
+            .body(bytes::Bytes::from_static(azure_core::EMPTY_BODY))
+            .map_err(resolve::Error::BuildRequestError)?;
+        let rsp = http_client
+            .execute_request(req)
+            .await
+            .map_err(resolve::Error::ExecuteRequestError)?;
+        let rsp_body = rsp.body();
+        match rsp.status() {
+            http::StatusCode::OK => {
+                let secret: resolve::SecretBundle =
+                    serde_json::from_slice(rsp_body).map_err(|source| resolve::Error::DeserializeError(source, rsp_body.clone()))?;
+                Ok(secret.value)
+            }
+            status_code => Err(resolve::Error::UnexpectedResponse {
+                status_code,
+                body: rsp_body.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "package-2019-02-01-preview",
+    feature = "package-2019-10-01",
+    feature = "package-2019-11-01-preview",
+    feature = "package-2020-06-01"
+))]
+pub mod resolve {
+    use serde::Deserialize;
+
+    /// The part of a secret of Key Vault that is read.
+    #[derive(Deserialize)]
+    pub(crate) struct SecretBundle {
+        pub(crate) value: String,
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    pub enum Error {
+        #[error("Unexpected HTTP status code {}", status_code)]
+        UnexpectedResponse { status_code: http::StatusCode, body: bytes::Bytes },
+        #[error("The Key Vault reference is not an https uri: {0}")]
+        InsecureUri(String),
+        #[error("Failed to parse request URL: {0}")]
+        ParseUrlError(url::ParseError),
+        #[error("Failed to build request: {0}")]
+        BuildRequestError(http::Error),
+        #[error("Failed to execute request: {0}")]
+        ExecuteRequestError(azure_core::HttpError),
+        #[error("Failed to deserialize response: {0}, body: {1:?}")]
+        DeserializeError(serde_json::Error, bytes::Bytes),
+        #[error("Failed to get access token: {0}")]
+        GetTokenError(azure_core::Error),
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "package-2019-02-01-preview",
+        feature = "package-2019-10-01",
+        feature = "package-2019-11-01-preview",
+        feature = "package-2020-06-01"
+    )
+))]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::{Request, Response, StatusCode};
+    use std::sync::Mutex;
+
+    fn reference(content_type: &str, value: &str) -> KeyValue {
+        serde_json::from_value(serde_json::json!({
+            "key": "Settings:Password",
+            "contentType": content_type,
+            "value": value,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn a_key_vault_reference_is_parsed() {
+        let key_value = reference(
+            KEY_VAULT_REFERENCE_CONTENT_TYPE,
+            r#"{"uri":"https://contoso.vault.azure.net/secrets/password"}"#,
+        );
+        assert_eq!(
+            key_value.key_vault_reference(),
+            Some(KeyVaultRef {
+                uri: "https://contoso.vault.azure.net/secrets/password".to_owned()
+            })
+        );
+        let plain = reference("text/plain", r#"{"uri":"https://contoso.vault.azure.net/secrets/password"}"#);
+        assert_eq!(plain.key_vault_reference(), None);
+    }
+
+    /// Records the requests and answers them with a secret.
+    #[derive(Debug, Default)]
+    struct MockVault {
+        requests: Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl azure_core::HttpClient for MockVault {
+        async fn execute_request(&self, request: Request<Bytes>) -> Result<Response<Bytes>, azure_core::HttpError> {
+            let authorization = request.headers()[http::header::AUTHORIZATION].to_str().unwrap().to_owned();
+            self.requests.lock().unwrap().push((request.uri().to_string(), authorization));
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .body(Bytes::from_static(
+                    br#"{"value":"hunter2","id":"https://contoso.vault.azure.net/secrets/password/1"}"#,
+                ))
+                .unwrap())
+        }
+
+        async fn execute_request2(&self, _request: &azure_core::Request) -> Result<azure_core::Response, azure_core::HttpError> {
+            Err(azure_core::HttpError::new_unexpected_status_code(
+                StatusCode::OK,
+                StatusCode::NOT_IMPLEMENTED,
+                "the resolution does not use the pipeline",
+            ))
+        }
+    }
+
+    /// Hands out a token named after the resource it is for.
+    struct MockCredential;
+
+    #[async_trait::async_trait]
+    impl azure_core::TokenCredential for MockCredential {
+        async fn get_token(&self, resource: &str) -> Result<azure_core::TokenResponse, azure_core::Error> {
+            Ok(azure_core::TokenResponse::new(
+                oauth2::AccessToken::new(format!("token-for-{}", resource)),
+                chrono::Utc::now(),
+            ))
+        }
+    }
+
+    #[test]
+    fn a_reference_is_resolved_from_the_vault() {
+        let vault = MockVault::default();
+        let reference = KeyVaultRef {
+            uri: "https://contoso.vault.azure.net/secrets/password".to_owned(),
+        };
+
+        let secret = futures::executor::block_on(reference.resolve(&vault, &MockCredential)).unwrap();
+
+        assert_eq!(secret, "hunter2");
+        assert_eq!(
+            *vault.requests.lock().unwrap(),
+            vec![(
+                "https://contoso.vault.azure.net/secrets/password?api-version=7.1".to_owned(),
+                "Bearer token-for-https://vault.azure.net".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn an_http_reference_is_not_resolved() {
+        let vault = MockVault::default();
+        let reference = KeyVaultRef {
+            uri: "http://contoso.vault.azure.net/secrets/password".to_owned(),
+        };
+
+        let result = futures::executor::block_on(reference.resolve(&vault, &MockCredential));
+
+        assert!(matches!(result, Err(resolve::Error::InsecureUri(_))));
+        assert!(vault.requests.lock().unwrap().is_empty());
+    }
+}
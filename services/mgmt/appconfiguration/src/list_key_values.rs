@@ -0,0 +1,236 @@
+//! Listing the key-values of a configuration store, page after page, filtered by key and label.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. Only the api versions
+//! from 2020-07-01-preview list the key-values through Azure Resource Manager, which does not
+//! filter them: the filters are applied to the listed key-values.
+
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+use crate::models::{KeyValue, KeyValueListResult};
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+use crate::operations::key_values::list_by_configuration_store;
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+use crate::OperationConfig;
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+
+/// The label of the key-values without label, for the label filters.
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+const NULL_LABEL: &str = "\0";
+
+/// The page of key-values to request next.
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+enum NextPage {
+    First,
+    SkipToken(String),
+    Done,
+}
+
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+impl OperationConfig {
+    /// The key-values of the store whose key matches `key_filter` and label `label_filter`,
+    /// following the `nextLink` of each page.
+    ///
+    /// The filters are the ones of App Configuration: comma-separated names, a trailing `*`
+    /// matching any name with that prefix and `\` escaping `*`, `,` and `\`. The label `\0`
+    /// matches the key-values without label; without label filter, all the labels match.
+    ///
+    /// An error ends the stream.
+    pub fn list_key_values<'a>(
+        &'a self,
+        subscription_id: &'a str,
+        resource_group_name: &'a str,
+        config_store_name: &'a str,
+        key_filter: &str,
+        label_filter: Option<&str>,
+    ) -> impl Stream<Item = Result<KeyValue, list_by_configuration_store::Error>> + 'a {
+        let key_filter = Filter::parse(key_filter);
+        let label_filter = label_filter.map(Filter::parse);
+        stream::unfold(NextPage::First, move |next_page| async move {
+            let skip_token = match &next_page {
+                NextPage::First => None,
+                NextPage::SkipToken(skip_token) => Some(skip_token.as_str()),
+                NextPage::Done => return None,
+            };
+            let page = list_by_configuration_store(self, subscription_id, resource_group_name, config_store_name, skip_token).await;
+            Some(match page {
+                Ok(KeyValueListResult { value, next_link }) => {
+                    let next_page = match next_link.as_deref().and_then(skip_token_of) {
+                        Some(skip_token) => NextPage::SkipToken(skip_token),
+                        None => NextPage::Done,
+                    };
+                    (Ok(value), next_page)
+                }
+                Err(error) => (Err(error), NextPage::Done),
+            })
+        })
+        .map_ok(|key_values| stream::iter(key_values.into_iter().map(Ok)))
+        .try_flatten()
+        .try_filter(move |key_value| {
+            let properties = key_value.properties.as_ref();
+            let key = properties.and_then(|properties| properties.key.as_deref()).unwrap_or_default();
+            let label = properties.and_then(|properties| properties.label.as_deref()).unwrap_or(NULL_LABEL);
+            let matches = key_filter.matches(key) && label_filter.as_ref().map_or(true, |filter| filter.matches(label));
+            futures::future::ready(matches)
+        })
+        .boxed()
+    }
+}
+
+/// The `$skipToken` of the `nextLink` of a page.
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+fn skip_token_of(next_link: &str) -> Option<String> {
+    let url = url::Url::parse(next_link).ok()?;
+    let skip_token = url.query_pairs().find(|(name, _)| name == "$skipToken")?.1;
+    Some(skip_token.into_owned())
+}
+
+/// A key or label filter: the names, or name prefixes, it matches.
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+#[derive(Debug, PartialEq)]
+struct Filter(Vec<Pattern>);
+
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+#[derive(Debug, PartialEq)]
+enum Pattern {
+    Name(String),
+    Prefix(String),
+}
+
+#[cfg(any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview"))]
+impl Filter {
+    fn parse(filter: &str) -> Self {
+        let mut patterns = Vec::new();
+        let mut name = String::new();
+        // an unescaped `*`, which makes a prefix if it ends the name
+        let mut is_prefix = false;
+        let mut chars = filter.chars();
+        loop {
+            let c = chars.next();
+            if is_prefix && !matches!(c, None | Some(',')) {
+                name.push('*');
+                is_prefix = false;
+            }
+            match c {
+                Some('\\') => name.extend(chars.next()),
+                Some('*') => is_prefix = true,
+                Some(',') | None => {
+                    let name = std::mem::take(&mut name);
+                    patterns.push(match std::mem::take(&mut is_prefix) {
+                        true => Pattern::Prefix(name),
+                        false => Pattern::Name(name),
+                    });
+                    if c.is_none() {
+                        return Self(patterns);
+                    }
+                }
+                Some(c) => name.push(c),
+            }
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.0.iter().any(|pattern| match pattern {
+            Pattern::Name(pattern) => name == pattern,
+            Pattern::Prefix(prefix) => name.starts_with(prefix.as_str()),
+        })
+    }
+}
+
+#[cfg(all(test, any(feature = "package-2020-07-01-preview", feature = "package-2021-03-01-preview")))]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::{Request, Response, StatusCode};
+    use std::sync::{Arc, Mutex};
+
+    /// Answers the requests with `responses`, in order, and records their uris.
+    #[derive(Debug, Default)]
+    struct MockTransport {
+        responses: Mutex<Vec<&'static str>>,
+        uris: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl azure_core::HttpClient for MockTransport {
+        async fn execute_request(&self, request: Request<Bytes>) -> Result<Response<Bytes>, azure_core::HttpError> {
+            self.uris.lock().unwrap().push(request.uri().to_string());
+            let body = self.responses.lock().unwrap().remove(0);
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .body(Bytes::from_static(body.as_bytes()))
+                .unwrap())
+        }
+
+        async fn execute_request2(&self, _request: &azure_core::Request) -> Result<azure_core::Response, azure_core::HttpError> {
+            Err(azure_core::HttpError::new_unexpected_status_code(
+                StatusCode::OK,
+                StatusCode::NOT_IMPLEMENTED,
+                "the operations do not use the pipeline",
+            ))
+        }
+    }
+
+    #[test]
+    fn key_values_are_listed_across_pages_and_filtered_by_label() {
+        let transport = Arc::new(MockTransport {
+            responses: Mutex::new(vec![
+                r#"{
+                    "value": [
+                        { "properties": { "key": "app:color", "label": "production", "value": "blue" } },
+                        { "properties": { "key": "app:color", "label": "staging", "value": "red" } }
+                    ],
+                    "nextLink": "https://management.azure.com/subscriptions/sub/resourceGroups/rg/providers/Microsoft.AppConfiguration/configurationStores/contoso/keyValues?api-version=2021-03-01-preview&$skipToken=page2"
+                }"#,
+                r#"{
+                    "value": [
+                        { "properties": { "key": "app:size", "label": "production", "value": "10" } },
+                        { "properties": { "key": "other", "label": "production", "value": "x" } }
+                    ]
+                }"#,
+            ]),
+            ..MockTransport::default()
+        });
+        let config = OperationConfig {
+            api_version: crate::API_VERSION.to_owned(),
+            http_client: transport.clone(),
+            base_path: "https://management.azure.com".to_owned(),
+            token_credential: None,
+            token_credential_resource: "https://management.azure.com/".to_owned(),
+        };
+
+        let key_values: Vec<KeyValue> = futures::executor::block_on(
+            config
+                .list_key_values("sub", "rg", "contoso", "app:*", Some("production"))
+                .try_collect(),
+        )
+        .unwrap();
+
+        let values: Vec<_> = key_values
+            .iter()
+            .map(|key_value| key_value.properties.as_ref().unwrap().value.as_deref().unwrap())
+            .collect();
+        assert_eq!(values, vec!["blue", "10"]);
+        let uris = transport.uris.lock().unwrap();
+        assert_eq!(uris.len(), 2);
+        assert!(uris[1].ends_with("&%24skipToken=page2"));
+    }
+
+    #[test]
+    fn filters_are_comma_separated_names_and_prefixes() {
+        let filter = Filter::parse(r"app:*,exact,star\*,comma\,name");
+        assert_eq!(
+            filter,
+            Filter(vec![
+                Pattern::Prefix("app:".to_owned()),
+                Pattern::Name("exact".to_owned()),
+                Pattern::Name("star*".to_owned()),
+                Pattern::Name("comma,name".to_owned()),
+            ])
+        );
+        assert!(filter.matches("app:color"));
+        assert!(filter.matches("star*"));
+        assert!(!filter.matches("starlight"));
+        assert!(Filter::parse("*").matches("anything"));
+        assert!(Filter::parse(NULL_LABEL).matches(NULL_LABEL));
+    }
+}
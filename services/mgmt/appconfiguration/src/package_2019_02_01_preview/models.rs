@@ -16,6 +16,11 @@ pub struct ConfigurationStore {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<ConfigurationStoreProperties>,
 }
+impl ConfigurationStore {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationStoreProperties {
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
@@ -24,6 +29,8 @@ pub struct ConfigurationStoreProperties {
     pub creation_date: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub endpoint: Option<String>,
+    #[serde(flatten)]
+    pub additional: std::collections::HashMap<String, serde_json::Value>,
 }
 pub mod configuration_store_properties {
     use super::*;
@@ -36,6 +43,18 @@ pub mod configuration_store_properties {
         Failed,
         Canceled,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationStoreUpdateParameters {
@@ -44,6 +63,11 @@ pub struct ConfigurationStoreUpdateParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
 }
+impl ConfigurationStoreUpdateParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationStorePropertiesUpdateParameters {}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -60,6 +84,14 @@ pub mod check_name_availability_parameters {
         MicrosoftAppConfigurationConfigurationStores,
     }
 }
+impl CheckNameAvailabilityParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        if self.name.is_empty() {
+            return Err(azure_core::ValidationError::EmptyRequiredField("name"));
+        }
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NameAvailabilityStatus {
     #[serde(rename = "nameAvailable", default, skip_serializing_if = "Option::is_none")]
@@ -96,12 +128,25 @@ pub struct RegenerateKeyParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 }
+impl RegenerateKeyParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListKeyValueParameters {
     pub key: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
 }
+impl ListKeyValueParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        if self.key.is_empty() {
+            return Err(azure_core::ValidationError::EmptyRequiredField("key"));
+        }
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyValue {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -248,6 +248,9 @@ pub mod configuration_stores {
             req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
         }
         url.query_pairs_mut().append_pair("api-version", operation_config.api_version());
+        config_store_creation_parameters
+            .validate()
+            .map_err(create::Error::ValidationError)?;
         let req_body = azure_core::to_json(config_store_creation_parameters).map_err(create::Error::SerializeError)?;
         req_builder = req_builder.uri(url.as_str());
         let req = req_builder.body(req_body).map_err(create::Error::BuildRequestError)?;
@@ -296,6 +299,8 @@ pub mod configuration_stores {
             BuildRequestError(http::Error),
             #[error("Failed to execute request: {0}")]
             ExecuteRequestError(azure_core::HttpError),
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
             #[error("Failed to serialize request body: {0}")]
             SerializeError(serde_json::Error),
             #[error("Failed to deserialize response: {0}, body: {1:?}")]
@@ -330,6 +335,7 @@ pub mod configuration_stores {
             req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
         }
         url.query_pairs_mut().append_pair("api-version", operation_config.api_version());
+        config_store_update_parameters.validate().map_err(update::Error::ValidationError)?;
         let req_body = azure_core::to_json(config_store_update_parameters).map_err(update::Error::SerializeError)?;
         req_builder = req_builder.uri(url.as_str());
         let req = req_builder.body(req_body).map_err(update::Error::BuildRequestError)?;
@@ -378,6 +384,8 @@ pub mod configuration_stores {
             BuildRequestError(http::Error),
             #[error("Failed to execute request: {0}")]
             ExecuteRequestError(azure_core::HttpError),
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
             #[error("Failed to serialize request body: {0}")]
             SerializeError(serde_json::Error),
             #[error("Failed to deserialize response: {0}, body: {1:?}")]
@@ -563,6 +571,9 @@ pub mod configuration_stores {
             req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
         }
         url.query_pairs_mut().append_pair("api-version", operation_config.api_version());
+        regenerate_key_parameters
+            .validate()
+            .map_err(regenerate_key::Error::ValidationError)?;
         let req_body = azure_core::to_json(regenerate_key_parameters).map_err(regenerate_key::Error::SerializeError)?;
         req_builder = req_builder.uri(url.as_str());
         let req = req_builder.body(req_body).map_err(regenerate_key::Error::BuildRequestError)?;
@@ -603,6 +614,8 @@ pub mod configuration_stores {
             BuildRequestError(http::Error),
             #[error("Failed to execute request: {0}")]
             ExecuteRequestError(azure_core::HttpError),
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
             #[error("Failed to serialize request body: {0}")]
             SerializeError(serde_json::Error),
             #[error("Failed to deserialize response: {0}, body: {1:?}")]
@@ -637,6 +650,9 @@ pub mod configuration_stores {
             req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
         }
         url.query_pairs_mut().append_pair("api-version", operation_config.api_version());
+        list_key_value_parameters
+            .validate()
+            .map_err(list_key_value::Error::ValidationError)?;
         let req_body = azure_core::to_json(list_key_value_parameters).map_err(list_key_value::Error::SerializeError)?;
         req_builder = req_builder.uri(url.as_str());
         let req = req_builder.body(req_body).map_err(list_key_value::Error::BuildRequestError)?;
@@ -677,6 +693,8 @@ pub mod configuration_stores {
             BuildRequestError(http::Error),
             #[error("Failed to execute request: {0}")]
             ExecuteRequestError(azure_core::HttpError),
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
             #[error("Failed to serialize request body: {0}")]
             SerializeError(serde_json::Error),
             #[error("Failed to deserialize response: {0}, body: {1:?}")]
@@ -710,6 +728,9 @@ pub mod operations {
             req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
         }
         url.query_pairs_mut().append_pair("api-version", operation_config.api_version());
+        check_name_availability_parameters
+            .validate()
+            .map_err(check_name_availability::Error::ValidationError)?;
         let req_body = azure_core::to_json(check_name_availability_parameters).map_err(check_name_availability::Error::SerializeError)?;
         req_builder = req_builder.uri(url.as_str());
         let req = req_builder
@@ -752,6 +773,8 @@ pub mod operations {
             BuildRequestError(http::Error),
             #[error("Failed to execute request: {0}")]
             ExecuteRequestError(azure_core::HttpError),
+            #[error("Invalid request body: {0}")]
+            ValidationError(azure_core::ValidationError),
             #[error("Failed to serialize request body: {0}")]
             SerializeError(serde_json::Error),
             #[error("Failed to deserialize response: {0}, body: {1:?}")]
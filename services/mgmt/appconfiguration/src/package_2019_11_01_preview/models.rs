@@ -19,6 +19,11 @@ pub struct ConfigurationStore {
     pub properties: Option<ConfigurationStoreProperties>,
     pub sku: Sku,
 }
+impl ConfigurationStore {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationStoreProperties {
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
@@ -33,6 +38,8 @@ pub struct ConfigurationStoreProperties {
     pub private_endpoint_connections: Vec<PrivateEndpointConnectionReference>,
     #[serde(rename = "publicNetworkAccess", default, skip_serializing_if = "Option::is_none")]
     pub public_network_access: Option<configuration_store_properties::PublicNetworkAccess>,
+    #[serde(flatten)]
+    pub additional: std::collections::HashMap<String, serde_json::Value>,
 }
 pub mod configuration_store_properties {
     use super::*;
@@ -45,6 +52,18 @@ pub mod configuration_store_properties {
         Failed,
         Canceled,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum PublicNetworkAccess {
         Enabled,
@@ -85,6 +104,11 @@ pub struct ConfigurationStoreUpdateParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<serde_json::Value>,
 }
+impl ConfigurationStoreUpdateParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationStorePropertiesUpdateParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -104,6 +128,14 @@ pub mod check_name_availability_parameters {
         MicrosoftAppConfigurationConfigurationStores,
     }
 }
+impl CheckNameAvailabilityParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        if self.name.is_empty() {
+            return Err(azure_core::ValidationError::EmptyRequiredField("name"));
+        }
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NameAvailabilityStatus {
     #[serde(rename = "nameAvailable", default, skip_serializing_if = "Option::is_none")]
@@ -140,12 +172,25 @@ pub struct RegenerateKeyParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 }
+impl RegenerateKeyParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListKeyValueParameters {
     pub key: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
 }
+impl ListKeyValueParameters {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        if self.key.is_empty() {
+            return Err(azure_core::ValidationError::EmptyRequiredField("key"));
+        }
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeyValue {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -260,6 +305,11 @@ pub struct PrivateEndpointConnection {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<PrivateEndpointConnectionProperties>,
 }
+impl PrivateEndpointConnection {
+    pub fn validate(&self) -> std::result::Result<(), azure_core::ValidationError> {
+        Ok(())
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateEndpointConnectionProperties {
     #[serde(rename = "provisioningState", default, skip_serializing_if = "Option::is_none")]
@@ -280,6 +330,18 @@ pub mod private_endpoint_connection_properties {
         Failed,
         Canceled,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateEndpoint {
@@ -337,3 +399,27 @@ pub struct PrivateLinkResourceProperties {
     #[serde(rename = "requiredZoneNames", default, skip_serializing_if = "Vec::is_empty")]
     pub required_zone_names: Vec<String>,
 }
+impl From<configuration_store_properties::ProvisioningState> for private_endpoint_connection_properties::ProvisioningState {
+    fn from(state: configuration_store_properties::ProvisioningState) -> Self {
+        match state {
+            configuration_store_properties::ProvisioningState::Creating => Self::Creating,
+            configuration_store_properties::ProvisioningState::Updating => Self::Updating,
+            configuration_store_properties::ProvisioningState::Deleting => Self::Deleting,
+            configuration_store_properties::ProvisioningState::Succeeded => Self::Succeeded,
+            configuration_store_properties::ProvisioningState::Failed => Self::Failed,
+            configuration_store_properties::ProvisioningState::Canceled => Self::Canceled,
+        }
+    }
+}
+impl From<private_endpoint_connection_properties::ProvisioningState> for configuration_store_properties::ProvisioningState {
+    fn from(state: private_endpoint_connection_properties::ProvisioningState) -> Self {
+        match state {
+            private_endpoint_connection_properties::ProvisioningState::Creating => Self::Creating,
+            private_endpoint_connection_properties::ProvisioningState::Updating => Self::Updating,
+            private_endpoint_connection_properties::ProvisioningState::Deleting => Self::Deleting,
+            private_endpoint_connection_properties::ProvisioningState::Succeeded => Self::Succeeded,
+            private_endpoint_connection_properties::ProvisioningState::Failed => Self::Failed,
+            private_endpoint_connection_properties::ProvisioningState::Canceled => Self::Canceled,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Read-modify-write of a configuration store through its update parameters.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. The update parameters
+//! differ between the API versions, it follows the ones of the default `package-2020-06-01`.
+
+#[cfg(feature = "package-2020-06-01")]
+use crate::models::{
+    configuration_store_properties, configuration_store_properties_update_parameters, ConfigurationStore, ConfigurationStoreProperties,
+    ConfigurationStorePropertiesUpdateParameters, ConfigurationStoreUpdateParameters, ResourceIdentity,
+};
+
+#[cfg(feature = "package-2020-06-01")]
+impl ConfigurationStore {
+    /// The update parameters of the store, with its mutable fields only.
+    ///
+    /// The read-only fields, such as the provisioning state, the creation date, the endpoint and
+    /// the private endpoint connections, are dropped, as are the ids and location of the resource
+    /// and the principal and tenant ids of its identity. Sending the parameters back only changes
+    /// what was edited.
+    pub fn into_update_parameters(self) -> ConfigurationStoreUpdateParameters {
+        ConfigurationStoreUpdateParameters {
+            properties: self.properties.map(ConfigurationStoreProperties::into_update_parameters),
+            identity: self.identity.map(ResourceIdentity::into_update_parameters),
+            sku: Some(self.sku),
+            tags: self.resource.tags,
+        }
+    }
+}
+
+#[cfg(feature = "package-2020-06-01")]
+impl ConfigurationStoreProperties {
+    /// The update parameters of the properties, with their mutable fields only.
+    pub fn into_update_parameters(self) -> ConfigurationStorePropertiesUpdateParameters {
+        ConfigurationStorePropertiesUpdateParameters {
+            encryption: self.encryption,
+            public_network_access: self.public_network_access.map(|access| match access {
+                configuration_store_properties::PublicNetworkAccess::Enabled => {
+                    configuration_store_properties_update_parameters::PublicNetworkAccess::Enabled
+                }
+                configuration_store_properties::PublicNetworkAccess::Disabled => {
+                    configuration_store_properties_update_parameters::PublicNetworkAccess::Disabled
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "package-2020-06-01")]
+impl ResourceIdentity {
+    /// The identity without its read-only principal and tenant ids, nor the ones of its user
+    /// assigned identities.
+    pub fn into_update_parameters(self) -> ResourceIdentity {
+        let user_assigned_identities = self.user_assigned_identities.map(|identities| match identities {
+            serde_json::Value::Object(identities) => identities
+                .into_iter()
+                .map(|(id, _)| (id, serde_json::json!({})))
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+            identities => identities,
+        });
+        ResourceIdentity {
+            type_: self.type_,
+            user_assigned_identities,
+            principal_id: None,
+            tenant_id: None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "package-2020-06-01"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_only_fields_are_dropped() {
+        let store: ConfigurationStore = serde_json::from_value(serde_json::json!({
+            "id": "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.AppConfiguration/configurationStores/contoso",
+            "name": "contoso",
+            "type": "Microsoft.AppConfiguration/configurationStores",
+            "location": "westus",
+            "tags": { "env": "dev" },
+            "identity": {
+                "type": "SystemAssigned, UserAssigned",
+                "principalId": "0b8ad4a7-4a1e-4e40-a0a0-6b9f1e5e8a1c",
+                "tenantId": "72f988bf-86f1-41af-91ab-2d7cd011db47",
+                "userAssignedIdentities": {
+                    "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/id": {
+                        "principalId": "d2b5a1f4-8e0b-4d1c-9f7e-0a3c6b2e1d4f",
+                        "clientId": "5c1e3b2a-7d4f-4e8a-b9c0-1f2e3d4c5b6a"
+                    }
+                }
+            },
+            "properties": {
+                "provisioningState": "Succeeded",
+                "creationDate": "2020-06-01T00:00:00+00:00",
+                "endpoint": "https://contoso.azconfig.io",
+                "privateEndpointConnections": [{ "id": "connection" }],
+                "publicNetworkAccess": "Disabled"
+            },
+            "sku": { "name": "Standard" }
+        }))
+        .unwrap();
+
+        let parameters = store.into_update_parameters();
+        assert_eq!(
+            serde_json::to_value(&parameters).unwrap(),
+            serde_json::json!({
+                "properties": { "publicNetworkAccess": "Disabled" },
+                "identity": {
+                    "type": "SystemAssigned, UserAssigned",
+                    "userAssignedIdentities": {
+                        "/subscriptions/sub/resourceGroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/id": {}
+                    }
+                },
+                "sku": { "name": "Standard" },
+                "tags": { "env": "dev" }
+            })
+        );
+    }
+}
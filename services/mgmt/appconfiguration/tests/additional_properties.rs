@@ -0,0 +1,32 @@
+use azure_mgmt_appconfiguration::models::ConfigurationStoreProperties;
+
+#[test]
+fn unknown_properties_are_kept() {
+    let properties: ConfigurationStoreProperties = serde_json::from_str(
+        r#"{
+            "endpoint": "https://contoso.azconfig.io",
+            "x-unknown-field": true,
+            "softDeleteRetentionInDays": 7
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(properties.endpoint.as_deref(), Some("https://contoso.azconfig.io"));
+    assert_eq!(properties.additional.len(), 2);
+    assert_eq!(properties.additional["x-unknown-field"], serde_json::json!(true));
+    assert_eq!(properties.additional["softDeleteRetentionInDays"], serde_json::json!(7));
+}
+
+#[test]
+fn unknown_properties_are_serialized_back() {
+    let json = serde_json::json!({ "endpoint": "https://contoso.azconfig.io", "x-unknown-field": true });
+    let properties: ConfigurationStoreProperties = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&properties).unwrap(), json);
+
+    let properties: ConfigurationStoreProperties = serde_json::from_str(r#"{ "endpoint": "https://contoso.azconfig.io" }"#).unwrap();
+    assert!(properties.additional.is_empty());
+    assert_eq!(
+        serde_json::to_value(&properties).unwrap(),
+        serde_json::json!({ "endpoint": "https://contoso.azconfig.io" })
+    );
+}
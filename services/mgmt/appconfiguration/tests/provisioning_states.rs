@@ -0,0 +1,18 @@
+#![cfg(any(
+    feature = "package-2021-03-01-preview",
+    feature = "package-2020-07-01-preview",
+    feature = "package-2020-06-01",
+    feature = "package-2019-11-01-preview"
+))]
+
+use azure_mgmt_appconfiguration::models::{configuration_store_properties, private_endpoint_connection_properties};
+
+#[test]
+fn store_provisioning_state_converts_to_private_endpoint_connection_one() {
+    let state: private_endpoint_connection_properties::ProvisioningState =
+        configuration_store_properties::ProvisioningState::Updating.into();
+    assert_eq!(state, private_endpoint_connection_properties::ProvisioningState::Updating);
+
+    let state: configuration_store_properties::ProvisioningState = private_endpoint_connection_properties::ProvisioningState::Failed.into();
+    assert_eq!(state, configuration_store_properties::ProvisioningState::Failed);
+}
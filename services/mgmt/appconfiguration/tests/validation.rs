@@ -0,0 +1,20 @@
+use azure_core::ValidationError;
+use azure_mgmt_appconfiguration::models::{check_name_availability_parameters, CheckNameAvailabilityParameters};
+
+#[test]
+fn check_name_availability_parameters_without_a_name_are_invalid() {
+    let parameters = CheckNameAvailabilityParameters {
+        name: String::new(),
+        type_: check_name_availability_parameters::Type::MicrosoftAppConfigurationConfigurationStores,
+    };
+    assert_eq!(parameters.validate(), Err(ValidationError::EmptyRequiredField("name")));
+}
+
+#[test]
+fn check_name_availability_parameters_with_a_name_are_valid() {
+    let parameters = CheckNameAvailabilityParameters {
+        name: "contoso".to_owned(),
+        type_: check_name_availability_parameters::Type::MicrosoftAppConfigurationConfigurationStores,
+    };
+    assert_eq!(parameters.validate(), Ok(()));
+}
@@ -54,6 +54,21 @@ pub mod cluster_resource_properties {
         Moved,
         MoveFailed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Deleted => azure_core::PollResult::Done,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Moving => azure_core::PollResult::InProgress,
+                Self::Moved => azure_core::PollResult::InProgress,
+                Self::MoveFailed => azure_core::PollResult::Failed("MoveFailed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ManagedIdentityProperties {
@@ -126,6 +141,17 @@ pub mod config_server_properties {
         Succeeded,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotAvailable => azure_core::PollResult::InProgress,
+                Self::Deleted => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MonitoringSettingResource {
@@ -158,6 +184,16 @@ pub mod monitoring_setting_properties {
         Succeeded,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotAvailable => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ApplicationInsightsAgentVersions {
@@ -341,6 +377,16 @@ pub mod app_resource_properties {
         Creating,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TemporaryDisk {
@@ -528,6 +574,16 @@ pub mod deployment_resource_properties {
         Succeeded,
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum Status {
         Unknown,
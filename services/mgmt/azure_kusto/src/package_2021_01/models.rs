@@ -983,6 +983,18 @@ pub enum ProvisioningState {
     Failed,
     Moving,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Running => azure_core::PollResult::InProgress,
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Moving => azure_core::PollResult::InProgress,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -42,6 +42,16 @@ pub enum ProvisioningState {
     Succeeded,
     Canceled,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
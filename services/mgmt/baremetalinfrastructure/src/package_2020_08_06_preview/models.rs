@@ -66,6 +66,19 @@ pub mod azure_bare_metal_instance_properties {
         Deleting,
         Migrating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Migrating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HardwareProfile {
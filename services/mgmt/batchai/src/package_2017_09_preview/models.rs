@@ -24,7 +24,7 @@ pub struct FileServerListResult {
 pub struct DataDisks {
     #[serde(rename = "diskSizeInGB")]
     pub disk_size_in_gb: i32,
-    #[serde(rename = "cachingType", skip_serializing)]
+    #[serde(rename = "cachingType", default, skip_serializing)]
     pub caching_type: Option<data_disks::CachingType>,
     #[serde(rename = "diskCount")]
     pub disk_count: i32,
@@ -112,11 +112,11 @@ pub struct FileServerProperties {
     pub subnet: Option<ResourceId>,
     #[serde(rename = "mountSettings", default, skip_serializing_if = "Option::is_none")]
     pub mount_settings: Option<MountSettings>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<file_server_properties::ProvisioningState>,
 }
 pub mod file_server_properties {
@@ -134,6 +134,17 @@ pub mod file_server_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServer {
@@ -292,19 +303,19 @@ pub struct ClusterProperties {
     pub user_account_settings: Option<UserAccountSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<cluster_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
-    #[serde(rename = "allocationState", skip_serializing)]
+    #[serde(rename = "allocationState", default, skip_serializing)]
     pub allocation_state: Option<cluster_properties::AllocationState>,
-    #[serde(rename = "allocationStateTransitionTime", skip_serializing)]
+    #[serde(rename = "allocationStateTransitionTime", default, skip_serializing)]
     pub allocation_state_transition_time: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<BatchAiError>,
-    #[serde(rename = "currentNodeCount", skip_serializing)]
+    #[serde(rename = "currentNodeCount", default, skip_serializing)]
     pub current_node_count: Option<i32>,
     #[serde(rename = "nodeStateCounts", default, skip_serializing_if = "Option::is_none")]
     pub node_state_counts: Option<NodeStateCounts>,
@@ -329,6 +340,16 @@ pub mod cluster_properties {
         #[serde(rename = "deleting")]
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
         #[serde(rename = "steady")]
@@ -439,15 +460,15 @@ pub struct JobProperties {
     pub environment_variables: Vec<EnvironmentSetting>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_properties::Constraints>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<job_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
     #[serde(rename = "executionState", default, skip_serializing_if = "Option::is_none")]
     pub execution_state: Option<job_properties::ExecutionState>,
-    #[serde(rename = "executionStateTransitionTime", skip_serializing)]
+    #[serde(rename = "executionStateTransitionTime", default, skip_serializing)]
     pub execution_state_transition_time: Option<String>,
     #[serde(rename = "executionInfo", default, skip_serializing_if = "Option::is_none")]
     pub execution_info: Option<job_properties::ExecutionInfo>,
@@ -470,6 +491,16 @@ pub mod job_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ExecutionState {
         #[serde(rename = "queued")]
@@ -541,7 +572,7 @@ pub struct File {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<File>,
     #[serde(rename = "nextLink", default, skip_serializing_if = "Option::is_none")]
     pub next_link: Option<String>,
@@ -552,15 +583,15 @@ pub struct ResourceId {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub id: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "type", skip_serializing)]
+    #[serde(rename = "type", default, skip_serializing)]
     pub type_: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub location: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub tags: Option<serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
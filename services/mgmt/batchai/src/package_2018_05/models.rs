@@ -4,18 +4,18 @@
 use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct UsageName {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Option<String>,
-    #[serde(rename = "localizedValue", skip_serializing)]
+    #[serde(rename = "localizedValue", default, skip_serializing)]
     pub localized_value: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Usage {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub unit: Option<usage::Unit>,
-    #[serde(rename = "currentValue", skip_serializing)]
+    #[serde(rename = "currentValue", default, skip_serializing)]
     pub current_value: Option<i32>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub limit: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<UsageName>,
@@ -29,9 +29,9 @@ pub mod usage {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ListUsagesResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Usage>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -49,7 +49,7 @@ pub struct FileServerBaseProperties {
 pub struct FileServerListResult {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub value: Vec<FileServer>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -122,11 +122,11 @@ pub struct FileServerProperties {
     pub subnet: Option<ResourceId>,
     #[serde(rename = "mountSettings", default, skip_serializing_if = "Option::is_none")]
     pub mount_settings: Option<MountSettings>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<file_server_properties::ProvisioningState>,
 }
 pub mod file_server_properties {
@@ -144,6 +144,17 @@ pub mod file_server_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileServer {
@@ -233,15 +244,15 @@ pub struct ImageReference {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NodeStateCounts {
-    #[serde(rename = "idleNodeCount", skip_serializing)]
+    #[serde(rename = "idleNodeCount", default, skip_serializing)]
     pub idle_node_count: Option<i32>,
-    #[serde(rename = "runningNodeCount", skip_serializing)]
+    #[serde(rename = "runningNodeCount", default, skip_serializing)]
     pub running_node_count: Option<i32>,
-    #[serde(rename = "preparingNodeCount", skip_serializing)]
+    #[serde(rename = "preparingNodeCount", default, skip_serializing)]
     pub preparing_node_count: Option<i32>,
-    #[serde(rename = "unusableNodeCount", skip_serializing)]
+    #[serde(rename = "unusableNodeCount", default, skip_serializing)]
     pub unusable_node_count: Option<i32>,
-    #[serde(rename = "leavingNodeCount", skip_serializing)]
+    #[serde(rename = "leavingNodeCount", default, skip_serializing)]
     pub leaving_node_count: Option<i32>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -285,7 +296,7 @@ pub struct SetupTask {
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(rename = "stdOutErrPathPrefix")]
     pub std_out_err_path_prefix: String,
-    #[serde(rename = "stdOutErrPathSuffix", skip_serializing)]
+    #[serde(rename = "stdOutErrPathSuffix", default, skip_serializing)]
     pub std_out_err_path_suffix: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -314,19 +325,19 @@ pub struct ClusterProperties {
     pub user_account_settings: Option<UserAccountSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subnet: Option<ResourceId>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<cluster_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
-    #[serde(rename = "allocationState", skip_serializing)]
+    #[serde(rename = "allocationState", default, skip_serializing)]
     pub allocation_state: Option<cluster_properties::AllocationState>,
-    #[serde(rename = "allocationStateTransitionTime", skip_serializing)]
+    #[serde(rename = "allocationStateTransitionTime", default, skip_serializing)]
     pub allocation_state_transition_time: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub errors: Vec<BatchAiError>,
-    #[serde(rename = "currentNodeCount", skip_serializing)]
+    #[serde(rename = "currentNodeCount", default, skip_serializing)]
     pub current_node_count: Option<i32>,
     #[serde(rename = "nodeStateCounts", default, skip_serializing_if = "Option::is_none")]
     pub node_state_counts: Option<NodeStateCounts>,
@@ -351,6 +362,16 @@ pub mod cluster_properties {
         #[serde(rename = "deleting")]
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AllocationState {
         #[serde(rename = "steady")]
@@ -368,9 +389,9 @@ pub struct Cluster {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClusterListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Cluster>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -473,7 +494,7 @@ pub struct JobProperties {
     pub horovod_settings: Option<HorovodSettings>,
     #[serde(rename = "jobPreparation", default, skip_serializing_if = "Option::is_none")]
     pub job_preparation: Option<JobPreparation>,
-    #[serde(rename = "jobOutputDirectoryPathSegment", skip_serializing)]
+    #[serde(rename = "jobOutputDirectoryPathSegment", default, skip_serializing)]
     pub job_output_directory_path_segment: Option<String>,
     #[serde(rename = "stdOutErrPathPrefix", default, skip_serializing_if = "Option::is_none")]
     pub std_out_err_path_prefix: Option<String>,
@@ -487,15 +508,15 @@ pub struct JobProperties {
     pub secrets: Vec<EnvironmentVariableWithSecretValue>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub constraints: Option<job_properties::Constraints>,
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<job_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
-    #[serde(rename = "executionState", skip_serializing)]
+    #[serde(rename = "executionState", default, skip_serializing)]
     pub execution_state: Option<job_properties::ExecutionState>,
-    #[serde(rename = "executionStateTransitionTime", skip_serializing)]
+    #[serde(rename = "executionStateTransitionTime", default, skip_serializing)]
     pub execution_state_transition_time: Option<String>,
     #[serde(rename = "executionInfo", default, skip_serializing_if = "Option::is_none")]
     pub execution_info: Option<job_properties::ExecutionInfo>,
@@ -527,6 +548,16 @@ pub mod job_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ExecutionState {
         #[serde(rename = "queued")]
@@ -542,13 +573,13 @@ pub mod job_properties {
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct ExecutionInfo {
-        #[serde(rename = "startTime", skip_serializing)]
+        #[serde(rename = "startTime", default, skip_serializing)]
         pub start_time: Option<String>,
-        #[serde(rename = "endTime", skip_serializing)]
+        #[serde(rename = "endTime", default, skip_serializing)]
         pub end_time: Option<String>,
-        #[serde(rename = "exitCode", skip_serializing)]
+        #[serde(rename = "exitCode", default, skip_serializing)]
         pub exit_code: Option<i32>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub errors: Vec<BatchAiError>,
     }
 }
@@ -561,41 +592,41 @@ pub struct Job {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct JobListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Job>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformation {
-    #[serde(rename = "nodeId", skip_serializing)]
+    #[serde(rename = "nodeId", default, skip_serializing)]
     pub node_id: Option<String>,
-    #[serde(rename = "ipAddress", skip_serializing)]
+    #[serde(rename = "ipAddress", default, skip_serializing)]
     pub ip_address: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub port: Option<f64>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RemoteLoginInformationListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<RemoteLoginInformation>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileProperties {
-    #[serde(rename = "lastModified", skip_serializing)]
+    #[serde(rename = "lastModified", default, skip_serializing)]
     pub last_modified: Option<String>,
-    #[serde(rename = "contentLength", skip_serializing)]
+    #[serde(rename = "contentLength", default, skip_serializing)]
     pub content_length: Option<i64>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct File {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "fileType", skip_serializing)]
+    #[serde(rename = "fileType", default, skip_serializing)]
     pub file_type: Option<file::FileType>,
-    #[serde(rename = "downloadUrl", skip_serializing)]
+    #[serde(rename = "downloadUrl", default, skip_serializing)]
     pub download_url: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<FileProperties>,
@@ -612,9 +643,9 @@ pub mod file {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FileListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<File>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -623,24 +654,24 @@ pub struct ResourceId {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Resource {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub id: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "type", skip_serializing)]
+    #[serde(rename = "type", default, skip_serializing)]
     pub type_: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub location: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub tags: Option<serde_json::Value>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ProxyResource {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub id: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
-    #[serde(rename = "type", skip_serializing)]
+    #[serde(rename = "type", default, skip_serializing)]
     pub type_: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -893,31 +924,31 @@ pub struct CloudError {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CloudErrorBody {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub code: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub message: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub target: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<CloudErrorBody>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BatchAiError {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub code: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub message: Option<String>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub details: Vec<NameValuePair>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Operation {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub name: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display: Option<operation::Display>,
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub origin: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
@@ -926,28 +957,28 @@ pub mod operation {
     use super::*;
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Display {
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub provider: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub operation: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub resource: Option<String>,
-        #[serde(skip_serializing)]
+        #[serde(default, skip_serializing)]
         pub description: Option<String>,
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OperationListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Operation>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Workspace>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -963,11 +994,11 @@ pub struct WorkspaceUpdateParameters {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceProperties {
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<workspace_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
 }
 pub mod workspace_properties {
@@ -983,6 +1014,16 @@ pub mod workspace_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Workspace {
@@ -993,18 +1034,18 @@ pub struct Workspace {
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExperimentListResult {
-    #[serde(skip_serializing)]
+    #[serde(default, skip_serializing)]
     pub value: Vec<Experiment>,
-    #[serde(rename = "nextLink", skip_serializing)]
+    #[serde(rename = "nextLink", default, skip_serializing)]
     pub next_link: Option<String>,
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExperimentProperties {
-    #[serde(rename = "creationTime", skip_serializing)]
+    #[serde(rename = "creationTime", default, skip_serializing)]
     pub creation_time: Option<String>,
-    #[serde(rename = "provisioningState", skip_serializing)]
+    #[serde(rename = "provisioningState", default, skip_serializing)]
     pub provisioning_state: Option<experiment_properties::ProvisioningState>,
-    #[serde(rename = "provisioningStateTransitionTime", skip_serializing)]
+    #[serde(rename = "provisioningStateTransitionTime", default, skip_serializing)]
     pub provisioning_state_transition_time: Option<String>,
 }
 pub mod experiment_properties {
@@ -1020,6 +1061,16 @@ pub mod experiment_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Experiment {
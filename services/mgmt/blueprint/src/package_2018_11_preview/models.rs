@@ -394,6 +394,22 @@ pub mod assignment_properties {
         #[serde(rename = "deleting")]
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Validating => azure_core::PollResult::InProgress,
+                Self::Waiting => azure_core::PollResult::InProgress,
+                Self::Deploying => azure_core::PollResult::InProgress,
+                Self::Cancelling => azure_core::PollResult::InProgress,
+                Self::Locking => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("canceled".to_owned()),
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceGroupValue {
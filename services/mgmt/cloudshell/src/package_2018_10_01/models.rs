@@ -35,6 +35,21 @@ pub mod console_properties {
         Canceled,
         Succeeded,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotSpecified => azure_core::PollResult::InProgress,
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Pending => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Repairing => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConsoleCreateProperties {
@@ -64,6 +79,21 @@ pub mod console_create_properties {
         Canceled,
         Succeeded,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotSpecified => azure_core::PollResult::InProgress,
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Pending => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Repairing => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConsoleDefinition {
@@ -96,6 +96,21 @@ pub enum ProvisioningState {
     Deleted,
     NotSpecified,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Accepted => azure_core::PollResult::InProgress,
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Updating => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+            Self::Deleted => azure_core::PollResult::Done,
+            Self::NotSpecified => azure_core::PollResult::InProgress,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SaaSOfferStatus {
     Started,
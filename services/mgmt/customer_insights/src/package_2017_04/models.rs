@@ -1093,6 +1093,18 @@ pub enum ProvisioningState {
     HumanIntervention,
     Failed,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Provisioning => azure_core::PollResult::InProgress,
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Expiring => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::HumanIntervention => azure_core::PollResult::InProgress,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ConnectorType {
     None,
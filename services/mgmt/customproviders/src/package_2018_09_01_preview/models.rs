@@ -87,6 +87,17 @@ pub mod custom_rp_manifest {
             Succeeded,
             Failed,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Accepted => azure_core::PollResult::InProgress,
+                    Self::Deleting => azure_core::PollResult::InProgress,
+                    Self::Running => azure_core::PollResult::InProgress,
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -147,6 +158,17 @@ pub mod association {
             Succeeded,
             Failed,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Accepted => azure_core::PollResult::InProgress,
+                    Self::Deleting => azure_core::PollResult::InProgress,
+                    Self::Running => azure_core::PollResult::InProgress,
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -171,6 +171,21 @@ pub mod data_lake_analytics_account_properties_basic {
         Deleting,
         Deleted,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Running => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Patching => azure_core::PollResult::InProgress,
+                Self::Suspending => azure_core::PollResult::InProgress,
+                Self::Resuming => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Deleted => azure_core::PollResult::Done,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum State {
         #[serde(rename = "active")]
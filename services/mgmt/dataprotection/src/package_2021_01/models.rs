@@ -376,6 +376,17 @@ pub mod backup_vault {
         Unknown,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Unknown => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BackupVaultResource {
@@ -0,0 +1,37 @@
+//! Reading the cause out of nested `ErrorDetail`s.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::models::ErrorDetail;
+
+impl ErrorDetail {
+    /// The `code: message` of this error and of all its nested `details`, depth-first, joined
+    /// with `; `.
+    ///
+    /// An entry without code is only its message and one without message only its code.
+    pub fn flatten_messages(&self) -> String {
+        let mut messages = Vec::new();
+        self.collect_messages(&mut messages);
+        messages.join("; ")
+    }
+
+    /// The error at the end of the chain of single `details`, this one if it has none or several.
+    pub fn root_cause(&self) -> &ErrorDetail {
+        let mut error = self;
+        while let [detail] = error.details.as_slice() {
+            error = detail;
+        }
+        error
+    }
+
+    fn collect_messages(&self, messages: &mut Vec<String>) {
+        match (&self.code, &self.message) {
+            (Some(code), Some(message)) => messages.push(format!("{}: {}", code, message)),
+            (Some(entry), None) | (None, Some(entry)) => messages.push(entry.clone()),
+            (None, None) => {}
+        }
+        for detail in &self.details {
+            detail.collect_messages(messages);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Telling the caller why a name cannot be used.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::models::{check_name_availability_response::Reason, CheckNameAvailabilityResponse};
+
+impl CheckNameAvailabilityResponse {
+    /// Whether the name can be used: a response without `nameAvailable` counts as unavailable.
+    pub fn is_available(&self) -> bool {
+        self.name_available == Some(true)
+    }
+
+    /// `available`, or why the name is not: the message of the service when it sent one,
+    /// otherwise a message for the `reason`.
+    pub fn explain(&self) -> String {
+        if self.is_available() {
+            return "available".to_owned();
+        }
+        match (&self.message, &self.reason) {
+            (Some(message), _) if !message.is_empty() => message.clone(),
+            (_, Some(Reason::Invalid)) => "the name is invalid".to_owned(),
+            (_, Some(Reason::AlreadyExists)) => "the name is already in use".to_owned(),
+            (_, None) => "the name is not available".to_owned(),
+        }
+    }
+}
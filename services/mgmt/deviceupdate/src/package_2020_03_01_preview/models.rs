@@ -33,6 +33,18 @@ pub mod account {
             Accepted,
             Creating,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Deleted => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                    Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                    Self::Accepted => azure_core::PollResult::InProgress,
+                    Self::Creating => azure_core::PollResult::InProgress,
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -74,6 +86,18 @@ pub mod instance {
             Accepted,
             Creating,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Deleted => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                    Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                    Self::Accepted => azure_core::PollResult::InProgress,
+                    Self::Creating => azure_core::PollResult::InProgress,
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -0,0 +1,21 @@
+use azure_mgmt_deviceupdate::{models::ErrorAdditionalInfo, JsonValueExt};
+
+#[test]
+fn typed_fields_can_be_read_from_additional_info() {
+    let additional_info: ErrorAdditionalInfo = serde_json::from_str(
+        r#"{
+            "type": "PolicyViolation",
+            "info": {
+                "policyDefinitionDisplayName": "Allowed locations",
+                "evaluationDetails": { "evaluatedExpressions": [{ "path": "location", "result": 1 }] }
+            }
+        }"#,
+    )
+    .unwrap();
+    let info = additional_info.info.unwrap();
+
+    assert_eq!(info.get_str("policyDefinitionDisplayName"), Some("Allowed locations"));
+    assert_eq!(info.get_str("evaluationDetails.evaluatedExpressions.0.path"), Some("location"));
+    assert_eq!(info.get_i64("evaluationDetails.evaluatedExpressions.0.result"), Some(1));
+    assert_eq!(info.get_str("evaluationDetails.evaluatedExpressions.1.path"), None);
+}
@@ -0,0 +1,49 @@
+use azure_mgmt_deviceupdate::models::ErrorDetail;
+
+#[test]
+fn a_flat_error_is_its_own_root_cause() {
+    let error: ErrorDetail = serde_json::from_str(r#"{ "code": "BadRequest", "message": "The account name is invalid." }"#).unwrap();
+    assert_eq!(error.flatten_messages(), "BadRequest: The account name is invalid.");
+    assert_eq!(error.root_cause(), &error);
+}
+
+#[test]
+fn nested_errors_are_flattened_depth_first() {
+    let error: ErrorDetail = serde_json::from_str(
+        r#"{
+            "code": "DeploymentFailed",
+            "message": "At least one resource deployment operation failed.",
+            "details": [{
+                "code": "Conflict",
+                "details": [{
+                    "code": "InstanceLimitExceeded",
+                    "message": "The account already has 10 instances."
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        error.flatten_messages(),
+        "DeploymentFailed: At least one resource deployment operation failed.; Conflict; InstanceLimitExceeded: The account already has 10 instances."
+    );
+    let root_cause = error.root_cause();
+    assert_eq!(root_cause.code.as_deref(), Some("InstanceLimitExceeded"));
+    assert!(root_cause.details.is_empty());
+}
+
+#[test]
+fn the_root_cause_stops_at_sibling_details() {
+    let error: ErrorDetail = serde_json::from_str(
+        r#"{
+            "code": "DeploymentFailed",
+            "details": [{
+                "code": "Conflict",
+                "details": [{ "code": "First" }, { "code": "Second" }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(error.root_cause().code.as_deref(), Some("Conflict"));
+    assert_eq!(error.flatten_messages(), "DeploymentFailed; Conflict; First; Second");
+}
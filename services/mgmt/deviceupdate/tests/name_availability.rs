@@ -0,0 +1,32 @@
+use azure_mgmt_deviceupdate::models::CheckNameAvailabilityResponse;
+
+#[test]
+fn an_available_name_is_explained_as_such() {
+    let response: CheckNameAvailabilityResponse = serde_json::from_str(r#"{ "nameAvailable": true }"#).unwrap();
+    assert!(response.is_available());
+    assert_eq!(response.explain(), "available");
+}
+
+#[test]
+fn a_taken_name_is_explained_by_its_reason() {
+    let response: CheckNameAvailabilityResponse = serde_json::from_str(r#"{ "nameAvailable": false, "reason": "AlreadyExists" }"#).unwrap();
+    assert!(!response.is_available());
+    assert_eq!(response.explain(), "the name is already in use");
+}
+
+#[test]
+fn an_invalid_name_is_explained_by_the_message_of_the_service() {
+    let response: CheckNameAvailabilityResponse = serde_json::from_str(
+        r#"{ "nameAvailable": false, "reason": "Invalid", "message": "The name must be between 3 and 24 characters." }"#,
+    )
+    .unwrap();
+    assert!(!response.is_available());
+    assert_eq!(response.explain(), "The name must be between 3 and 24 characters.");
+}
+
+#[test]
+fn a_response_without_availability_is_not_available() {
+    let response: CheckNameAvailabilityResponse = serde_json::from_str(r#"{ "reason": "Invalid" }"#).unwrap();
+    assert!(!response.is_available());
+    assert_eq!(response.explain(), "the name is invalid");
+}
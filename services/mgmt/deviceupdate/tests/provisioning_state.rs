@@ -0,0 +1,25 @@
+use azure_core::{IntoPollResult, PollResult};
+use azure_mgmt_deviceupdate::models::{account, instance};
+
+#[test]
+fn account_provisioning_states_are_poll_results() {
+    use account::properties::ProvisioningState;
+    assert_eq!(ProvisioningState::Succeeded.into_poll_result(), PollResult::Done);
+    assert_eq!(ProvisioningState::Deleted.into_poll_result(), PollResult::Done);
+    assert_eq!(
+        ProvisioningState::Failed.into_poll_result(),
+        PollResult::Failed("Failed".to_owned())
+    );
+    assert_eq!(
+        ProvisioningState::Canceled.into_poll_result(),
+        PollResult::Failed("Canceled".to_owned())
+    );
+    assert_eq!(ProvisioningState::Accepted.into_poll_result(), PollResult::InProgress);
+    assert_eq!(ProvisioningState::Creating.into_poll_result(), PollResult::InProgress);
+}
+
+#[test]
+fn deserialized_instance_states_are_poll_results() {
+    let state: instance::properties::ProvisioningState = serde_json::from_str(r#""Creating""#).unwrap();
+    assert_eq!(state.into_poll_result(), PollResult::InProgress);
+}
@@ -133,6 +133,15 @@ pub mod arm_disaster_recovery {
             Succeeded,
             Failed,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Accepted => azure_core::PollResult::InProgress,
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                }
+            }
+        }
         #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
         pub enum Role {
             Primary,
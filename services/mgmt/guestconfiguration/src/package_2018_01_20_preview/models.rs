@@ -68,6 +68,13 @@ pub mod guest_configuration_assignment_properties {
     pub enum ProvisioningState {
         Succeeded,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum ComplianceStatus {
         Compliant,
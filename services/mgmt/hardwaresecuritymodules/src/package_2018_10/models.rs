@@ -85,6 +85,19 @@ pub mod dedicated_hsm_properties {
         CheckingQuota,
         Deleting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Allocating => azure_core::PollResult::InProgress,
+                Self::Connecting => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::CheckingQuota => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct DedicatedHsm {
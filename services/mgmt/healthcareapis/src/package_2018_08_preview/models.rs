@@ -29,6 +29,21 @@ pub mod services_properties {
         Canceled,
         Deprovisioned,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Verifying => azure_core::PollResult::InProgress,
+                Self::Updating => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Deprovisioned => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 pub type ServiceAccessPoliciesInfo = Vec<ServiceAccessPolicyEntry>;
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
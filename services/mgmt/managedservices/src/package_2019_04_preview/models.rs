@@ -46,6 +46,24 @@ pub mod registration_definition_properties {
         Succeeded,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotSpecified => azure_core::PollResult::InProgress,
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Running => azure_core::PollResult::InProgress,
+                Self::Ready => azure_core::PollResult::InProgress,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Created => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Deleted => azure_core::PollResult::Done,
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RegistrationDefinitionList {
@@ -91,6 +109,24 @@ pub mod registration_assignment_properties {
         Succeeded,
         Updating,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::NotSpecified => azure_core::PollResult::InProgress,
+                Self::Accepted => azure_core::PollResult::InProgress,
+                Self::Running => azure_core::PollResult::InProgress,
+                Self::Ready => azure_core::PollResult::InProgress,
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::Created => azure_core::PollResult::InProgress,
+                Self::Deleting => azure_core::PollResult::InProgress,
+                Self::Deleted => azure_core::PollResult::Done,
+                Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Updating => azure_core::PollResult::InProgress,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct RegistrationDefinition {
         #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -142,6 +178,24 @@ pub mod registration_assignment_properties {
                 Succeeded,
                 Updating,
             }
+            impl azure_core::IntoPollResult for ProvisioningState {
+                fn into_poll_result(&self) -> azure_core::PollResult {
+                    match self {
+                        Self::NotSpecified => azure_core::PollResult::InProgress,
+                        Self::Accepted => azure_core::PollResult::InProgress,
+                        Self::Running => azure_core::PollResult::InProgress,
+                        Self::Ready => azure_core::PollResult::InProgress,
+                        Self::Creating => azure_core::PollResult::InProgress,
+                        Self::Created => azure_core::PollResult::InProgress,
+                        Self::Deleting => azure_core::PollResult::InProgress,
+                        Self::Deleted => azure_core::PollResult::Done,
+                        Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+                        Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                        Self::Succeeded => azure_core::PollResult::Done,
+                        Self::Updating => azure_core::PollResult::InProgress,
+                    }
+                }
+            }
         }
     }
 }
@@ -381,3 +381,10 @@ pub mod check_name_availability_request {
 pub enum ProvisioningState {
     Updating,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Updating => azure_core::PollResult::InProgress,
+        }
+    }
+}
@@ -53,6 +53,17 @@ pub mod private_endpoint_connection_properties {
         Failed,
         Rejecting,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Approving => azure_core::PollResult::InProgress,
+                Self::Ready => azure_core::PollResult::InProgress,
+                Self::Dropping => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                Self::Rejecting => azure_core::PollResult::InProgress,
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PrivateEndpointProperty {
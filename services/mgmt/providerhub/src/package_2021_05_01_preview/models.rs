@@ -18,6 +18,25 @@ pub enum ProvisioningState {
     TransientFailure,
     RolloutInProgress,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::NotSpecified => azure_core::PollResult::InProgress,
+            Self::Accepted => azure_core::PollResult::InProgress,
+            Self::Running => azure_core::PollResult::InProgress,
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Created => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::Deleted => azure_core::PollResult::Done,
+            Self::Canceled => azure_core::PollResult::Failed("Canceled".to_owned()),
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::MovingResources => azure_core::PollResult::InProgress,
+            Self::TransientFailure => azure_core::PollResult::InProgress,
+            Self::RolloutInProgress => azure_core::PollResult::InProgress,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TrafficRegions {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
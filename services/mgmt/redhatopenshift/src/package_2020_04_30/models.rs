@@ -151,6 +151,18 @@ pub enum ProvisioningState {
     Succeeded,
     Updating,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::AdminUpdating => azure_core::PollResult::InProgress,
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Updating => azure_core::PollResult::InProgress,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServicePrincipalProfile {
     #[serde(rename = "clientId", default, skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,128 @@
+//! Getting all the reservation orders applied to a subscription.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::models::{AppliedReservationList, Error};
+use crate::operations::get_applied_reservation_list;
+use crate::OperationConfig;
+
+impl OperationConfig {
+    /// The ids of all the reservation orders applied to the subscription `subscription_id`.
+    ///
+    /// The ids are a paged list within the `AppliedReservations` resource: its pages are
+    /// requested, in order, until the one without `nextLink`.
+    pub async fn get_applied_reservations(&self, subscription_id: &str) -> Result<Vec<String>, get_applied_reservation_list::Error> {
+        let applied_reservations = crate::operations::get_applied_reservation_list(self, subscription_id).await?;
+        let mut page = match applied_reservations
+            .properties
+            .and_then(|properties| properties.reservation_order_ids)
+        {
+            Some(page) => page,
+            None => return Ok(Vec::new()),
+        };
+        let mut reservation_order_ids = Vec::new();
+        loop {
+            reservation_order_ids.append(&mut page.value);
+            match page.next_link {
+                Some(next_link) if !next_link.is_empty() => page = self.applied_reservations_page(&next_link).await?,
+                _ => return Ok(reservation_order_ids),
+            }
+        }
+    }
+
+    /// The page of applied reservation order ids at `next_link`.
+    async fn applied_reservations_page(&self, next_link: &str) -> Result<AppliedReservationList, get_applied_reservation_list::Error> {
+        use get_applied_reservation_list::Error as OperationError;
+
+        let url = self.next_link_url(next_link).map_err(OperationError::ParseUrlError)?;
+        let mut req_builder = http::request::Builder::new().method(http::Method::GET);
+        if let Some(token_credential) = self.token_credential() {
+            let token_response = token_credential
+                .get_token(self.token_credential_resource())
+                .await
+                .map_err(OperationError::GetTokenError)?;
+            req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        }
+        let req = req_builder
+            .uri(url.as_str())
+            .body(bytes::Bytes::from_static(azure_core::EMPTY_BODY))
+            .map_err(OperationError::BuildRequestError)?;
+        let rsp = self
+            .http_client()
+            .execute_request(req)
+            .await
+            .map_err(OperationError::ExecuteRequestError)?;
+        let rsp_body = rsp.body();
+        match rsp.status() {
+            http::StatusCode::OK => {
+                serde_json::from_slice(rsp_body).map_err(|source| OperationError::DeserializeError(source, rsp_body.clone()))
+            }
+            status_code => {
+                let value: Error =
+                    serde_json::from_slice(rsp_body).map_err(|source| OperationError::DeserializeError(source, rsp_body.clone()))?;
+                Err(OperationError::DefaultResponse { status_code, value })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::paging::mock::config;
+    use http::StatusCode;
+
+    const SUBSCRIPTION_ID: &str = "0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42";
+
+    #[test]
+    fn the_order_ids_of_all_the_pages_are_assembled() {
+        let (config, transport) = config(vec![
+            (
+                StatusCode::OK,
+                r#"{
+                    "id": "/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42/providers/Microsoft.Capacity/appliedReservations/default",
+                    "properties": {
+                        "reservationOrderIds": {
+                            "value": ["/providers/Microsoft.Capacity/reservationOrders/first"],
+                            "nextLink": "https://management.azure.com/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42/providers/Microsoft.Capacity/appliedReservations?$skiptoken=abc"
+                        }
+                    }
+                }"#,
+            ),
+            (
+                StatusCode::OK,
+                r#"{ "value": ["/providers/Microsoft.Capacity/reservationOrders/second", "/providers/Microsoft.Capacity/reservationOrders/third"] }"#,
+            ),
+        ]);
+
+        let reservation_order_ids = futures::executor::block_on(config.get_applied_reservations(SUBSCRIPTION_ID)).unwrap();
+
+        assert_eq!(
+            reservation_order_ids,
+            vec![
+                "/providers/Microsoft.Capacity/reservationOrders/first",
+                "/providers/Microsoft.Capacity/reservationOrders/second",
+                "/providers/Microsoft.Capacity/reservationOrders/third",
+            ]
+        );
+        let uris = transport.uris.lock().unwrap();
+        assert_eq!(uris.len(), 2);
+        assert_eq!(
+            uris[1],
+            format!(
+                "https://management.azure.com/subscriptions/{}/providers/Microsoft.Capacity/appliedReservations?%24skiptoken=abc&api-version={}",
+                SUBSCRIPTION_ID,
+                crate::API_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn a_subscription_without_applied_reservations_has_no_order_ids() {
+        let (config, transport) = config(vec![(StatusCode::OK, r#"{ "properties": {} }"#)]);
+
+        let reservation_order_ids = futures::executor::block_on(config.get_applied_reservations(SUBSCRIPTION_ID)).unwrap();
+
+        assert!(reservation_order_ids.is_empty());
+        assert_eq!(transport.uris.lock().unwrap().len(), 1);
+    }
+}
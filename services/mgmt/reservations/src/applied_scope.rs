@@ -0,0 +1,140 @@
+//! Typed scopes for the `appliedScopes` of a reservation.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A scope a reservation applies to: a subscription or one of its resource groups.
+///
+/// It is parsed from, and displayed as, its ARM id: `/subscriptions/{subscriptionId}` or
+/// `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AppliedScope {
+    subscription_id: String,
+    resource_group_name: Option<String>,
+}
+
+impl AppliedScope {
+    /// The scope of a whole subscription.
+    pub fn subscription(subscription_id: impl Into<String>) -> Result<Self, AppliedScopeError> {
+        let subscription_id = subscription_id.into();
+        if !is_guid(&subscription_id) {
+            return Err(AppliedScopeError::InvalidSubscriptionId(subscription_id));
+        }
+        Ok(Self {
+            subscription_id,
+            resource_group_name: None,
+        })
+    }
+
+    /// The scope of a resource group of a subscription.
+    pub fn resource_group(subscription_id: impl Into<String>, resource_group_name: impl Into<String>) -> Result<Self, AppliedScopeError> {
+        let resource_group_name = resource_group_name.into();
+        if !is_resource_group_name(&resource_group_name) {
+            return Err(AppliedScopeError::InvalidResourceGroupName(resource_group_name));
+        }
+        Ok(Self {
+            resource_group_name: Some(resource_group_name),
+            ..Self::subscription(subscription_id)?
+        })
+    }
+
+    pub fn subscription_id(&self) -> &str {
+        &self.subscription_id
+    }
+
+    pub fn resource_group_name(&self) -> Option<&str> {
+        self.resource_group_name.as_deref()
+    }
+}
+
+impl fmt::Display for AppliedScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "/subscriptions/{}", self.subscription_id)?;
+        if let Some(resource_group_name) = &self.resource_group_name {
+            write!(f, "/resourceGroups/{}", resource_group_name)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AppliedScope {
+    type Err = AppliedScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || AppliedScopeError::MalformedScope(s.to_owned());
+        let segments: Vec<&str> = s.strip_prefix('/').ok_or_else(malformed)?.split('/').collect();
+        match segments.as_slice() {
+            [subscriptions, subscription_id] if subscriptions.eq_ignore_ascii_case("subscriptions") => Self::subscription(*subscription_id),
+            [subscriptions, subscription_id, resource_groups, resource_group_name]
+                if subscriptions.eq_ignore_ascii_case("subscriptions") && resource_groups.eq_ignore_ascii_case("resourceGroups") =>
+            {
+                Self::resource_group(*subscription_id, *resource_group_name)
+            }
+            _ => Err(malformed()),
+        }
+    }
+}
+
+impl From<AppliedScope> for String {
+    fn from(scope: AppliedScope) -> Self {
+        scope.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AppliedScopeError {
+    #[error("not a /subscriptions/{{subscriptionId}}[/resourceGroups/{{resourceGroupName}}] scope: {0}")]
+    MalformedScope(String),
+    #[error("subscription id is not a GUID: {0}")]
+    InvalidSubscriptionId(String),
+    #[error("invalid resource group name: {0}")]
+    InvalidResourceGroupName(String),
+}
+
+/// Assembles the `appliedScopes` of a reservation, such as the ones of `PatchProperties`.
+///
+/// ```ignore
+/// let applied_scopes = AppliedScopesBuilder::new()
+///     .scope(AppliedScope::subscription("00000000-0000-0000-0000-000000000000")?)
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AppliedScopesBuilder {
+    scopes: Vec<AppliedScope>,
+}
+
+impl AppliedScopesBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn scope(mut self, scope: AppliedScope) -> Self {
+        self.scopes.push(scope);
+        self
+    }
+
+    /// The ARM ids of the scopes, in the order they were added.
+    pub fn build(self) -> Vec<String> {
+        self.scopes.into_iter().map(String::from).collect()
+    }
+}
+
+/// Whether `s` has the `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` shape of a GUID.
+pub(crate) fn is_guid(s: &str) -> bool {
+    let groups: Vec<&str> = s.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip(&[8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == *len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Whether `s` follows the ARM naming rules of resource groups: 1 to 90 letters, digits, underscores,
+/// hyphens, periods and parentheses, not ending with a period.
+fn is_resource_group_name(s: &str) -> bool {
+    (1..=90).contains(&s.chars().count())
+        && !s.ends_with('.')
+        && s.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '(' | ')'))
+}
@@ -0,0 +1,264 @@
+//! Quoting the price of a `PurchaseRequest` before purchasing it.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. Like the purchases, the
+//! price calculations are sent with the body of the checked `PurchaseRequest` rather than the
+//! generated one.
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::models::{CalculatePriceResponse, CalculatePriceResponseProperties, Error, ErrorResponseCode};
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::operations::reservation_order::calculate;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::{OperationConfig, PurchaseError, PurchaseRequest};
+
+/// The price of a purchase, in the currency it is billed in.
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceQuote {
+    /// The reservation order id the price is quoted for, to purchase the reservation with.
+    pub quote_id: String,
+    /// The billing currency, such as `USD`.
+    pub currency_code: String,
+    /// The total price in the billing currency.
+    pub amount: f64,
+    /// The rest of the quote, such as the price in the pricing currency and the payment schedule.
+    pub properties: CalculatePriceResponseProperties,
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+impl PriceQuote {
+    fn from_response(response: CalculatePriceResponse) -> Result<Self, CalculatePriceError> {
+        let properties = response.properties.ok_or(CalculatePriceError::IncompleteQuote("properties"))?;
+        let quote_id = properties
+            .reservation_order_id
+            .clone()
+            .ok_or(CalculatePriceError::IncompleteQuote("reservationOrderId"))?;
+        let total = properties
+            .billing_currency_total
+            .as_ref()
+            .ok_or(CalculatePriceError::IncompleteQuote("billingCurrencyTotal"))?;
+        let currency_code = total
+            .currency_code
+            .clone()
+            .ok_or(CalculatePriceError::IncompleteQuote("billingCurrencyTotal.currencyCode"))?;
+        let amount = total
+            .amount
+            .ok_or(CalculatePriceError::IncompleteQuote("billingCurrencyTotal.amount"))?;
+        Ok(Self {
+            quote_id,
+            currency_code,
+            amount,
+            properties,
+        })
+    }
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+#[derive(Debug, thiserror::Error)]
+pub enum CalculatePriceError {
+    #[error("invalid purchase: {0}")]
+    InvalidPurchase(#[from] PurchaseError),
+    /// The service could not price the purchase, with its message.
+    #[error("the price calculation failed: {0}")]
+    CalculatePriceFailed(String),
+    #[error("the price response has no {0}")]
+    IncompleteQuote(&'static str),
+    #[error(transparent)]
+    Calculate(#[from] calculate::Error),
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+impl OperationConfig {
+    /// Quotes the price of `body` without purchasing it.
+    ///
+    /// The purchase is validated as [`purchase_reservation_order`](Self::purchase_reservation_order)
+    /// does before being sent.
+    pub async fn calculate_reservation_price(&self, body: PurchaseRequest) -> Result<PriceQuote, CalculatePriceError> {
+        body.validate()?;
+        let body = body.to_json()?;
+
+        let url_str = &format!("{}/providers/Microsoft.Capacity/calculatePrice", self.base_path());
+        let mut url = url::Url::parse(url_str).map_err(calculate::Error::ParseUrlError)?;
+        url.query_pairs_mut().append_pair("api-version", self.api_version());
+        let mut req_builder = http::request::Builder::new().method(http::Method::POST);
+        if let Some(token_credential) = self.token_credential() {
+            let token_response = token_credential
+                .get_token(self.token_credential_resource())
+                .await
+                .map_err(calculate::Error::GetTokenError)?;
+            req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        }
+        let req_body = azure_core::to_json(&body).map_err(calculate::Error::SerializeError)?;
+        let req = req_builder
+            .uri(url.as_str())
+            .body(req_body)
+            .map_err(calculate::Error::BuildRequestError)?;
+        let rsp = self
+            .http_client()
+            .execute_request(req)
+            .await
+            .map_err(calculate::Error::ExecuteRequestError)?;
+        let rsp_body = rsp.body();
+        match rsp.status() {
+            http::StatusCode::OK => {
+                let response: CalculatePriceResponse =
+                    serde_json::from_slice(rsp_body).map_err(|source| calculate::Error::DeserializeError(source, rsp_body.clone()))?;
+                PriceQuote::from_response(response)
+            }
+            status_code => {
+                let value: Error =
+                    serde_json::from_slice(rsp_body).map_err(|source| calculate::Error::DeserializeError(source, rsp_body.clone()))?;
+                match value.error {
+                    Some(info) if info.code == Some(ErrorResponseCode::CalculatePriceFailed) => {
+                        Err(CalculatePriceError::CalculatePriceFailed(info.message.unwrap_or_default()))
+                    }
+                    error => Err(calculate::Error::DefaultResponse {
+                        status_code,
+                        value: Error { error },
+                    }
+                    .into()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04"
+    )
+))]
+mod tests {
+    use super::*;
+    use crate::models::{AppliedScopeType, ReservedResourceType};
+    use crate::paging::mock::config;
+    use http::StatusCode;
+
+    const SUBSCRIPTION_ID: &str = "0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42";
+
+    fn purchase_request() -> PurchaseRequest {
+        PurchaseRequest {
+            sku: "Standard_D2s_v3".to_owned(),
+            location: Some("westus".to_owned()),
+            reserved_resource_type: ReservedResourceType::VirtualMachines,
+            term: "P3Y".to_owned(),
+            quantity: 1,
+            billing_scope_id: format!("/subscriptions/{}", SUBSCRIPTION_ID),
+            billing_plan: None,
+            display_name: None,
+            applied_scope_type: AppliedScopeType::Shared,
+            applied_scopes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn the_price_is_quoted() {
+        let (config, transport) = config(vec![(
+            StatusCode::OK,
+            r#"{
+                "properties": {
+                    "billingCurrencyTotal": { "currencyCode": "EUR", "amount": 1462.5 },
+                    "isBillingPartnerManaged": false,
+                    "reservationOrderId": "6d9cec54-7de8-abcd-9de7-80f5d634f2d2",
+                    "skuTitle": "Reserved VM Instance, Standard_D2s_v3, West US, 3 Years",
+                    "skuDescription": "Standard_D2s_v3",
+                    "pricingCurrencyTotal": { "currencyCode": "USD", "amount": 1702.0 },
+                    "paymentSchedule": []
+                }
+            }"#,
+        )]);
+
+        let quote = futures::executor::block_on(config.calculate_reservation_price(purchase_request())).unwrap();
+
+        assert_eq!(quote.quote_id, "6d9cec54-7de8-abcd-9de7-80f5d634f2d2");
+        assert_eq!(quote.currency_code, "EUR");
+        assert_eq!(quote.amount, 1462.5);
+        assert_eq!(quote.properties.sku_description.as_deref(), Some("Standard_D2s_v3"));
+        assert_eq!(
+            transport.uris.lock().unwrap()[0],
+            format!(
+                "https://management.azure.com/providers/Microsoft.Capacity/calculatePrice?api-version={}",
+                crate::API_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn a_failed_calculation_is_typed() {
+        let (config, _) = config(vec![(
+            StatusCode::BAD_REQUEST,
+            r#"{ "error": { "code": "CalculatePriceFailed", "message": "The SKU is not available in this region." } }"#,
+        )]);
+
+        let result = futures::executor::block_on(config.calculate_reservation_price(purchase_request()));
+
+        assert!(matches!(
+            result,
+            Err(CalculatePriceError::CalculatePriceFailed(message)) if message == "The SKU is not available in this region."
+        ));
+    }
+
+    #[test]
+    fn an_invalid_purchase_is_not_quoted() {
+        let (config, transport) = config(Vec::new());
+        let purchase = PurchaseRequest {
+            quantity: 0,
+            ..purchase_request()
+        };
+
+        let result = futures::executor::block_on(config.calculate_reservation_price(purchase));
+
+        assert!(matches!(
+            result,
+            Err(CalculatePriceError::InvalidPurchase(PurchaseError::ZeroQuantity))
+        ));
+        assert!(transport.uris.lock().unwrap().is_empty());
+    }
+}
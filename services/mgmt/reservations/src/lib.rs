@@ -26,6 +26,7 @@ pub use package_2018_06::{models, operations, API_VERSION};
 #[cfg(feature = "package-2017-11")]
 mod package_2017_11;
 use azure_core::setters;
+pub use azure_core::JsonValueExt;
 #[cfg(feature = "package-2017-11")]
 pub use package_2017_11::{models, operations, API_VERSION};
 pub fn config(
@@ -52,24 +53,31 @@ impl OperationConfigBuilder {
     pub fn build(self) -> OperationConfig {
         OperationConfig {
             api_version: self.api_version.unwrap_or(API_VERSION.to_owned()),
-            http_client: self.http_client,
+            http_client: std::sync::Arc::new(azure_core::ArmThrottlingHttpClient::new(self.http_client)),
             base_path: self.base_path.unwrap_or("https://management.azure.com".to_owned()),
-            token_credential: Some(self.token_credential),
+            token_credential: Some(self.token_credential.into()),
             token_credential_resource: self.token_credential_resource.unwrap_or("https://management.azure.com/".to_owned()),
         }
     }
 }
+#[derive(Clone)]
 pub struct OperationConfig {
     api_version: String,
     http_client: std::sync::Arc<dyn azure_core::HttpClient>,
     base_path: String,
-    token_credential: Option<Box<dyn azure_core::TokenCredential>>,
+    token_credential: Option<std::sync::Arc<dyn azure_core::TokenCredential>>,
     token_credential_resource: String,
 }
 impl OperationConfig {
     pub fn api_version(&self) -> &str {
         self.api_version.as_str()
     }
+    pub fn with_api_version(&self, api_version: impl Into<String>) -> Self {
+        Self {
+            api_version: api_version.into(),
+            ..self.clone()
+        }
+    }
     pub fn http_client(&self) -> &dyn azure_core::HttpClient {
         self.http_client.as_ref()
     }
@@ -83,3 +91,192 @@ impl OperationConfig {
         self.token_credential_resource.as_str()
     }
 }
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod applied_scope;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use applied_scope::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod merge_request;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use merge_request::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod sku_restriction;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use sku_restriction::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod reservation_orders;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use reservation_orders::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod paging;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use paging::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod applied_reservations;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use applied_reservations::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod patch;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use patch::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod purchase;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use purchase::*;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+mod calculate_price;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06",
+    feature = "package-2017-11"
+))]
+#[allow(unused_imports)]
+pub use calculate_price::*;
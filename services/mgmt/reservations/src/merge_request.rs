@@ -0,0 +1,48 @@
+//! Building a `MergeRequest` from the ids of the reservations to merge.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::applied_scope::is_guid;
+use crate::models::{MergeProperties, MergeRequest};
+
+impl MergeRequest {
+    /// Merges the reservations `reservation_ids` of the reservation order `order_id`.
+    ///
+    /// The `sources` are the fully qualified
+    /// `/providers/Microsoft.Capacity/reservationOrders/{order_id}/reservations/{reservation_id}`
+    /// ids, once each and in the given order. At least two distinct reservations are needed.
+    pub fn from_reservation_ids(order_id: &str, reservation_ids: &[&str]) -> Result<Self, MergeRequestError> {
+        if !is_guid(order_id) {
+            return Err(MergeRequestError::InvalidReservationOrderId(order_id.to_owned()));
+        }
+        let mut sources = Vec::with_capacity(reservation_ids.len());
+        for reservation_id in reservation_ids {
+            if !is_guid(reservation_id) {
+                return Err(MergeRequestError::InvalidReservationId((*reservation_id).to_owned()));
+            }
+            let source = format!(
+                "/providers/Microsoft.Capacity/reservationOrders/{}/reservations/{}",
+                order_id, reservation_id
+            );
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        if sources.len() < 2 {
+            return Err(MergeRequestError::NotEnoughSources(sources.len()));
+        }
+        Ok(Self {
+            properties: Some(MergeProperties { sources }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MergeRequestError {
+    #[error("reservation order id is not a GUID: {0}")]
+    InvalidReservationOrderId(String),
+    #[error("reservation id is not a GUID: {0}")]
+    InvalidReservationId(String),
+    #[error("a merge needs at least two distinct reservations, got {0}")]
+    NotEnoughSources(usize),
+}
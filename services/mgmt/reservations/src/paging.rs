@@ -0,0 +1,78 @@
+//! Following the `nextLink` of the paged responses.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::OperationConfig;
+
+impl OperationConfig {
+    /// The url of the page at `next_link`, with the api-version of this configuration.
+    ///
+    /// The service usually repeats the api-version in the `nextLink`: it is replaced rather than
+    /// sent twice.
+    pub(crate) fn next_link_url(&self, next_link: &str) -> Result<url::Url, url::ParseError> {
+        let mut url = url::Url::parse(next_link)?;
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| name != "api-version")
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(query)
+            .append_pair("api-version", self.api_version());
+        Ok(url)
+    }
+}
+
+/// An `OperationConfig` answering the requests with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use crate::OperationConfig;
+    use bytes::Bytes;
+    use http::{Request, Response, StatusCode};
+    use std::sync::{Arc, Mutex};
+
+    /// Answers the requests with `responses`, in order, and records their uris and bodies.
+    #[derive(Debug, Default)]
+    pub(crate) struct MockTransport {
+        responses: Mutex<Vec<(StatusCode, &'static str)>>,
+        pub(crate) uris: Mutex<Vec<String>>,
+        pub(crate) bodies: Mutex<Vec<Bytes>>,
+    }
+
+    #[async_trait::async_trait]
+    impl azure_core::HttpClient for MockTransport {
+        async fn execute_request(&self, request: Request<Bytes>) -> Result<Response<Bytes>, azure_core::HttpError> {
+            self.uris.lock().unwrap().push(request.uri().to_string());
+            self.bodies.lock().unwrap().push(request.body().clone());
+            let (status, body) = self.responses.lock().unwrap().remove(0);
+            Ok(Response::builder()
+                .status(status)
+                .body(Bytes::from_static(body.as_bytes()))
+                .unwrap())
+        }
+
+        async fn execute_request2(&self, _request: &azure_core::Request) -> Result<azure_core::Response, azure_core::HttpError> {
+            Err(azure_core::HttpError::new_unexpected_status_code(
+                StatusCode::OK,
+                StatusCode::NOT_IMPLEMENTED,
+                "the operations do not use the pipeline",
+            ))
+        }
+    }
+
+    pub(crate) fn config(responses: Vec<(StatusCode, &'static str)>) -> (OperationConfig, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport {
+            responses: Mutex::new(responses),
+            ..MockTransport::default()
+        });
+        let config = OperationConfig {
+            api_version: crate::API_VERSION.to_owned(),
+            http_client: transport.clone(),
+            base_path: "https://management.azure.com".to_owned(),
+            token_credential: None,
+            token_credential_resource: "https://management.azure.com/".to_owned(),
+        };
+        (config, transport)
+    }
+}
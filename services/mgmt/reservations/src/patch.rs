@@ -0,0 +1,299 @@
+//! Changing the applied scope of a reservation with a checked `Patch`.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. The instance
+//! flexibility and name of a reservation can only be patched from `package-2018-06` on.
+
+use crate::applied_scope::{AppliedScope, AppliedScopesBuilder};
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04",
+    feature = "package-2018-06"
+))]
+use crate::models::InstanceFlexibility;
+use crate::models::{AppliedScopeType, Patch, PatchProperties, ReservationResponse};
+use crate::operations::reservation::update;
+use crate::OperationConfig;
+
+impl Patch {
+    /// Checks the applied scope of the patch: a `Single` scope type needs exactly one scope, a
+    /// `Shared` one needs none, and scopes need a scope type.
+    pub fn validate(&self) -> Result<(), PatchError> {
+        let properties = match &self.properties {
+            Some(properties) => properties,
+            None => return Ok(()),
+        };
+        let scopes = properties.applied_scopes.as_ref().map_or(0, Vec::len);
+        match (&properties.applied_scope_type, scopes) {
+            (Some(AppliedScopeType::Single), 1) | (Some(AppliedScopeType::Shared), 0) | (None, 0) => Ok(()),
+            (Some(AppliedScopeType::Single), scopes) => Err(PatchError::SingleScopeCount(scopes)),
+            (Some(AppliedScopeType::Shared), _) => Err(PatchError::SharedWithScopes),
+            (None, _) => Err(PatchError::ScopesWithoutScopeType),
+        }
+    }
+}
+
+/// Builds a `Patch` of a reservation, checking its applied scope.
+///
+/// ```ignore
+/// let patch = PatchBuilder::new()
+///     .applied_scope_type(AppliedScopeType::Single)
+///     .scope(AppliedScope::subscription("00000000-0000-0000-0000-000000000000")?)
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PatchBuilder {
+    applied_scope_type: Option<AppliedScopeType>,
+    applied_scopes: Option<AppliedScopesBuilder>,
+    #[cfg(any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04",
+        feature = "package-2018-06"
+    ))]
+    instance_flexibility: Option<InstanceFlexibility>,
+    #[cfg(any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04",
+        feature = "package-2018-06"
+    ))]
+    name: Option<String>,
+}
+
+impl PatchBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn applied_scope_type(mut self, applied_scope_type: AppliedScopeType) -> Self {
+        self.applied_scope_type = Some(applied_scope_type);
+        self
+    }
+
+    pub fn scope(mut self, scope: AppliedScope) -> Self {
+        self.applied_scopes = Some(self.applied_scopes.unwrap_or_default().scope(scope));
+        self
+    }
+
+    #[cfg(any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04",
+        feature = "package-2018-06"
+    ))]
+    pub fn instance_flexibility(mut self, instance_flexibility: InstanceFlexibility) -> Self {
+        self.instance_flexibility = Some(instance_flexibility);
+        self
+    }
+
+    #[cfg(any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04",
+        feature = "package-2018-06"
+    ))]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn build(self) -> Result<Patch, PatchError> {
+        let patch = Patch {
+            properties: Some(PatchProperties {
+                applied_scope_type: self.applied_scope_type,
+                applied_scopes: self.applied_scopes.map(AppliedScopesBuilder::build),
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04",
+                    feature = "package-2018-06"
+                ))]
+                instance_flexibility: self.instance_flexibility,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04",
+                    feature = "package-2018-06"
+                ))]
+                name: self.name,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04"
+                ))]
+                renew: None,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04"
+                ))]
+                renew_properties: None,
+            }),
+        };
+        patch.validate()?;
+        Ok(patch)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatchError {
+    #[error("a Single applied scope type needs exactly one scope, got {0}")]
+    SingleScopeCount(usize),
+    #[error("a Shared applied scope type takes no scopes")]
+    SharedWithScopes,
+    #[error("applied scopes need an applied scope type")]
+    ScopesWithoutScopeType,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateReservationError {
+    #[error("invalid patch: {0}")]
+    InvalidPatch(#[from] PatchError),
+    #[error(transparent)]
+    Update(#[from] update::Error),
+}
+
+impl OperationConfig {
+    /// Applies `patch` to the reservation `reservation_id` of the reservation order `order_id`.
+    ///
+    /// The patch is validated before being sent. The updated reservation is `None` when the
+    /// service accepted the update to apply it asynchronously.
+    pub async fn update_reservation(
+        &self,
+        order_id: &str,
+        reservation_id: &str,
+        patch: Patch,
+    ) -> Result<Option<ReservationResponse>, UpdateReservationError> {
+        patch.validate()?;
+        match crate::operations::reservation::update(self, order_id, reservation_id, &patch).await? {
+            update::Response::Ok200(reservation) => Ok(Some(reservation)),
+            update::Response::Accepted202 => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paging::mock::config;
+    use http::StatusCode;
+
+    const ORDER_ID: &str = "a075419f-44cc-497f-b68a-14ee811d48b9";
+    const RESERVATION_ID: &str = "b1f6c2a3-5d4e-4f70-8a91-0c2d3e4f5a6b";
+    const SUBSCRIPTION_ID: &str = "0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42";
+
+    #[test]
+    fn a_shared_reservation_is_changed_to_a_single_scope() {
+        let (config, transport) = config(vec![(
+            StatusCode::OK,
+            r#"{
+                "name": "a075419f-44cc-497f-b68a-14ee811d48b9/b1f6c2a3-5d4e-4f70-8a91-0c2d3e4f5a6b",
+                "properties": {
+                    "appliedScopeType": "Single",
+                    "appliedScopes": ["/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42"]
+                }
+            }"#,
+        )]);
+        let patch = PatchBuilder::new()
+            .applied_scope_type(AppliedScopeType::Single)
+            .scope(AppliedScope::subscription(SUBSCRIPTION_ID).unwrap())
+            .build()
+            .unwrap();
+
+        let reservation = futures::executor::block_on(config.update_reservation(ORDER_ID, RESERVATION_ID, patch))
+            .unwrap()
+            .unwrap();
+
+        let properties = reservation.properties.unwrap();
+        assert_eq!(properties.applied_scope_type, Some(AppliedScopeType::Single));
+        assert_eq!(properties.applied_scopes, Some(vec![format!("/subscriptions/{}", SUBSCRIPTION_ID)]));
+        assert_eq!(
+            transport.uris.lock().unwrap()[0],
+            format!(
+                "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders/{}/reservations/{}?api-version={}",
+                ORDER_ID,
+                RESERVATION_ID,
+                crate::API_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn an_invalid_patch_is_not_sent() {
+        let (config, transport) = config(Vec::new());
+        let patch = Patch {
+            properties: Some(PatchProperties {
+                applied_scope_type: Some(AppliedScopeType::Shared),
+                applied_scopes: Some(vec![format!("/subscriptions/{}", SUBSCRIPTION_ID)]),
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04",
+                    feature = "package-2018-06"
+                ))]
+                instance_flexibility: None,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04",
+                    feature = "package-2018-06"
+                ))]
+                name: None,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04"
+                ))]
+                renew: None,
+                #[cfg(any(
+                    feature = "package-2020-10-25",
+                    feature = "package-2020-11-preview",
+                    feature = "package-2020-10-preview",
+                    feature = "package-preview-2019-07-19",
+                    feature = "package-preview-2019-04"
+                ))]
+                renew_properties: None,
+            }),
+        };
+
+        let result = futures::executor::block_on(config.update_reservation(ORDER_ID, RESERVATION_ID, patch));
+
+        assert!(matches!(
+            result,
+            Err(UpdateReservationError::InvalidPatch(PatchError::SharedWithScopes))
+        ));
+        assert!(transport.uris.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn a_single_scope_type_needs_a_scope() {
+        let result = PatchBuilder::new().applied_scope_type(AppliedScopeType::Single).build();
+
+        assert_eq!(result.unwrap_err(), PatchError::SingleScopeCount(0));
+    }
+}
@@ -0,0 +1,331 @@
+//! Purchasing a reservation order with a checked `PurchaseRequest`.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated. The generated
+//! `models::PurchaseRequest` models its billing scope as an empty object, so the purchases are
+//! sent with the body of the `PurchaseRequest` of this module instead. Only the api versions
+//! with a purchase operation, from preview-2019-04, are wired in.
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::applied_scope::{is_guid, AppliedScope};
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::models::{AppliedScopeType, Error, ReservationBillingPlan, ReservationOrderResponse, ReservationTerm, ReservedResourceType};
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::operations::reservation_order::purchase;
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+use crate::OperationConfig;
+
+/// What to reserve, for how long, who pays for it and what it applies to.
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurchaseRequest {
+    /// The SKU to reserve, such as `Standard_D2s_v3`.
+    pub sku: String,
+    /// The region of the reserved resources, such as `westus`, for the regional SKUs.
+    pub location: Option<String>,
+    pub reserved_resource_type: ReservedResourceType,
+    /// The length of the reservation, `P1Y` or `P3Y`.
+    pub term: String,
+    /// The number of resources reserved, at least one.
+    pub quantity: u32,
+    /// The subscription billed for the reservation: `/subscriptions/{subscriptionId}`.
+    pub billing_scope_id: String,
+    pub billing_plan: Option<ReservationBillingPlan>,
+    pub display_name: Option<String>,
+    pub applied_scope_type: AppliedScopeType,
+    /// The one scope of a `Single` applied scope type, none for a `Shared` one.
+    pub applied_scopes: Vec<AppliedScope>,
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+impl PurchaseRequest {
+    /// Checks the purchase: the term is `P1Y` or `P3Y`, at least one resource is reserved, the
+    /// billing scope is a subscription, a `Single` scope type has exactly one scope and a
+    /// `Shared` one has none.
+    pub fn validate(&self) -> Result<(), PurchaseError> {
+        self.reservation_term()?;
+        if self.quantity == 0 {
+            return Err(PurchaseError::ZeroQuantity);
+        }
+        match self.billing_scope_id.parse::<AppliedScope>() {
+            Ok(scope) if scope.resource_group_name().is_none() => {}
+            _ => return Err(PurchaseError::InvalidBillingScope(self.billing_scope_id.clone())),
+        }
+        match (&self.applied_scope_type, self.applied_scopes.len()) {
+            (AppliedScopeType::Single, 1) | (AppliedScopeType::Shared, 0) => Ok(()),
+            (AppliedScopeType::Single, scopes) => Err(PurchaseError::SingleScopeCount(scopes)),
+            (AppliedScopeType::Shared, _) => Err(PurchaseError::SharedWithScopes),
+        }
+    }
+
+    fn reservation_term(&self) -> Result<ReservationTerm, PurchaseError> {
+        match self.term.as_str() {
+            "P1Y" => Ok(ReservationTerm::P1y),
+            "P3Y" => Ok(ReservationTerm::P3y),
+            _ => Err(PurchaseError::UnsupportedTerm(self.term.clone())),
+        }
+    }
+
+    /// The body of the `PUT` of the reservation order, also the one of the price calculation.
+    pub(crate) fn to_json(&self) -> Result<serde_json::Value, PurchaseError> {
+        let mut properties = serde_json::json!({
+            "reservedResourceType": self.reserved_resource_type,
+            "billingScopeId": self.billing_scope_id,
+            "term": self.reservation_term()?,
+            "quantity": self.quantity,
+            "appliedScopeType": self.applied_scope_type,
+        });
+        if let Some(billing_plan) = &self.billing_plan {
+            properties["billingPlan"] = serde_json::json!(billing_plan);
+        }
+        if let Some(display_name) = &self.display_name {
+            properties["displayName"] = serde_json::json!(display_name);
+        }
+        if !self.applied_scopes.is_empty() {
+            let applied_scopes: Vec<String> = self.applied_scopes.iter().map(ToString::to_string).collect();
+            properties["appliedScopes"] = serde_json::json!(applied_scopes);
+        }
+        let mut body = serde_json::json!({
+            "sku": { "name": self.sku },
+            "properties": properties,
+        });
+        if let Some(location) = &self.location {
+            body["location"] = serde_json::json!(location);
+        }
+        Ok(body)
+    }
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PurchaseError {
+    #[error("reservation order id is not a GUID: {0}")]
+    InvalidReservationOrderId(String),
+    #[error("unsupported reservation term {0}, expected P1Y or P3Y")]
+    UnsupportedTerm(String),
+    #[error("a reservation needs a quantity of at least one")]
+    ZeroQuantity,
+    #[error("billing scope is not a /subscriptions/{{subscriptionId}} scope: {0}")]
+    InvalidBillingScope(String),
+    #[error("a Single applied scope type needs exactly one scope, got {0}")]
+    SingleScopeCount(usize),
+    #[error("a Shared applied scope type takes no scopes")]
+    SharedWithScopes,
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+#[derive(Debug, thiserror::Error)]
+pub enum PurchaseReservationOrderError {
+    #[error("invalid purchase: {0}")]
+    InvalidPurchase(#[from] PurchaseError),
+    #[error(transparent)]
+    Purchase(#[from] purchase::Error),
+}
+
+#[cfg(any(
+    feature = "package-2020-10-25",
+    feature = "package-2020-11-preview",
+    feature = "package-2020-10-preview",
+    feature = "package-preview-2019-07-19",
+    feature = "package-preview-2019-04"
+))]
+impl OperationConfig {
+    /// Purchases the reservation order `order_id`, a GUID chosen by the caller.
+    ///
+    /// The purchase is validated before being sent. The order is returned whether the service
+    /// completed the purchase or accepted it to complete it asynchronously.
+    pub async fn purchase_reservation_order(
+        &self,
+        order_id: &str,
+        body: PurchaseRequest,
+    ) -> Result<ReservationOrderResponse, PurchaseReservationOrderError> {
+        if !is_guid(order_id) {
+            return Err(PurchaseError::InvalidReservationOrderId(order_id.to_owned()).into());
+        }
+        body.validate()?;
+        let body = body.to_json()?;
+
+        let url_str = &format!("{}/providers/Microsoft.Capacity/reservationOrders/{}", self.base_path(), order_id);
+        let mut url = url::Url::parse(url_str).map_err(purchase::Error::ParseUrlError)?;
+        url.query_pairs_mut().append_pair("api-version", self.api_version());
+        let mut req_builder = http::request::Builder::new().method(http::Method::PUT);
+        if let Some(token_credential) = self.token_credential() {
+            let token_response = token_credential
+                .get_token(self.token_credential_resource())
+                .await
+                .map_err(purchase::Error::GetTokenError)?;
+            req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        }
+        let req_body = azure_core::to_json(&body).map_err(purchase::Error::SerializeError)?;
+        let req = req_builder
+            .uri(url.as_str())
+            .body(req_body)
+            .map_err(purchase::Error::BuildRequestError)?;
+        let rsp = self
+            .http_client()
+            .execute_request(req)
+            .await
+            .map_err(purchase::Error::ExecuteRequestError)?;
+        let rsp_body = rsp.body();
+        match rsp.status() {
+            http::StatusCode::OK | http::StatusCode::ACCEPTED => {
+                Ok(serde_json::from_slice(rsp_body).map_err(|source| purchase::Error::DeserializeError(source, rsp_body.clone()))?)
+            }
+            status_code => {
+                let value: Error =
+                    serde_json::from_slice(rsp_body).map_err(|source| purchase::Error::DeserializeError(source, rsp_body.clone()))?;
+                Err(purchase::Error::DefaultResponse { status_code, value }.into())
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "package-2020-10-25",
+        feature = "package-2020-11-preview",
+        feature = "package-2020-10-preview",
+        feature = "package-preview-2019-07-19",
+        feature = "package-preview-2019-04"
+    )
+))]
+mod tests {
+    use super::*;
+    use crate::paging::mock::config;
+    use http::StatusCode;
+
+    const ORDER_ID: &str = "a075419f-44cc-497f-b68a-14ee811d48b9";
+    const SUBSCRIPTION_ID: &str = "0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42";
+
+    fn purchase_request() -> PurchaseRequest {
+        PurchaseRequest {
+            sku: "Standard_D2s_v3".to_owned(),
+            location: Some("westus".to_owned()),
+            reserved_resource_type: ReservedResourceType::VirtualMachines,
+            term: "P1Y".to_owned(),
+            quantity: 2,
+            billing_scope_id: format!("/subscriptions/{}", SUBSCRIPTION_ID),
+            billing_plan: Some(ReservationBillingPlan::Monthly),
+            display_name: None,
+            applied_scope_type: AppliedScopeType::Single,
+            applied_scopes: vec![AppliedScope::resource_group(SUBSCRIPTION_ID, "web").unwrap()],
+        }
+    }
+
+    #[test]
+    fn a_valid_purchase_is_sent() {
+        let (config, transport) = config(vec![(
+            StatusCode::ACCEPTED,
+            r#"{ "name": "a075419f-44cc-497f-b68a-14ee811d48b9", "properties": { "provisioningState": "Creating" } }"#,
+        )]);
+
+        let order = futures::executor::block_on(config.purchase_reservation_order(ORDER_ID, purchase_request())).unwrap();
+
+        assert_eq!(order.name.as_deref(), Some(ORDER_ID));
+        assert_eq!(
+            transport.uris.lock().unwrap()[0],
+            format!(
+                "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders/{}?api-version={}",
+                ORDER_ID,
+                crate::API_VERSION
+            )
+        );
+        let body: serde_json::Value = serde_json::from_slice(&transport.bodies.lock().unwrap()[0]).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "sku": { "name": "Standard_D2s_v3" },
+                "location": "westus",
+                "properties": {
+                    "reservedResourceType": "VirtualMachines",
+                    "billingScopeId": format!("/subscriptions/{}", SUBSCRIPTION_ID),
+                    "term": "P1Y",
+                    "quantity": 2,
+                    "billingPlan": "Monthly",
+                    "appliedScopeType": "Single",
+                    "appliedScopes": [format!("/subscriptions/{}/resourceGroups/web", SUBSCRIPTION_ID)],
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn an_unsupported_term_is_not_sent() {
+        let (config, transport) = config(Vec::new());
+        let purchase = PurchaseRequest {
+            term: "P5Y".to_owned(),
+            ..purchase_request()
+        };
+
+        let result = futures::executor::block_on(config.purchase_reservation_order(ORDER_ID, purchase));
+
+        assert!(matches!(
+            result,
+            Err(PurchaseReservationOrderError::InvalidPurchase(PurchaseError::UnsupportedTerm(term))) if term == "P5Y"
+        ));
+        assert!(transport.uris.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn the_applied_scopes_follow_the_scope_type() {
+        let shared = PurchaseRequest {
+            applied_scope_type: AppliedScopeType::Shared,
+            ..purchase_request()
+        };
+        assert_eq!(shared.validate(), Err(PurchaseError::SharedWithScopes));
+
+        let single = PurchaseRequest {
+            applied_scopes: Vec::new(),
+            ..purchase_request()
+        };
+        assert_eq!(single.validate(), Err(PurchaseError::SingleScopeCount(0)));
+    }
+}
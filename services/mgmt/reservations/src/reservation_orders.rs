@@ -0,0 +1,186 @@
+//! Listing all the reservation orders, page after page, and getting one of them.
+//!
+//! Only the 2017-11 api version, the one of the `package-2017-11` feature, is wired in.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+#[cfg(feature = "package-2017-11")]
+use crate::models::{Error, ReservationOrderList, ReservationOrderResponse};
+#[cfg(feature = "package-2017-11")]
+use crate::operations::reservation_order::{get, list};
+#[cfg(feature = "package-2017-11")]
+use crate::OperationConfig;
+#[cfg(feature = "package-2017-11")]
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+
+/// The page of reservation orders to request next.
+#[cfg(feature = "package-2017-11")]
+enum NextPage {
+    First,
+    Link(String),
+    Done,
+}
+
+#[cfg(feature = "package-2017-11")]
+impl OperationConfig {
+    /// All the reservation orders the caller can access, following the `nextLink` of each page.
+    ///
+    /// An error ends the stream.
+    pub fn list_reservation_orders(&self) -> impl Stream<Item = Result<ReservationOrderResponse, list::Error>> + '_ {
+        stream::unfold(NextPage::First, move |next_page| async move {
+            let page = match next_page {
+                NextPage::First => crate::operations::reservation_order::list(self).await,
+                NextPage::Link(next_link) => self.list_reservation_orders_page(&next_link).await,
+                NextPage::Done => return None,
+            };
+            Some(match page {
+                Ok(ReservationOrderList { value, next_link }) => {
+                    let next_page = match next_link {
+                        Some(next_link) if !next_link.is_empty() => NextPage::Link(next_link),
+                        _ => NextPage::Done,
+                    };
+                    (Ok(value), next_page)
+                }
+                Err(error) => (Err(error), NextPage::Done),
+            })
+        })
+        .map_ok(|orders| stream::iter(orders.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+    }
+
+    /// The reservation order `order_id`.
+    pub async fn get_reservation_order(&self, order_id: &str) -> Result<ReservationOrderResponse, get::Error> {
+        crate::operations::reservation_order::get(self, order_id).await
+    }
+
+    /// The page of reservation orders at `next_link`.
+    async fn list_reservation_orders_page(&self, next_link: &str) -> Result<ReservationOrderList, list::Error> {
+        let url = self.next_link_url(next_link).map_err(list::Error::ParseUrlError)?;
+        let mut req_builder = http::request::Builder::new().method(http::Method::GET);
+        if let Some(token_credential) = self.token_credential() {
+            let token_response = token_credential
+                .get_token(self.token_credential_resource())
+                .await
+                .map_err(list::Error::GetTokenError)?;
+            req_builder = req_builder.header(http::header::AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        }
+        let req = req_builder
+            .uri(url.as_str())
+            .body(bytes::Bytes::from_static(azure_core::EMPTY_BODY))
+            .map_err(list::Error::BuildRequestError)?;
+        let rsp = self
+            .http_client()
+            .execute_request(req)
+            .await
+            .map_err(list::Error::ExecuteRequestError)?;
+        let rsp_body = rsp.body();
+        match rsp.status() {
+            http::StatusCode::OK => {
+                serde_json::from_slice(rsp_body).map_err(|source| list::Error::DeserializeError(source, rsp_body.clone()))
+            }
+            status_code => {
+                let value: Error =
+                    serde_json::from_slice(rsp_body).map_err(|source| list::Error::DeserializeError(source, rsp_body.clone()))?;
+                Err(list::Error::DefaultResponse { status_code, value })
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "package-2017-11"))]
+mod tests {
+    use super::*;
+    use crate::paging::mock::config;
+    use http::StatusCode;
+
+    const ORDER_ID: &str = "a075419f-44cc-497f-b68a-14ee811d48b9";
+
+    #[test]
+    fn orders_are_listed_across_pages() {
+        let (config, transport) = config(vec![
+            (
+                StatusCode::OK,
+                r#"{
+                    "value": [{ "name": "first" }, { "name": "second" }],
+                    "nextLink": "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders?api-version=2017-11-01&$skiptoken=abc"
+                }"#,
+            ),
+            (StatusCode::OK, r#"{ "value": [{ "name": "third" }] }"#),
+        ]);
+
+        let orders: Vec<ReservationOrderResponse> = futures::executor::block_on(config.list_reservation_orders().try_collect()).unwrap();
+
+        let names: Vec<_> = orders.iter().map(|order| order.name.as_deref().unwrap()).collect();
+        assert_eq!(names, vec!["first", "second", "third"]);
+        let uris = transport.uris.lock().unwrap();
+        assert_eq!(
+            *uris,
+            vec![
+                format!(
+                    "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders?api-version={}",
+                    crate::API_VERSION
+                ),
+                format!(
+                    "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders?%24skiptoken=abc&api-version={}",
+                    crate::API_VERSION
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_failed_page_ends_the_listing() {
+        let (config, _transport) = config(vec![(
+            StatusCode::FORBIDDEN,
+            r#"{ "error": { "code": "AuthorizationFailed", "message": "no access" } }"#,
+        )]);
+
+        let orders: Vec<_> = futures::executor::block_on(config.list_reservation_orders().collect());
+
+        assert_eq!(orders.len(), 1);
+        assert!(matches!(
+            &orders[0],
+            Err(list::Error::DefaultResponse {
+                status_code: StatusCode::FORBIDDEN,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn an_order_is_got_by_id() {
+        let (config, transport) = config(vec![(
+            StatusCode::OK,
+            r#"{ "id": "/providers/microsoft.capacity/reservationOrders/a075419f-44cc-497f-b68a-14ee811d48b9", "name": "a075419f-44cc-497f-b68a-14ee811d48b9" }"#,
+        )]);
+
+        let order = futures::executor::block_on(config.get_reservation_order(ORDER_ID)).unwrap();
+
+        assert_eq!(order.name.as_deref(), Some(ORDER_ID));
+        assert_eq!(
+            transport.uris.lock().unwrap()[0],
+            format!(
+                "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders/{}?api-version={}",
+                ORDER_ID,
+                crate::API_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn an_order_is_got_with_an_overridden_api_version() {
+        let (config, transport) = config(vec![(StatusCode::OK, r#"{ "name": "a075419f-44cc-497f-b68a-14ee811d48b9" }"#)]);
+
+        futures::executor::block_on(get(&config.with_api_version("2017-11-01-preview"), ORDER_ID)).unwrap();
+
+        assert_eq!(
+            transport.uris.lock().unwrap()[0],
+            format!(
+                "https://management.azure.com/providers/Microsoft.Capacity/reservationOrders/{}?api-version=2017-11-01-preview",
+                ORDER_ID
+            )
+        );
+        assert_eq!(config.api_version(), crate::API_VERSION);
+    }
+}
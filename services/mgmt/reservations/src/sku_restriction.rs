@@ -0,0 +1,49 @@
+//! Typed reading of the restrictions of a SKU in the reservations catalog.
+//!
+//! This module is hand-written: it is kept when the crate is regenerated.
+
+use crate::models::SkuRestriction;
+use serde::de::{value::StrDeserializer, IntoDeserializer};
+use serde::{Deserialize, Serialize};
+
+/// What a SKU is restricted on, the `type` of a `SkuRestriction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RestrictionType {
+    /// The SKU is not available in the locations of the `values`.
+    Location,
+    /// The SKU is not available in the availability zones of the `values`.
+    Zone,
+    /// A type this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Why a SKU is restricted, the `reasonCode` of a `SkuRestriction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RestrictionReason {
+    /// The offer of the subscription does not include the SKU.
+    QuotaId,
+    /// The SKU cannot be purchased by the subscription.
+    NotAvailableForSubscription,
+    /// A reason this crate does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+impl SkuRestriction {
+    /// The `type` of the restriction, if the service gave one.
+    pub fn restriction_type(&self) -> Option<RestrictionType> {
+        self.type_.as_deref().map(parse)
+    }
+
+    /// The `reasonCode` of the restriction, if the service gave one.
+    pub fn reason(&self) -> Option<RestrictionReason> {
+        self.reason_code.as_deref().map(parse)
+    }
+}
+
+/// Reads a restriction string as its enum, the `Unknown` variant if it is not a known one.
+fn parse<'de, T: Deserialize<'de>>(s: &'de str) -> T {
+    let deserializer: StrDeserializer<'de, serde::de::value::Error> = s.into_deserializer();
+    T::deserialize(deserializer).expect("the restriction enums fall back to Unknown")
+}
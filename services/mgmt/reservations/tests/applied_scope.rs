@@ -0,0 +1,64 @@
+use azure_mgmt_reservations::models::PatchProperties;
+use azure_mgmt_reservations::{AppliedScope, AppliedScopeError, AppliedScopesBuilder};
+
+const SUBSCRIPTION_ID: &str = "0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42";
+
+#[test]
+fn subscription_scopes_are_parsed() {
+    let scope: AppliedScope = format!("/subscriptions/{}", SUBSCRIPTION_ID).parse().unwrap();
+    assert_eq!(scope, AppliedScope::subscription(SUBSCRIPTION_ID).unwrap());
+    assert_eq!(scope.subscription_id(), SUBSCRIPTION_ID);
+    assert_eq!(scope.resource_group_name(), None);
+    assert_eq!(scope.to_string(), format!("/subscriptions/{}", SUBSCRIPTION_ID));
+}
+
+#[test]
+fn resource_group_scopes_are_parsed() {
+    let scope: AppliedScope = format!("/subscriptions/{}/resourceGroups/my-rg_(1).prod", SUBSCRIPTION_ID)
+        .parse()
+        .unwrap();
+    assert_eq!(scope.resource_group_name(), Some("my-rg_(1).prod"));
+    assert_eq!(
+        scope.to_string(),
+        format!("/subscriptions/{}/resourceGroups/my-rg_(1).prod", SUBSCRIPTION_ID)
+    );
+
+    let mut patch: PatchProperties = serde_json::from_str("{}").unwrap();
+    patch.applied_scopes = Some(
+        AppliedScopesBuilder::new()
+            .scope(scope)
+            .scope(AppliedScope::subscription(SUBSCRIPTION_ID).unwrap())
+            .build(),
+    );
+    assert_eq!(
+        serde_json::to_value(&patch).unwrap()["appliedScopes"],
+        serde_json::json!([
+            format!("/subscriptions/{}/resourceGroups/my-rg_(1).prod", SUBSCRIPTION_ID),
+            format!("/subscriptions/{}", SUBSCRIPTION_ID),
+        ])
+    );
+}
+
+#[test]
+fn malformed_scopes_are_rejected() {
+    for scope in &[
+        "",
+        "subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42",
+        "/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42/",
+        "/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42/resourceGroups",
+        "/subscriptions/0f9b4d1e-3c7a-4b55-9a8e-2d6f1c0b7e42/providers/Microsoft.Compute",
+    ] {
+        assert_eq!(
+            scope.parse::<AppliedScope>(),
+            Err(AppliedScopeError::MalformedScope(scope.to_string()))
+        );
+    }
+    assert_eq!(
+        "/subscriptions/my-subscription".parse::<AppliedScope>(),
+        Err(AppliedScopeError::InvalidSubscriptionId("my-subscription".to_owned()))
+    );
+    assert_eq!(
+        AppliedScope::resource_group(SUBSCRIPTION_ID, "ends-with-a-period."),
+        Err(AppliedScopeError::InvalidResourceGroupName("ends-with-a-period.".to_owned()))
+    );
+}
@@ -0,0 +1,21 @@
+use azure_mgmt_reservations::models::{Catalog, ReservationTerm};
+
+#[test]
+fn catalogs_without_terms_have_no_terms() {
+    let catalog: Catalog = serde_json::from_str(
+        r#"{
+            "resourceType": "VirtualMachines",
+            "name": "Standard_D2s_v3",
+            "locations": ["westus"]
+        }"#,
+    )
+    .unwrap();
+    assert!(catalog.terms.is_empty());
+    assert_eq!(catalog.locations, vec!["westus".to_owned()]);
+}
+
+#[test]
+fn catalog_terms_are_deserialized() {
+    let catalog: Catalog = serde_json::from_str(r#"{ "terms": ["P1Y", "P3Y"] }"#).unwrap();
+    assert_eq!(catalog.terms, vec![ReservationTerm::P1y, ReservationTerm::P3y]);
+}
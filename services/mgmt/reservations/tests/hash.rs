@@ -0,0 +1,20 @@
+use azure_mgmt_reservations::models::{ReservationTerm, SkuName};
+use std::collections::HashSet;
+
+#[test]
+fn generated_enums_are_hashable() {
+    let mut terms = HashSet::new();
+    assert!(terms.insert(ReservationTerm::P1y));
+    assert!(terms.insert(ReservationTerm::P3y));
+    assert!(!terms.insert(ReservationTerm::P1y));
+    assert_eq!(terms.len(), 2);
+}
+
+#[test]
+fn structs_without_floats_or_untyped_values_are_hashable() {
+    let sku = SkuName {
+        name: Some("Standard_D2s_v3".to_owned()),
+    };
+    let skus: HashSet<SkuName> = vec![sku.clone(), sku].into_iter().collect();
+    assert_eq!(skus.len(), 1);
+}
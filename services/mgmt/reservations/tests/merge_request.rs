@@ -0,0 +1,49 @@
+use azure_mgmt_reservations::models::MergeRequest;
+use azure_mgmt_reservations::MergeRequestError;
+
+const ORDER_ID: &str = "a075419f-44cc-497f-b68a-14ee811d48b9";
+const FIRST_ID: &str = "8b2f6c1e-5d3a-4e7b-9c0d-1a2b3c4d5e6f";
+const SECOND_ID: &str = "2d7e9f0a-1b3c-4d5e-8f6a-7b8c9d0e1f2a";
+
+#[test]
+fn two_reservations_are_merged() {
+    let request = MergeRequest::from_reservation_ids(ORDER_ID, &[FIRST_ID, SECOND_ID]).unwrap();
+    assert_eq!(
+        request.properties.unwrap().sources,
+        vec![
+            format!(
+                "/providers/Microsoft.Capacity/reservationOrders/{}/reservations/{}",
+                ORDER_ID, FIRST_ID
+            ),
+            format!(
+                "/providers/Microsoft.Capacity/reservationOrders/{}/reservations/{}",
+                ORDER_ID, SECOND_ID
+            ),
+        ]
+    );
+}
+
+#[test]
+fn a_single_source_is_rejected() {
+    assert_eq!(
+        MergeRequest::from_reservation_ids(ORDER_ID, &[FIRST_ID]),
+        Err(MergeRequestError::NotEnoughSources(1))
+    );
+    // the same reservation twice is still a single source
+    assert_eq!(
+        MergeRequest::from_reservation_ids(ORDER_ID, &[FIRST_ID, FIRST_ID]),
+        Err(MergeRequestError::NotEnoughSources(1))
+    );
+}
+
+#[test]
+fn ids_must_be_guids() {
+    assert_eq!(
+        MergeRequest::from_reservation_ids("order", &[FIRST_ID, SECOND_ID]),
+        Err(MergeRequestError::InvalidReservationOrderId("order".to_owned()))
+    );
+    assert_eq!(
+        MergeRequest::from_reservation_ids(ORDER_ID, &[FIRST_ID, "../other"]),
+        Err(MergeRequestError::InvalidReservationId("../other".to_owned()))
+    );
+}
@@ -0,0 +1,24 @@
+// Generated enums are `#[non_exhaustive]`: outside of this crate a `match` on them only
+// compiles with a wildcard arm. Removing the `_ =>` arms below fails with error E0004.
+use azure_mgmt_reservations::models::{ErrorResponseCode, ReservationTerm};
+
+fn term_in_years(term: &ReservationTerm) -> Option<u32> {
+    match term {
+        ReservationTerm::P1y => Some(1),
+        ReservationTerm::P3y => Some(3),
+        _ => None,
+    }
+}
+
+#[test]
+fn matching_a_generated_enum_requires_a_wildcard() {
+    assert_eq!(term_in_years(&ReservationTerm::P3y), Some(3));
+
+    let code: ErrorResponseCode = serde_json::from_str("\"BadRequest\"").unwrap();
+    let description = match code {
+        ErrorResponseCode::ServerTimeout => "the service timed out",
+        ErrorResponseCode::BadRequest => "the request was invalid",
+        _ => "unexpected error",
+    };
+    assert_eq!(description, "the request was invalid");
+}
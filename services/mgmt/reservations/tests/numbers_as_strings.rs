@@ -0,0 +1,21 @@
+use azure_mgmt_reservations::models::ReservationOrderResponse;
+
+#[test]
+fn etag_is_read_from_a_number() {
+    let order: ReservationOrderResponse = serde_json::from_str(r#"{ "etag": 123 }"#).unwrap();
+
+    assert_eq!(order.etag, Some(123));
+}
+
+#[test]
+fn etag_is_read_from_a_numeric_string() {
+    let order: ReservationOrderResponse = serde_json::from_str(r#"{ "etag": "123" }"#).unwrap();
+
+    assert_eq!(order.etag, Some(123));
+    assert_eq!(serde_json::to_value(&order).unwrap(), serde_json::json!({ "etag": 123 }));
+}
+
+#[test]
+fn etag_is_not_read_from_a_non_numeric_string() {
+    assert!(serde_json::from_str::<ReservationOrderResponse>(r#"{ "etag": "W/\"123\"" }"#).is_err());
+}
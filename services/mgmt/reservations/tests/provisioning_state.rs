@@ -0,0 +1,38 @@
+// Only the 2017-11 package has a `ProvisioningState`:
+// cargo test --no-default-features --features package-2017-11
+#![cfg(feature = "package-2017-11")]
+
+use azure_core::{IntoPollResult, PollResult};
+use azure_mgmt_reservations::models::ProvisioningState;
+
+#[test]
+fn billing_failures_and_expirations_are_failures() {
+    assert_eq!(
+        ProvisioningState::BillingFailed.into_poll_result(),
+        PollResult::Failed("BillingFailed".to_owned())
+    );
+    assert_eq!(
+        ProvisioningState::Expired.into_poll_result(),
+        PollResult::Failed("Expired".to_owned())
+    );
+    assert_eq!(
+        ProvisioningState::Cancelled.into_poll_result(),
+        PollResult::Failed("Cancelled".to_owned())
+    );
+}
+
+#[test]
+fn purchases_are_in_progress_until_they_succeed() {
+    for state in &[
+        ProvisioningState::Creating,
+        ProvisioningState::PendingResourceHold,
+        ProvisioningState::ConfirmedResourceHold,
+        ProvisioningState::PendingBilling,
+        ProvisioningState::ConfirmedBilling,
+        ProvisioningState::Created,
+    ] {
+        assert_eq!(state.into_poll_result(), PollResult::InProgress, "{:?}", state);
+    }
+    assert_eq!(ProvisioningState::Succeeded.into_poll_result(), PollResult::Done);
+    assert_eq!(ProvisioningState::Split.into_poll_result(), PollResult::Done);
+}
@@ -0,0 +1,22 @@
+use azure_mgmt_reservations::models::{ReservationOrderProperties, ReservationQuantity, SplitProperties};
+use std::convert::TryFrom;
+
+#[test]
+fn split_quantities_add_up_to_the_original_quantity() {
+    let order: ReservationOrderProperties = serde_json::from_str(r#"{"originalQuantity": 5}"#).unwrap();
+    let split: SplitProperties = serde_json::from_str(r#"{"quantities": [2, 3]}"#).unwrap();
+
+    let total: ReservationQuantity = split.quantities.iter().sum();
+    assert_eq!(Some(total), order.original_quantity);
+    assert_eq!(total.get(), 5);
+    assert_eq!(serde_json::to_string(&split.quantities).unwrap(), "[2,3]");
+}
+
+#[test]
+fn conversions_are_checked() {
+    assert_eq!(ReservationQuantity::try_from(3i32).unwrap(), ReservationQuantity::new(3));
+    assert!(ReservationQuantity::try_from(-1i32).is_err());
+    assert!(ReservationQuantity::try_from(i64::from(u32::MAX) + 1).is_err());
+    assert_eq!(ReservationQuantity::new(u32::MAX).checked_add(ReservationQuantity::new(1)), None);
+    assert!(serde_json::from_str::<SplitProperties>(r#"{"quantities": [-1]}"#).is_err());
+}
@@ -0,0 +1,35 @@
+use azure_mgmt_reservations::models::SkuRestriction;
+use azure_mgmt_reservations::{RestrictionReason, RestrictionType};
+
+#[test]
+fn known_restrictions_are_typed() {
+    let restriction: SkuRestriction = serde_json::from_str(
+        r#"{
+            "type": "Location",
+            "values": ["westus"],
+            "reasonCode": "NotAvailableForSubscription"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(restriction.restriction_type(), Some(RestrictionType::Location));
+    assert_eq!(restriction.reason(), Some(RestrictionReason::NotAvailableForSubscription));
+}
+
+#[test]
+fn unknown_restrictions_are_not_errors() {
+    let restriction: SkuRestriction = serde_json::from_str(r#"{ "type": "Region", "reasonCode": "TooFancy" }"#).unwrap();
+    assert_eq!(restriction.restriction_type(), Some(RestrictionType::Unknown));
+    assert_eq!(restriction.reason(), Some(RestrictionReason::Unknown));
+    // the original strings are still there
+    assert_eq!(restriction.reason_code.as_deref(), Some("TooFancy"));
+
+    let restriction: SkuRestriction = serde_json::from_str("{}").unwrap();
+    assert_eq!(restriction.restriction_type(), None);
+    assert_eq!(restriction.reason(), None);
+}
+
+#[test]
+fn restrictions_can_be_deserialized_as_enums() {
+    let reasons: Vec<RestrictionReason> = serde_json::from_str(r#"["QuotaId", "Other"]"#).unwrap();
+    assert_eq!(reasons, vec![RestrictionReason::QuotaId, RestrictionReason::Unknown]);
+}
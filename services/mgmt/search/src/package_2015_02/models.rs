@@ -97,6 +97,15 @@ pub mod search_service_readable_properties {
         #[serde(rename = "failed")]
         Failed,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Succeeded => azure_core::PollResult::Done,
+                Self::Provisioning => azure_core::PollResult::InProgress,
+                Self::Failed => azure_core::PollResult::Failed("failed".to_owned()),
+            }
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SearchServiceResource {
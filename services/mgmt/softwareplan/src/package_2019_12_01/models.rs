@@ -25,6 +25,15 @@ pub enum ProvisioningState {
     Cancelled,
     Failed,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Cancelled => azure_core::PollResult::Failed("Cancelled".to_owned()),
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HybridUseBenefitListResult {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
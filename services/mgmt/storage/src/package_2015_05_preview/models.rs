@@ -104,6 +104,15 @@ pub mod storage_account_properties {
         ResolvingDns,
         Succeeded,
     }
+    impl azure_core::IntoPollResult for ProvisioningState {
+        fn into_poll_result(&self) -> azure_core::PollResult {
+            match self {
+                Self::Creating => azure_core::PollResult::InProgress,
+                Self::ResolvingDns => azure_core::PollResult::InProgress,
+                Self::Succeeded => azure_core::PollResult::Done,
+            }
+        }
+    }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub enum AccountType {
         #[serde(rename = "Standard_LRS")]
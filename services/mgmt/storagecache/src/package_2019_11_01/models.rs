@@ -89,6 +89,18 @@ pub mod cache {
             Deleting,
             Updating,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                    Self::Cancelled => azure_core::PollResult::Failed("Cancelled".to_owned()),
+                    Self::Creating => azure_core::PollResult::InProgress,
+                    Self::Deleting => azure_core::PollResult::InProgress,
+                    Self::Updating => azure_core::PollResult::InProgress,
+                }
+            }
+        }
     }
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
     pub struct Sku {
@@ -270,6 +282,18 @@ pub mod storage_target {
             Deleting,
             Updating,
         }
+        impl azure_core::IntoPollResult for ProvisioningState {
+            fn into_poll_result(&self) -> azure_core::PollResult {
+                match self {
+                    Self::Succeeded => azure_core::PollResult::Done,
+                    Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+                    Self::Cancelled => azure_core::PollResult::Failed("Cancelled".to_owned()),
+                    Self::Creating => azure_core::PollResult::InProgress,
+                    Self::Deleting => azure_core::PollResult::InProgress,
+                    Self::Updating => azure_core::PollResult::InProgress,
+                }
+            }
+        }
     }
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
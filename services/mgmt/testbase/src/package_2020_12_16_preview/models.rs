@@ -139,6 +139,18 @@ pub enum ProvisioningState {
     Deleting,
     Updating,
 }
+impl azure_core::IntoPollResult for ProvisioningState {
+    fn into_poll_result(&self) -> azure_core::PollResult {
+        match self {
+            Self::Succeeded => azure_core::PollResult::Done,
+            Self::Failed => azure_core::PollResult::Failed("Failed".to_owned()),
+            Self::Cancelled => azure_core::PollResult::Failed("Cancelled".to_owned()),
+            Self::Creating => azure_core::PollResult::InProgress,
+            Self::Deleting => azure_core::PollResult::InProgress,
+            Self::Updating => azure_core::PollResult::InProgress,
+        }
+    }
+}
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TestBaseAccountUpdateParameters {
     #[serde(default, skip_serializing_if = "Option::is_none")]